@@ -0,0 +1,99 @@
+// https://adventofcode.com/2015/day/1
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+// code
+
+// the change of floor of each instruction on the only line of the puzzle input;
+// ( goes up one floor, and ) goes down one floor
+fn parse_input(input_string: &str) -> Result<Vec<i32>, ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+
+    let mut instructions = vec![];
+
+    while !scanner.is_at_end() {
+        let instruction = match scanner.char("( or )", |c| c == '(' || c == ')')? {
+            '(' => 1,
+            _ => -1,
+        };
+
+        instructions.push(instruction);
+    }
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok(instructions)
+}
+
+fn part_1(instructions: &[i32]) -> i32 {
+    instructions.iter().sum()
+}
+
+fn part_2(instructions: &[i32]) -> Option<usize> {
+    let mut current_floor = 0;
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        current_floor += instruction;
+
+        if current_floor < 0 {
+            return Some(index + 1);
+        }
+    }
+
+    None
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn year(&self) -> u16 {
+        2015
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(&parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(&parse_input(input_string).ok()?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(part_1(&parse_input("(()(()(").unwrap()), 3);
+        assert_eq!(part_1(&parse_input(")())())").unwrap()), -3);
+
+        assert_eq!(part_2(&parse_input(")").unwrap()), Some(1));
+        assert_eq!(part_2(&parse_input("()())").unwrap()), Some(5));
+        assert_eq!(part_2(&parse_input("((").unwrap()), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("(()x)").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 4: expected ( or ), found \"x\""
+        );
+
+        let error = parse_input("(()\n))").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
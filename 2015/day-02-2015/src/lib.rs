@@ -0,0 +1,162 @@
+// https://adventofcode.com/2015/day/2
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+// code
+
+#[derive(Debug)]
+struct Dimensions {
+    length: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Dimensions {
+    fn get_slack(&self) -> u32 {
+        let axis_1 = self.length * self.width;
+        let axis_2 = self.width * self.height;
+        let axis_3 = self.length * self.height;
+
+        let slacks = vec![axis_1, axis_2, axis_3];
+
+        slacks.into_iter().min_by(|x, y| x.cmp(y)).unwrap()
+    }
+
+    fn get_area(&self) -> u32 {
+        let axis_1 = 2 * self.length * self.width;
+        let axis_2 = 2 * self.width * self.height;
+        let axis_3 = 2 * self.length * self.height;
+
+        axis_1 + axis_2 + axis_3 + self.get_slack()
+    }
+
+    fn required_amount_of_ribbon(&self) -> u32 {
+        let axis_1 = 2 * self.length + 2 * self.width;
+        let axis_2 = 2 * self.width + 2 * self.height;
+        let axis_3 = 2 * self.length + 2 * self.height;
+
+        let possible_wraps = vec![axis_1, axis_2, axis_3];
+
+        let minimum_wrap = possible_wraps.into_iter().min_by(|x, y| x.cmp(y)).unwrap();
+
+        let bow_tie = self.length * self.width * self.height;
+
+        minimum_wrap + bow_tie
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn year(&self) -> u16 {
+        2015
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
+
+// e.g. 2x3x4
+fn parse_dimensions(mut scanner: Scanner) -> Result<Dimensions, ParseError> {
+    let length: u32 = scanner.integer()?;
+    scanner.literal("x")?;
+    let width: u32 = scanner.integer()?;
+    scanner.literal("x")?;
+    let height: u32 = scanner.integer()?;
+    scanner.end()?;
+
+    Ok(Dimensions {
+        length,
+        width,
+        height,
+    })
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Dimensions>, ParseError> {
+    scan_lines(input_string).map(parse_dimensions).collect()
+}
+
+fn part_1(input_string: &str) -> Option<u32> {
+    let dimensions = parse_input(input_string).ok()?;
+
+    let sum: u32 = dimensions
+        .iter()
+        .fold(0, |acc, item| -> u32 { acc + item.get_area() });
+
+    Some(sum)
+}
+
+fn part_2(input_string: &str) -> Option<u32> {
+    let dimensions = parse_input(input_string).ok()?;
+
+    let sum: u32 = dimensions.iter().fold(0, |acc, item| -> u32 {
+        acc + item.required_amount_of_ribbon()
+    });
+
+    Some(sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Some(1586300));
+
+        let input_string = r####"
+        2x3x4
+        "####;
+
+        assert_eq!(part_1(input_string), Some(58));
+
+        let input_string = r####"
+        1x1x10
+        "####;
+
+        assert_eq!(part_1(input_string), Some(43));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Some(3737498));
+
+        let input_string = r####"
+        2x3x4
+        "####;
+
+        assert_eq!(part_2(input_string), Some(34));
+
+        let input_string = r####"
+        1x1x10
+        "####;
+
+        assert_eq!(part_2(input_string), Some(14));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("2x3x4\n\n1x1\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 4: expected \"x\", found end of line"
+        );
+
+        let error = parse_input("2x3xfour").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+}
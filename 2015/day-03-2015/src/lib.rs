@@ -0,0 +1,183 @@
+// https://adventofcode.com/2015/day/3
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Solution};
+use aoc_grid::{Coordinate, Transitions};
+use std::collections::HashMap;
+
+// code
+
+type Visits = u32;
+
+struct Santa {
+    visited_houses: HashMap<Coordinate, Visits>,
+    current_position: Coordinate,
+}
+
+impl Santa {
+    fn new(initial_position: Coordinate) -> Self {
+        let mut visited_houses = HashMap::new();
+        visited_houses.insert(initial_position, 1);
+
+        Santa {
+            visited_houses,
+            current_position: initial_position,
+        }
+    }
+
+    fn update_next_coord(&mut self, next_coord: Coordinate) {
+        let counter = self.visited_houses.entry(next_coord).or_insert(0);
+        *counter += 1;
+
+        self.current_position = next_coord;
+    }
+
+    fn up(&mut self) {
+        let next_coord = self.current_position.up();
+        self.update_next_coord(next_coord);
+    }
+
+    fn down(&mut self) {
+        let next_coord = self.current_position.down();
+        self.update_next_coord(next_coord);
+    }
+
+    fn left(&mut self) {
+        let next_coord = self.current_position.left();
+        self.update_next_coord(next_coord);
+    }
+
+    fn right(&mut self) {
+        let next_coord = self.current_position.right();
+        self.update_next_coord(next_coord);
+    }
+
+    fn num_of_visited_houses(&self) -> usize {
+        self.visited_houses.keys().len()
+    }
+
+    fn visited_coords(&self) -> Vec<Coordinate> {
+        self.visited_houses.keys().cloned().collect()
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn year(&self) -> u16 {
+        2015
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<char>, ParseError> {
+    let mut instructions = vec![];
+
+    for mut scanner in scan_lines(input_string) {
+        while !scanner.is_at_end() {
+            let instruction = scanner.char("one of ^, v, > or <", |c| "^v><".contains(c))?;
+            instructions.push(instruction);
+        }
+    }
+
+    Ok(instructions)
+}
+
+fn deliver<'a>(instructions: impl Iterator<Item = &'a char>) -> Santa {
+    let mut santa = Santa::new((0, 0));
+
+    for instruction in instructions {
+        match instruction {
+            '^' => {
+                santa.up();
+            }
+            'v' => {
+                santa.down();
+            }
+            '>' => {
+                santa.right();
+            }
+            '<' => {
+                santa.left();
+            }
+
+            _ => {
+                unreachable!();
+            }
+        }
+    }
+
+    santa
+}
+
+fn part_1(input_string: &str) -> Option<usize> {
+    let instructions = parse_input(input_string).ok()?;
+    let santa = deliver(instructions.iter());
+
+    Some(santa.num_of_visited_houses())
+}
+
+fn part_2(input_string: &str) -> Option<usize> {
+    let instructions = parse_input(input_string).ok()?;
+
+    // santa and robo-santa take turns
+    let santa = deliver(instructions.iter().step_by(2));
+    let robo_santa = deliver(instructions.iter().skip(1).step_by(2));
+
+    let mut visited_coords = santa.visited_coords();
+    visited_coords.append(&mut robo_santa.visited_coords());
+
+    visited_coords.sort();
+    visited_coords.dedup();
+
+    Some(visited_coords.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Some(2572));
+
+        assert_eq!(part_1(">"), Some(2));
+        assert_eq!(part_1("^>v<"), Some(4));
+        assert_eq!(part_1("^v^v^v^v^v"), Some(2));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(include_str!("input.txt")), Some(2631));
+
+        assert_eq!(part_2("^v"), Some(3));
+        assert_eq!(part_2("^>v<"), Some(3));
+        assert_eq!(part_2("^v^v^v^v^v"), Some(11));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("^>v<\n^>x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected one of ^, v, > or <, found \"x\""
+        );
+    }
+}
@@ -0,0 +1,149 @@
+// https://adventofcode.com/2015/day/4
+
+extern crate md5;
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use rayon::prelude::*;
+
+fn general(secret_key: &str, num_of_zeroes: usize) -> i32 {
+    let marker: String = String::from_utf8(vec![b'0'; num_of_zeroes]).unwrap();
+
+    let mut current = 0;
+    let size = 100000;
+
+    loop {
+        let start: i32 = 1 + current * size;
+        let end: i32 = size + current * size;
+
+        let lowest_positive_num: Option<i32> = (start..=end)
+            .into_par_iter()
+            .map(|lowest_positive_num: i32| -> Option<i32> {
+                let digest = md5::compute(format!("{}{}", secret_key, lowest_positive_num));
+                let digest = format!("{:x}", digest);
+
+                if digest.starts_with(&marker) {
+                    return Some(lowest_positive_num);
+                }
+
+                None
+            })
+            .fold(
+                || None,
+                |lowest_positive_num: Option<i32>, candidate: Option<i32>| -> Option<i32> {
+                    match candidate {
+                        None => lowest_positive_num,
+                        Some(maybe_lowest_positive_num) => match lowest_positive_num {
+                            None => Some(maybe_lowest_positive_num),
+                            Some(lowest_positive_num) => {
+                                if maybe_lowest_positive_num < lowest_positive_num {
+                                    return Some(maybe_lowest_positive_num);
+                                }
+                                Some(lowest_positive_num)
+                            }
+                        },
+                    }
+                },
+            )
+            .reduce(
+                || None,
+                |lowest_positive_num: Option<i32>, candidate: Option<i32>| -> Option<i32> {
+                    match candidate {
+                        None => lowest_positive_num,
+                        Some(maybe_lowest_positive_num) => match lowest_positive_num {
+                            None => Some(maybe_lowest_positive_num),
+                            Some(lowest_positive_num) => {
+                                if maybe_lowest_positive_num < lowest_positive_num {
+                                    return Some(maybe_lowest_positive_num);
+                                }
+                                Some(lowest_positive_num)
+                            }
+                        },
+                    }
+                },
+            );
+
+        if lowest_positive_num.is_none() {
+            current += 1;
+            continue;
+        }
+
+        let lowest_positive_num = lowest_positive_num.unwrap();
+        return lowest_positive_num;
+    }
+}
+
+// the secret key, which is the only word of the puzzle input
+fn parse_input(input_string: &str) -> Result<&str, ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+
+    let secret_key = scanner.word("a secret key")?;
+    scanner.end()?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok(secret_key)
+}
+
+fn part_1(secret_key: &str) -> i32 {
+    general(secret_key, 5)
+}
+
+fn part_2(secret_key: &str) -> i32 {
+    general(secret_key, 6)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn year(&self) -> u16 {
+        2015
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1("abcdef"), 609043);
+        assert_eq!(part_1("pqrstuv"), 1048970);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("  abcdef\n"), Ok("abcdef"));
+
+        let error = parse_input("").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a secret key, found end of line"
+        );
+
+        let error = parse_input("abc def").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+
+        let error = parse_input("abcdef\n\nghijkl").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
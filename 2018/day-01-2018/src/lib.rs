@@ -0,0 +1,94 @@
+// https://adventofcode.com/2018/day/1
+
+// stdlib imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::collections::HashSet;
+
+// Part 1
+fn sum_frequencies(current_frequency: i32, changes: &[i32]) -> i32 {
+    current_frequency + changes.iter().sum::<i32>()
+}
+
+// Part 2
+fn find_second_frequency(
+    mut current_frequency: i32,
+    seen_frequencies: &mut HashSet<i32>,
+    changes: &[i32],
+) -> (bool, i32) {
+    for change in changes {
+        current_frequency += change;
+
+        if seen_frequencies.contains(&current_frequency) {
+            return (true, current_frequency);
+        }
+
+        seen_frequencies.insert(current_frequency);
+    }
+
+    (false, current_frequency)
+}
+
+// e.g. +7 or -12
+fn parse_change(mut scanner: Scanner) -> Result<i32, ParseError> {
+    let change = scanner.integer()?;
+    scanner.end()?;
+    Ok(change)
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<i32>, ParseError> {
+    scan_lines(input_string).map(parse_change).collect()
+}
+
+fn part_1(input_string: &str) -> Option<i32> {
+    // according to the puzzle, the starting frequency is 0
+    Some(sum_frequencies(0, &parse_input(input_string).ok()?))
+}
+
+fn part_2(input_string: &str) -> Option<i32> {
+    let changes = parse_input(input_string).ok()?;
+
+    if changes.is_empty() {
+        return None;
+    }
+
+    let mut seen_frequencies: HashSet<i32> = HashSet::new();
+    let mut current_frequency = 0;
+    seen_frequencies.insert(current_frequency);
+
+    loop {
+        let (should_break, next_frequency) =
+            find_second_frequency(current_frequency, &mut seen_frequencies, &changes);
+
+        current_frequency = next_frequency;
+
+        if should_break {
+            return Some(current_frequency);
+        }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
@@ -0,0 +1,216 @@
+// https://adventofcode.com/2018/day/2
+
+// stdlib imports
+
+use aoc_core::{scan_lines, ParseError, Solution};
+use std::collections::HashMap;
+
+// types
+
+#[derive(Debug)]
+enum Parsed {
+    // input string only contain letter(s) that appear exactly two times
+    HasTwo,
+    // input string only contain letter(s) that appear exactly three times
+    HasThree,
+    // input string only contain letter(s) that either appear exactly two times,
+    // or appear exactly thre times
+    HasBoth,
+    // input string does not satisfy the above conditions
+    None,
+}
+
+// helpers
+
+fn parse_input(input: &str) -> Parsed {
+    let letter_counter = input.chars().fold(
+        HashMap::new(), // accumulator
+        |mut letter_counter: HashMap<char, i32>, letter| {
+            // count the number of occurrences of the letters within the given input
+
+            match &letter_counter.get(&letter) {
+                None => {
+                    letter_counter.insert(letter, 1);
+                }
+                Some(&num_of_occurrences) => {
+                    letter_counter.insert(letter, num_of_occurrences + 1);
+                }
+            }
+
+            letter_counter
+        },
+    );
+
+    // from letter_counter, determine if input contains letters that either
+    // - occur exactly two times
+    // - occur exactly three times
+    // - or both
+
+    letter_counter.into_iter().fold(
+        Parsed::None, // accumulator
+        |current_state, (_letter, num_of_occurrences)| {
+            let has_two = num_of_occurrences == 2;
+            let has_three = num_of_occurrences == 3;
+
+            if !has_two && !has_three {
+                return current_state;
+            }
+
+            match current_state {
+                Parsed::None => {
+                    if has_two {
+                        return Parsed::HasTwo;
+                    }
+
+                    Parsed::HasThree
+                }
+                Parsed::HasTwo => {
+                    if has_two {
+                        return Parsed::HasTwo;
+                    }
+
+                    Parsed::HasBoth
+                }
+                Parsed::HasThree => {
+                    if has_three {
+                        return Parsed::HasThree;
+                    }
+
+                    Parsed::HasBoth
+                }
+                Parsed::HasBoth => Parsed::HasBoth,
+            }
+        },
+    )
+}
+
+fn part_1(inputs: Vec<&str>) -> i32 {
+    let (num_of_two, num_of_three) = inputs.into_iter().map(parse_input).fold(
+        (
+            0, // number of times inputs containing 2 letters that occur at least once
+            0, // number of times inputs containing 3 letters that occur at least once
+        ),
+        |accumulator, parsed_state: Parsed| {
+            let (num_of_two, num_of_three) = accumulator;
+
+            match parsed_state {
+                Parsed::None => (num_of_two, num_of_three),
+                Parsed::HasBoth => (num_of_two + 1, num_of_three + 1),
+                Parsed::HasTwo => (num_of_two + 1, num_of_three),
+                Parsed::HasThree => (num_of_two, num_of_three + 1),
+            }
+        },
+    );
+
+    // checksum
+    num_of_two * num_of_three
+}
+
+#[derive(Debug)]
+enum Diff {
+    // indicates two strings have no difference in positional characters
+    None,
+    // indicates strings differ by exactly one character
+    ByOne,
+    // indicates strings differ by more than one character
+    ByMoreThanOne,
+}
+
+// Check if two strings which differ by exactly one character at the same position in both strings
+fn strings_diff_by_1(this: &str, other: &str) -> bool {
+    let result = this.chars().zip(other.chars()).fold(
+        Diff::None,
+        |acc, (this_char, other_char): (char, char)| {
+            let has_diff = this_char != other_char;
+
+            match acc {
+                Diff::None => {
+                    if has_diff {
+                        return Diff::ByOne;
+                    }
+                    Diff::None
+                }
+                Diff::ByOne => {
+                    if has_diff {
+                        return Diff::ByMoreThanOne;
+                    }
+                    Diff::ByOne
+                }
+                Diff::ByMoreThanOne => Diff::ByMoreThanOne,
+            }
+        },
+    );
+
+    matches!(result, Diff::ByOne)
+}
+
+fn common_letters(this: &str, other: &str) -> String {
+    let result: String = this
+        .chars()
+        .zip(other.chars())
+        .filter(|&(this_char, other_char): &(char, char)| -> bool { this_char == other_char })
+        .map(|(this_char, _other_char): (char, char)| this_char)
+        .collect();
+
+    result
+}
+
+fn part_2(inputs: Vec<&str>) -> Option<String> {
+    for input in inputs.clone() {
+        for other_input in inputs.clone() {
+            if input == other_input {
+                continue;
+            }
+            let result = strings_diff_by_1(input, other_input);
+
+            if result {
+                // What letters are common between the two correct box IDs?
+                return Some(common_letters(input, other_input));
+            }
+        }
+    }
+
+    None
+}
+
+// box IDs consist of lowercase letters only
+fn parse_input_lines(input_string: &str) -> Result<Vec<&str>, ParseError> {
+    let mut inputs = vec![];
+
+    for mut scanner in scan_lines(input_string) {
+        let box_id = scanner.rest();
+
+        while !scanner.is_at_end() {
+            scanner.char("a lowercase letter", |c| c.is_ascii_lowercase())?;
+        }
+
+        inputs.push(box_id);
+    }
+
+    Ok(inputs)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input_lines(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input_lines(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(parse_input_lines(input_string).ok()?)
+    }
+}
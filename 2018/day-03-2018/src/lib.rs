@@ -0,0 +1,255 @@
+// https://adventofcode.com/2018/day/3
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::cmp;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+// part 1
+
+#[derive(Debug, PartialEq, Clone, Hash, Eq)]
+struct Fabric {
+    id: String,
+
+    // starting coordinates
+    left: i32,
+    top: i32,
+
+    // size
+    height: i32,
+    width: i32,
+}
+
+impl Fabric {
+    fn right(&self) -> i32 {
+        self.left + self.width
+    }
+
+    fn bottom(&self) -> i32 {
+        self.top + self.height
+    }
+
+    fn generate_claim_points(&self) -> HashSet<String> {
+        // NOTE: I don't like this :(
+
+        let mut points: HashSet<String> = HashSet::new();
+
+        for x in self.left..self.right() {
+            for y in self.top..self.bottom() {
+                points.insert(format!("{},{}", x, y));
+            }
+        }
+
+        points
+    }
+
+    fn is_overlapping(&self, other: &Fabric) -> bool {
+        // determines if self is on the left side of other, and not overlapping
+        let self_left_of_other = self.right() < other.left;
+        // determines if self is on the right side of other, and not overlapping
+        let self_right_of_other = self.left > other.right();
+        // determines if self is above other, and not overlapping
+        let self_above_of_other = self.bottom() < other.top;
+        // determines if self is below other, and not overlapping
+        let self_below_of_other = self.top > other.bottom();
+
+        // self does not overlap other if any of the above conditions is true
+        let not_overlapping =
+            self_left_of_other || self_right_of_other || self_above_of_other || self_below_of_other;
+
+        !not_overlapping
+    }
+
+    fn generate_intersection_fabric(&self, other: &Fabric) -> Option<Fabric> {
+        if !self.is_overlapping(other) {
+            return None;
+        }
+
+        let left = cmp::max(self.left, other.left);
+        let top = cmp::max(self.top, other.top);
+
+        let overlapping_width = cmp::min(self.right(), other.right()) - left;
+        let overlapping_height = cmp::min(self.bottom(), other.bottom()) - top;
+
+        let area = overlapping_width * overlapping_height;
+
+        if area <= 0 {
+            return None;
+        }
+
+        assert!(overlapping_width > 0);
+        assert!(overlapping_height > 0);
+
+        let intersection_fabric = Fabric {
+            id: format!("Insection of: {} and {}", self.id, other.id),
+            left,
+            top,
+            height: overlapping_height,
+            width: overlapping_width,
+        };
+
+        Some(intersection_fabric)
+    }
+}
+
+// e.g. #123 @ 3,2: 5x4
+fn parse_to_fabric(mut scanner: Scanner) -> Result<Fabric, ParseError> {
+    scanner.literal("#")?;
+    let id: u32 = scanner.integer()?;
+    scanner.skip_whitespace();
+    scanner.literal("@")?;
+    scanner.skip_whitespace();
+
+    let left: i32 = scanner.integer()?;
+    scanner.literal(",")?;
+    let top: i32 = scanner.integer()?;
+    scanner.literal(":")?;
+    scanner.skip_whitespace();
+
+    let width: i32 = scanner.integer()?;
+    scanner.literal("x")?;
+    let height: i32 = scanner.integer()?;
+    scanner.end()?;
+
+    Ok(Fabric {
+        id: format!("#{}", id),
+        left,
+        top,
+        height,
+        width,
+    })
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Fabric>, ParseError> {
+    scan_lines(input_string).map(parse_to_fabric).collect()
+}
+
+// returns the area overlapped by 2 or more fabrics, and the fabrics that do not overlap any other fabric
+fn claim_fabrics(fabrics: Vec<Fabric>) -> (usize, Vec<Fabric>) {
+    // set of fabrics assumed to not overlap with any other fabric
+    let mut nonoverlapping_fabrics: HashSet<&Fabric> = HashSet::from_iter(fabrics.iter());
+
+    let mut known_overlapping_area: HashSet<String> = HashSet::new();
+
+    for fabric in fabrics.clone() {
+        for other_fabric in fabrics.clone() {
+            if fabric.id == other_fabric.id {
+                continue;
+            }
+
+            let intersection_fabric = fabric.generate_intersection_fabric(&other_fabric);
+
+            if let Some(intersection_fabric) = intersection_fabric {
+                // add intersection to overlapping area
+
+                let claimed_points = intersection_fabric.generate_claim_points();
+                known_overlapping_area.extend(claimed_points);
+
+                // these fabrics overlap, remove them from nonoverlapping_fabrics set
+
+                nonoverlapping_fabrics.remove(&fabric);
+                nonoverlapping_fabrics.remove(&other_fabric);
+            }
+        }
+    }
+
+    let nonoverlapping_fabrics = nonoverlapping_fabrics.into_iter().cloned().collect();
+
+    (known_overlapping_area.len(), nonoverlapping_fabrics)
+}
+
+// part 1: area overlapping 2 or more fabrics
+fn part_1(input_string: &str) -> Option<usize> {
+    let (overlapping_area, _nonoverlapping_fabrics) =
+        claim_fabrics(parse_input(input_string).ok()?);
+    Some(overlapping_area)
+}
+
+// part 2: id of the only fabric that doesn't overlap
+fn part_2(input_string: &str) -> Option<String> {
+    let (_overlapping_area, nonoverlapping_fabrics) =
+        claim_fabrics(parse_input(input_string).ok()?);
+
+    match nonoverlapping_fabrics.as_slice() {
+        [fabric] => Some(fabric.id.trim_start_matches('#').to_string()),
+        _ => None,
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fabric(input: &str) -> Fabric {
+        parse_to_fabric(Scanner::new(1, input)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_to_fabric() {
+        let expected = Fabric {
+            id: "#123".to_string(),
+            left: 3,
+            top: 2,
+            height: 4,
+            width: 5,
+        };
+
+        assert_eq!(fabric("#123 @ 3,2: 5x4"), expected);
+
+        let error = parse_input("#1 @ 1,3: 4x4\n#2 @ 3,1 4x4").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 9: expected \":\", found \" \""
+        );
+    }
+
+    #[test]
+    fn test_overlap() {
+        fn get_overlapping_area(this: &Fabric, other: &Fabric) -> i32 {
+            let intersection_fabric = this.generate_intersection_fabric(other);
+
+            if let Some(intersection_fabric) = intersection_fabric {
+                let claimed_points = intersection_fabric.generate_claim_points();
+
+                return claimed_points.len() as i32;
+            }
+
+            0
+        }
+
+        let fabric_1 = fabric("#1 @ 1,3: 4x4");
+        let fabric_2 = fabric("#2 @ 3,1: 4x4");
+        let fabric_3 = fabric("#3 @ 5,5: 2x2");
+
+        assert_eq!(get_overlapping_area(&fabric_1, &fabric_2), 4);
+        assert_eq!(get_overlapping_area(&fabric_1, &fabric_3), 0);
+        assert_eq!(get_overlapping_area(&fabric_2, &fabric_3), 0);
+    }
+}
@@ -0,0 +1,399 @@
+// https://adventofcode.com/2018/day/4
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+// types
+
+type GuardID = i32;
+type Minute = i32;
+type Occurences = i32;
+type Timestamp = String;
+
+// records are added to the b-tree map in the order of their timestamps
+type GuardShifts = BTreeMap<Timestamp, Record>;
+
+#[derive(Debug)]
+enum GuardState {
+    BeginsShift(GuardID),
+    Sleeps(Minute),
+    Wakes(Minute),
+}
+
+#[derive(Debug)]
+struct Record {
+    date_time: Timestamp,
+    state: GuardState,
+}
+
+#[derive(Debug)]
+struct Guard {
+    id: GuardID,
+
+    minutes_slept: i32,
+
+    // which minute portion on midnight was the guard sleeping at, and how many times?
+    occurences_counter: HashMap<Minute, Occurences>,
+}
+
+impl Guard {
+    fn new(id: GuardID) -> Guard {
+        Guard {
+            id,
+            minutes_slept: 0,
+            occurences_counter: HashMap::new(),
+        }
+    }
+
+    fn slept_at_between(&mut self, slept_at: Minute, woke_up_at: Minute) {
+        assert!(slept_at < woke_up_at);
+
+        for minute in slept_at..woke_up_at {
+            self.occurences_counter
+                .entry(minute)
+                .and_modify(|x| *x += 1)
+                .or_insert(1);
+        }
+
+        let minutes_slept = woke_up_at - slept_at;
+
+        self.minutes_slept += minutes_slept;
+    }
+
+    fn get_minute_slept_most_at(&self) -> Option<(Minute, Occurences)> {
+        // What minute does that guard spend asleep the most?
+
+        let result = self.occurences_counter.iter().fold(
+            None,
+            |acc: Option<(Minute, Occurences)>, (minute, occurences_count)| match acc {
+                None => Some((*minute, *occurences_count)),
+                Some((_prev_minute, prev_occurences_count)) => {
+                    if occurences_count > &prev_occurences_count {
+                        return Some((*minute, *occurences_count));
+                    }
+                    acc
+                }
+            },
+        );
+
+        result
+    }
+}
+
+// track minutes slept for a guard
+type GuardSleepCounter = HashMap<GuardID, Guard>;
+
+// e.g. [1518-11-01 00:00] Guard #10 begins shift
+//
+// returns the record along with a scanner at its state, to report records that are out of order
+fn parse_record(mut scanner: Scanner) -> Result<(Record, Scanner), ParseError> {
+    // parse date
+
+    scanner.literal("[")?;
+    let year: u32 = scanner.integer()?;
+    scanner.literal("-")?;
+    let month: u32 = scanner.integer()?;
+    scanner.literal("-")?;
+    let day: u32 = scanner.integer()?;
+    scanner.literal(" ")?;
+    let hour: u32 = scanner.integer()?;
+    scanner.literal(":")?;
+    let minute: Minute = scanner.integer()?;
+    scanner.literal("]")?;
+    scanner.skip_whitespace();
+
+    let date_time = format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year, month, day, hour, minute
+    );
+
+    // parse state
+
+    let state_scanner = scanner;
+    let state: GuardState = if scanner.eat("wakes up") {
+        GuardState::Wakes(minute)
+    } else if scanner.eat("falls asleep") {
+        GuardState::Sleeps(minute)
+    } else if scanner.eat("Guard #") {
+        let guard_id: GuardID = scanner.integer()?;
+        scanner.skip_whitespace();
+        scanner.literal("begins shift")?;
+        GuardState::BeginsShift(guard_id)
+    } else {
+        return Err(scanner.error("\"wakes up\", \"falls asleep\" or \"Guard #\""));
+    };
+
+    scanner.end()?;
+
+    Ok((Record { date_time, state }, state_scanner))
+}
+
+// In the order of their timestamps, a guard begins their shift before falling asleep, and wakes
+// up after falling asleep and before the next shift begins.
+fn check_record_order(records: &BTreeMap<Timestamp, (Record, Scanner)>) -> Result<(), ParseError> {
+    let mut shift_began = false;
+    let mut slept_at: Option<Minute> = None;
+
+    for (record, scanner) in records.values() {
+        match record.state {
+            GuardState::BeginsShift(_guard_id) => {
+                if slept_at.is_some() {
+                    return Err(scanner.error("\"wakes up\" before the next shift begins"));
+                }
+
+                shift_began = true;
+            }
+            GuardState::Sleeps(minute) => {
+                if !shift_began {
+                    return Err(scanner.error("a shift to begin before \"falls asleep\""));
+                }
+
+                if slept_at.is_some() {
+                    return Err(scanner.error("\"wakes up\" after \"falls asleep\""));
+                }
+
+                slept_at = Some(minute);
+            }
+            GuardState::Wakes(woke_up_at) => match slept_at {
+                None => {
+                    return Err(scanner.error("\"falls asleep\" before \"wakes up\""));
+                }
+                Some(minute) if woke_up_at <= minute => {
+                    return Err(scanner.error(format!("waking up after minute {}", minute)));
+                }
+                Some(_minute) => {
+                    slept_at = None;
+                }
+            },
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_input(input_string: &str) -> Result<GuardShifts, ParseError> {
+    // for the b-tree map; ensure this ordering invariant holds
+    assert!("1518-09-24" < "1518-10-24");
+
+    let mut records: BTreeMap<Timestamp, (Record, Scanner)> = BTreeMap::new();
+
+    for scanner in scan_lines(input_string) {
+        let (record, state_scanner) = parse_record(scanner)?;
+
+        if records.contains_key(&record.date_time) {
+            return Err(scanner.error("a record with a timestamp of its own"));
+        }
+
+        records.insert(record.date_time.clone(), (record, state_scanner));
+    }
+
+    check_record_order(&records)?;
+
+    let guard_shifts: GuardShifts = records
+        .into_iter()
+        .map(|(date_time, (record, _state_scanner))| (date_time, record))
+        .collect();
+
+    Ok(guard_shifts)
+}
+
+fn track_guards(guard_shifts: GuardShifts) -> GuardSleepCounter {
+    let mut guard_sleep_tracker: GuardSleepCounter = HashMap::new();
+    let mut current_guard: Option<GuardID> = None;
+    let mut slept_at: Option<Minute> = None;
+
+    for (_date_time, record) in guard_shifts {
+        match record.state {
+            GuardState::BeginsShift(guard_id) => {
+                current_guard = Some(guard_id);
+                guard_sleep_tracker
+                    .entry(guard_id)
+                    .or_insert(Guard::new(guard_id));
+            }
+            GuardState::Sleeps(minute) => {
+                slept_at = Some(minute);
+            }
+            GuardState::Wakes(woke_up_at) => {
+                // track minutes slept for current guard;
+                // parse_input checked that the guard began their shift and fell asleep
+
+                let guard =
+                    current_guard.and_then(|guard_id| guard_sleep_tracker.get_mut(&guard_id));
+
+                if let (Some(guard), Some(slept_at)) = (guard, slept_at.take()) {
+                    guard.slept_at_between(slept_at, woke_up_at);
+                }
+            }
+        }
+    }
+
+    guard_sleep_tracker
+}
+
+fn part_1(input_string: &str) -> Option<i32> {
+    let guard_sleep_tracker = track_guards(parse_input(input_string).ok()?);
+
+    // Find the guard that has the most minutes asleep.
+
+    let guard_who_sleeps_the_most = guard_sleep_tracker.iter().fold(
+        None,
+        |acc: Option<&Guard>, (_guard_id, guard)| match acc {
+            None => Some(guard),
+            Some(prev_guard) => {
+                if guard.minutes_slept > prev_guard.minutes_slept {
+                    return Some(guard);
+                }
+
+                acc
+            }
+        },
+    );
+
+    let guard = guard_who_sleeps_the_most?;
+
+    // What minute does that guard spend asleep the most?
+    let (minute_slept_most_at, _occurences) = guard.get_minute_slept_most_at()?;
+
+    Some(minute_slept_most_at * guard.id)
+}
+
+fn part_2(input_string: &str) -> Option<i32> {
+    let guard_sleep_tracker = track_guards(parse_input(input_string).ok()?);
+
+    // Of all guards, which guard is most frequently asleep on the same minute?
+
+    let result = guard_sleep_tracker.iter().fold(
+        None,
+        |acc: Option<(&Guard, Minute, Occurences)>, (_guard_id, guard)| match acc {
+            None => guard
+                .get_minute_slept_most_at()
+                .map(|(minute, occurences_count)| (guard, minute, occurences_count)),
+            Some((_prev_guard, _best_minute, best_occurences_count)) => {
+                match guard.get_minute_slept_most_at() {
+                    None => acc,
+                    Some((minute, occurences_count)) => {
+                        if occurences_count > best_occurences_count {
+                            return Some((guard, minute, occurences_count));
+                        }
+
+                        acc
+                    }
+                }
+            }
+        },
+    );
+
+    let (guard, minute, _count) = result?;
+
+    Some(guard.id * minute)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let input_string = r###"
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+"###;
+        assert_eq!(parse_input(input_string).unwrap().len(), 3);
+
+        let error = parse_input("[1518-11-01 00:00] Guard #x begins shift").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 27: expected an integer, found \"x\""
+        );
+
+        let error = parse_input("\n[1518-11-01 00:05] falls awake").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 20));
+    }
+
+    #[test]
+    fn test_record_order_errors() {
+        // the records are checked in the order of their timestamps
+        let input_string = r###"
+[1518-11-01 00:05] falls asleep
+[1518-10-31 23:58] Guard #10 begins shift
+[1518-11-01 00:25] falls asleep
+"###;
+        let error = parse_input(input_string).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 20: expected \"wakes up\" after \"falls asleep\", found \"falls\""
+        );
+
+        let error = parse_input("[1518-11-01 00:05] falls asleep").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 20: expected a shift to begin before \"falls asleep\", found \"falls\""
+        );
+
+        let input_string = r###"
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:25] wakes up
+"###;
+        let error = parse_input(input_string).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 20));
+
+        let input_string = r###"
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-02 00:00] Guard #99 begins shift
+"###;
+        let error = parse_input(input_string).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 20));
+
+        let input_string = r###"
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:05] wakes up
+"###;
+        let error = parse_input(input_string).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected a record with a timestamp of its own, found \"[\""
+        );
+
+        let input_string = r###"
+[1518-11-01 23:58] Guard #10 begins shift
+[1518-11-01 23:59] falls asleep
+[1518-11-02 00:10] wakes up
+"###;
+        let error = parse_input(input_string).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 20: expected waking up after minute 59, found \"wakes\""
+        );
+    }
+}
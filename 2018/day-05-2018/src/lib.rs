@@ -0,0 +1,191 @@
+// https://adventofcode.com/2018/day/5
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+// helpers
+
+fn is_same_type(x: char, y: char) -> bool {
+    x.to_lowercase().to_string() == y.to_lowercase().to_string()
+}
+
+fn is_opposite_polarity(x: char, y: char) -> bool {
+    (x.is_uppercase() && y.is_lowercase()) || (y.is_uppercase() && x.is_lowercase())
+}
+
+fn does_react(x: char, y: char) -> bool {
+    is_same_type(x, y) && is_opposite_polarity(x, y)
+}
+
+fn part_1(input: &str) -> String {
+    let mut units: Vec<char> = input.par_chars().collect();
+
+    // skip first N units known to not react
+    let mut skip_n = 0;
+
+    'outer_loop: loop {
+        let mut units_iterable = units.iter().enumerate().skip(skip_n).peekable();
+
+        while let Some((current_index, current_unit)) = units_iterable.next() {
+            if units_iterable.peek().is_none() {
+                // no further reactions possible
+                break 'outer_loop;
+            }
+
+            let (_next_index, next_unit) = units_iterable.peek().unwrap();
+
+            if does_react(*current_unit, **next_unit) {
+                // remove these items and start from the beginning
+                units.drain(current_index..(current_index + 2));
+
+                // Know that first skip_n do not react, so we start again from there.
+                skip_n = if current_index == 0 {
+                    current_index
+                } else {
+                    current_index - 1
+                };
+
+                break;
+            }
+        }
+    }
+
+    let final_result: String = units.into_iter().collect();
+    final_result
+}
+
+fn part_2(input: &str) -> String {
+    let unique_types: HashSet<char> = HashSet::from_iter(input.to_lowercase().chars());
+
+    let result = unique_types
+        .par_iter()
+        // fold divides work into groups, and in for each group, find the shortest string
+        .fold(
+            || input.to_string(),
+            |shortest_string, character: &char| -> String {
+                let units: String = input
+                    .par_chars()
+                    .filter(|x| -> bool { !is_same_type(*x, *character) })
+                    .collect();
+
+                let reacted = part_1(&units);
+
+                if reacted.len() < shortest_string.len() {
+                    // found new shortest polymer produced
+                    return reacted;
+                }
+
+                shortest_string
+            },
+        )
+        // find the shortest string among all the folded groups
+        .reduce(
+            || input.to_string(),
+            |a, b| -> String {
+                if a.len() > b.len() {
+                    return b;
+                }
+                a
+            },
+        );
+
+    result
+}
+
+// the polymer, which is the only line of the puzzle input
+fn parse_input(input_string: &str) -> Result<&str, ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+    let polymer = scanner.rest();
+
+    while !scanner.is_at_end() {
+        scanner.char("a unit", |c| c.is_ascii_alphabetic())?;
+    }
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok(polymer)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).len().to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).len().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_same_type() {
+        assert!(is_same_type('a', 'a'));
+        assert!(is_same_type('a', 'A'));
+        assert!(!is_same_type('a', 'b'));
+    }
+
+    #[test]
+    fn test_is_opposite_polarity() {
+        assert!(!is_opposite_polarity('a', 'a'));
+        assert!(!is_opposite_polarity('B', 'B'));
+        assert!(is_opposite_polarity('a', 'A'));
+        assert!(is_opposite_polarity('A', 'a'));
+        assert!(is_opposite_polarity('a', 'B'));
+    }
+
+    #[test]
+    fn test_does_react() {
+        assert!(!does_react('a', 'a'));
+        assert!(!does_react('A', 'A'));
+        assert!(does_react('a', 'A'));
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1("dabAcCaCBAcCcaDA"), "dabCBAcaDA".to_string());
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2("dabAcCaCBAcCcaDA"), "daDA".to_string());
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("dabAcCaCBAcCcaDA\n"), Ok("dabAcCaCBAcCcaDA"));
+
+        let error = parse_input("dabA cC").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected a unit, found \" \""
+        );
+
+        let error = parse_input("dabA\ncC").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
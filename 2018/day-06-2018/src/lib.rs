@@ -0,0 +1,347 @@
+// https://adventofcode.com/2018/day/6
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::collections::HashMap;
+
+// helpers
+
+type Position = (i32, i32);
+type Destination = Position;
+type Distance = i32;
+
+type Area = i32;
+// mapping a Region to Area
+type Regions = HashMap<Destination, Area>;
+
+// https://math.stackexchange.com/a/139604/10247
+fn get_manhattan_distance(x: Position, y: Position) -> i32 {
+    let (a, b) = x;
+    let (c, d) = y;
+
+    (a - c).abs() + (b - d).abs()
+}
+
+// e.g. 1, 6
+fn parse_to_coord(mut scanner: Scanner) -> Result<Position, ParseError> {
+    let x = scanner.integer()?;
+    scanner.literal(",")?;
+    scanner.skip_whitespace();
+    let y = scanner.integer()?;
+    scanner.end()?;
+
+    Ok((x, y))
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Position>, ParseError> {
+    scan_lines(input_string).map(parse_to_coord).collect()
+}
+
+fn get_x(src: Position) -> i32 {
+    let (x, _y) = src;
+    x
+}
+
+fn get_y(src: Position) -> i32 {
+    let (_x, y) = src;
+    y
+}
+
+fn is_better_top_edge(reference: Position, target: Position) -> bool {
+    let ref_point = get_y(reference);
+    let target_point = get_y(target);
+    target_point > ref_point
+}
+
+fn is_better_bottom_edge(reference: Position, target: Position) -> bool {
+    let ref_point = get_y(reference);
+    let target_point = get_y(target);
+    target_point < ref_point
+}
+
+fn is_better_left_edge(reference: Position, target: Position) -> bool {
+    let ref_point = get_x(reference);
+    let target_point = get_x(target);
+    target_point < ref_point
+}
+
+fn is_better_right_edge(reference: Position, target: Position) -> bool {
+    let ref_point = get_x(reference);
+    let target_point = get_x(target);
+    target_point > ref_point
+}
+
+#[derive(Debug, Clone)]
+struct BoundingBox {
+    top: Position,
+    bottom: Position,
+    left: Position,
+    right: Position,
+}
+
+impl BoundingBox {
+    fn new(start: Position) -> BoundingBox {
+        BoundingBox {
+            top: start,
+            bottom: start,
+            left: start,
+            right: start,
+        }
+    }
+
+    fn is_strictly_inside_bounding_box(&self, target: Position) -> bool {
+        let (x, y) = target;
+
+        self.get_x_start() < x
+            && x < self.get_x_end()
+            && self.get_y_start() < y
+            && y < self.get_y_end()
+    }
+
+    // left-most x coord
+    fn get_x_start(&self) -> i32 {
+        let (x, _y) = self.left;
+        x
+    }
+
+    // right-most x coord
+    fn get_x_end(&self) -> i32 {
+        let (x, _y) = self.right;
+        x
+    }
+
+    // bottom-most y coord
+    fn get_y_start(&self) -> i32 {
+        let (_x, y) = self.bottom;
+        y
+    }
+
+    // top-most y coord
+    fn get_y_end(&self) -> i32 {
+        let (_x, y) = self.top;
+        y
+    }
+
+    fn add_point(&self, src: Position) -> BoundingBox {
+        let mut cloned = self.clone();
+
+        if is_better_top_edge(self.top, src) {
+            cloned.top = src;
+        }
+
+        if is_better_bottom_edge(self.bottom, src) {
+            cloned.bottom = src;
+        }
+
+        if is_better_left_edge(self.left, src) {
+            cloned.left = src;
+        }
+
+        if is_better_right_edge(self.right, src) {
+            cloned.right = src;
+        }
+
+        cloned
+    }
+}
+
+fn part_1(input_string: &str) -> Option<i32> {
+    let destinations: Vec<Position> = parse_input(input_string).ok()?;
+
+    // from the given destinations, generate the bounding box.
+
+    let bounding_box = destinations
+        .iter()
+        .fold(None, |acc: Option<BoundingBox>, dest| match acc {
+            None => Some(BoundingBox::new(*dest)),
+            Some(bounding_box) => Some(bounding_box.add_point(*dest)),
+        });
+
+    if bounding_box.is_none() {
+        println!("No bounding box generated.");
+        return None;
+    }
+
+    let bounding_box = bounding_box.unwrap();
+
+    let mut regions = {
+        let mut regions: Regions = HashMap::new();
+
+        for destination in destinations.clone() {
+            regions.insert(destination, 0);
+        }
+
+        regions
+    };
+
+    for x in bounding_box.get_x_start()..=bounding_box.get_x_end() {
+        for y in bounding_box.get_y_start()..=bounding_box.get_y_end() {
+            // find region that this position belongs to
+            let position = (x, y);
+
+            let mut distances: Vec<(Destination, Distance)> = destinations
+                .iter()
+                .map(|dest| {
+                    let distance_to_position = get_manhattan_distance(position, *dest);
+                    (*dest, distance_to_position)
+                })
+                .collect();
+
+            // sort by distance from largest to smallest
+            distances.sort_by_key(|&(_dest, distance)| distance);
+
+            let (dest, smallest_distance) = distances.first().unwrap();
+            // a lone destination is closest to every position
+            let second_smallest_distance = distances
+                .get(1)
+                .map_or(&Distance::MAX, |(_dest2, distance)| distance);
+
+            if smallest_distance < second_smallest_distance {
+                // invariant: position belongs to the region defined by dest
+
+                // if a position is on the edge of the bounding box,
+                // then the region defined by dest has infinite area.
+                if !bounding_box.is_strictly_inside_bounding_box(position) {
+                    regions.remove(dest);
+                    continue;
+                }
+
+                regions.entry(*dest).and_modify(|e| *e += 1);
+            }
+        }
+    }
+
+    let largest_region_size =
+        regions
+            .iter()
+            .fold(None, |acc: Option<i32>, (destination, region_area)| {
+                if !bounding_box.is_strictly_inside_bounding_box(*destination) {
+                    return acc;
+                }
+
+                match acc {
+                    None => Some(*region_area),
+                    Some(largest_region_area_size) => {
+                        if region_area > &largest_region_area_size {
+                            return Some(*region_area);
+                        }
+
+                        acc
+                    }
+                }
+            });
+
+    largest_region_size
+}
+
+fn part_2(input_string: &str) -> Option<i32> {
+    let destinations: Vec<Position> = parse_input(input_string).ok()?;
+
+    // from the given destinations, generate the bounding box.
+
+    let bounding_box = destinations
+        .iter()
+        .fold(None, |acc: Option<BoundingBox>, dest| match acc {
+            None => Some(BoundingBox::new(*dest)),
+            Some(bounding_box) => Some(bounding_box.add_point(*dest)),
+        });
+
+    if bounding_box.is_none() {
+        println!("No bounding box generated.");
+        return None;
+    }
+
+    let bounding_box = bounding_box.unwrap();
+
+    let distance = 10000;
+    let gap = distance / destinations.len() as i32 + 1;
+
+    let mut size_of_region = 0;
+    for x in (bounding_box.get_x_start() - gap)..=(bounding_box.get_x_end() + gap) {
+        for y in (bounding_box.get_y_start() - gap)..=(bounding_box.get_y_end() + gap) {
+            let position = (x, y);
+
+            let mut total = 0;
+
+            for destination in &destinations {
+                let distance_to_position = get_manhattan_distance(position, *destination);
+
+                total += distance_to_position;
+
+                if total >= distance {
+                    continue;
+                }
+            }
+
+            if total >= distance {
+                continue;
+            }
+
+            size_of_region += 1;
+        }
+    }
+
+    Some(size_of_region)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_to_coord() {
+        assert_eq!(parse_to_coord(Scanner::new(1, "1, 6")), Ok((1, 6)));
+
+        let error = parse_input("1, 1\n1 6").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 2: expected \",\", found \" \""
+        );
+    }
+
+    #[test]
+    fn test_get_manhattan_distance() {
+        assert_eq!(get_manhattan_distance((0, 0), (0, 0)), 0);
+        assert_eq!(get_manhattan_distance((0, 0), (3, 3)), 6);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = r###"
+1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9
+        "###;
+
+        assert_eq!(part_1(input), Some(17));
+    }
+}
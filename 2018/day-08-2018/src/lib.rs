@@ -0,0 +1,159 @@
+// https://adventofcode.com/2018/day/8
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+#[derive(Debug)]
+struct Node {
+    children: Vec<Node>,
+
+    metadata: Vec<i32>,
+}
+
+impl Node {
+    fn part_2_get_value(&self) -> i32 {
+        if self.children.is_empty() {
+            return self.metadata.iter().sum();
+        }
+
+        let mut total_value = 0;
+
+        for nth_child in &self.metadata {
+            let index = *nth_child - 1;
+
+            if index < 0 {
+                continue;
+            }
+
+            let child_node = self.children.get(index as usize);
+
+            if child_node.is_none() {
+                continue;
+            }
+
+            total_value += child_node.unwrap().part_2_get_value();
+        }
+
+        total_value
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(scanner: &mut Scanner) -> Result<T, ParseError> {
+    scanner.skip_whitespace();
+    scanner.integer()
+}
+
+fn parse_node(scanner: &mut Scanner) -> Result<Node, ParseError> {
+    // parse headers
+    let num_of_child_nodes: usize = parse_number(scanner)?;
+    let num_of_meta_entries: usize = parse_number(scanner)?;
+
+    let mut children = vec![];
+    for _ in 0..num_of_child_nodes {
+        children.push(parse_node(scanner)?);
+    }
+
+    let mut metadata = vec![];
+    for _ in 0..num_of_meta_entries {
+        metadata.push(parse_number(scanner)?);
+    }
+
+    Ok(Node { children, metadata })
+}
+
+// the root node, whose numbers are on the only line of the puzzle input
+fn parse_input(input_string: &str) -> Result<Node, ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+
+    let root_node = parse_node(&mut scanner)?;
+    scanner.end()?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok(root_node)
+}
+
+fn part_1(root_node: Node) -> i32 {
+    let mut total: i32 = 0;
+
+    let mut stack = vec![root_node];
+
+    while let Some(node) = stack.pop() {
+        let metadata_sum: i32 = node.metadata.iter().sum();
+        total += metadata_sum;
+
+        stack.extend(node.children);
+    }
+
+    total
+}
+
+fn part_2(root_node: Node) -> i32 {
+    root_node.part_2_get_value()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input_string = r###"
+2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2
+        "###;
+
+        assert_eq!(part_1(parse_input(input_string).unwrap()), 138);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = r###"
+2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2
+        "###;
+
+        assert_eq!(part_2(parse_input(input_string).unwrap()), 66);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // the second child is missing its last metadata entry
+        let error = parse_input("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 34: expected an integer, found end of line"
+        );
+
+        let error = parse_input("1 1 0 -1 5 5").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+
+        let error = parse_input("0 1 5 6").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+    }
+}
@@ -0,0 +1,201 @@
+// https://adventofcode.com/2018/day/9
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::collections::VecDeque;
+
+type Score = usize;
+
+struct GameState {
+    state: VecDeque<usize>,
+    // number of marbles added
+    num_of_marbles_added: usize,
+    players: Vec<Score>,
+}
+
+impl GameState {
+    fn new(num_of_players: usize) -> GameState {
+        // each player has an initial score of 0
+        let players = vec![0; num_of_players];
+
+        // the head of the ring buffer is always the current position
+        let mut state = VecDeque::new();
+        state.push_front(0);
+
+        GameState {
+            // initially, the marble numbered 0 is placed within the circle
+            state,
+            num_of_marbles_added: 1,
+            players,
+        }
+    }
+
+    fn max_score(&self) -> Score {
+        *self.players.iter().max().unwrap()
+    }
+
+    fn move_clockwise(&mut self, amount: usize) {
+        for _ in 1..=amount {
+            // remove first item and add it to the end of the ring buffer
+            let first = self.state.pop_front().unwrap();
+            self.state.push_back(first);
+        }
+    }
+
+    fn move_counter_clockwise(&mut self, amount: usize) {
+        for _ in 1..=amount {
+            // remove the last item and add it to the front of the ring buffer
+            let x = self.state.pop_back().unwrap();
+            self.state.push_front(x);
+        }
+    }
+
+    fn add_marble(&mut self) {
+        let value_of_next_marble = self.num_of_marbles_added;
+
+        if value_of_next_marble.is_multiple_of(23) {
+            // zero-based index
+            let current_player = (value_of_next_marble - 1) % self.players.len();
+
+            // add new marble to current player's score
+            self.players[current_player] += value_of_next_marble;
+
+            // the marble 7 marbles counter-clockwise from the current marble is removed from the circle
+            // and also added to the current player's score.
+            self.move_counter_clockwise(7);
+            let removed_marble = self.state.pop_front().unwrap();
+            self.players[current_player] += removed_marble;
+
+            // The marble located immediately clockwise of the marble that was removed becomes the new current marble.
+
+            self.num_of_marbles_added += 1;
+            return;
+        }
+
+        // update game state
+
+        self.move_clockwise(2);
+        self.state.push_front(value_of_next_marble);
+        self.num_of_marbles_added += 1;
+    }
+}
+
+// e.g. 10 players; last marble is worth 1618 points
+fn parse_input(input_string: &str) -> Result<(usize, usize), ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+
+    let start = scanner;
+    let num_of_players: usize = scanner.integer()?;
+    if num_of_players == 0 {
+        return Err(start.error("at least 1 player"));
+    }
+    scanner.literal(" players; last marble is worth ")?;
+    let last_marble: usize = scanner.integer()?;
+    scanner.literal(" points")?;
+    scanner.end()?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok((num_of_players, last_marble))
+}
+
+fn play(num_of_players: usize, last_marble: usize) -> Score {
+    // init marble game with the first marble in the circle
+    let mut game_state: GameState = GameState::new(num_of_players);
+
+    for _idx in 1..=last_marble {
+        game_state.add_marble();
+    }
+
+    game_state.max_score()
+}
+
+fn part_1(input_string: &str) -> Option<Score> {
+    let (num_of_players, last_marble) = parse_input(input_string).ok()?;
+    Some(play(num_of_players, last_marble))
+}
+
+// None if the value of the last marble overflows
+fn part_2(input_string: &str) -> Option<Score> {
+    let (num_of_players, last_marble) = parse_input(input_string).ok()?;
+    Some(play(num_of_players, last_marble.checked_mul(100)?))
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            part_1("10 players; last marble is worth 1618 points"),
+            Some(8317)
+        );
+        assert_eq!(
+            part_1("13 players; last marble is worth 7999 points"),
+            Some(146373)
+        );
+        assert_eq!(
+            part_1("17 players; last marble is worth 1104 points"),
+            Some(2764)
+        );
+        assert_eq!(
+            part_1("21 players; last marble is worth 6111 points"),
+            Some(54718)
+        );
+        assert_eq!(
+            part_1("30 players; last marble is worth 5807 points"),
+            Some(37305)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("10 players; last marble is worth x points").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 34: expected an integer, found \"x\""
+        );
+
+        let error = parse_input("0 players; last marble is worth 1618 points").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected at least 1 player, found \"0\""
+        );
+
+        let error = parse_input("10 players, last marble is worth 1618 points").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+
+        assert_eq!(
+            part_2("10 players; last marble is worth 18446744073709551615 points"),
+            None
+        );
+    }
+}
@@ -0,0 +1,827 @@
+// https://adventofcode.com/2018/day/10
+
+use aoc_core::{scan_lines, ParseError, Part, Scanner, Solution, Variant};
+
+// imports
+
+extern crate image;
+
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+struct Star {
+    position: (i64, i64),
+    velocity: (i64, i64),
+}
+
+impl Star {
+    fn wait_for(&mut self, seconds: i64) {
+        let (x, y) = self.position;
+        let (x_velocity, y_velocity) = self.velocity;
+
+        self.position = (x + x_velocity * seconds, y + y_velocity * seconds);
+    }
+}
+
+// e.g. < 9,  1>
+fn parse_pair(scanner: &mut Scanner) -> Result<(i64, i64), ParseError> {
+    scanner.literal("<")?;
+    scanner.skip_whitespace();
+    let x = scanner.integer()?;
+    scanner.literal(",")?;
+    scanner.skip_whitespace();
+    let y = scanner.integer()?;
+    scanner.skip_whitespace();
+    scanner.literal(">")?;
+
+    Ok((x, y))
+}
+
+// e.g. position=< 9,  1> velocity=< 0,  2>
+fn parse_input_to_star(mut scanner: Scanner) -> Result<Star, ParseError> {
+    scanner.literal("position=")?;
+    let position = parse_pair(&mut scanner)?;
+    scanner.skip_whitespace();
+    scanner.literal("velocity=")?;
+    let velocity = parse_pair(&mut scanner)?;
+    scanner.end()?;
+
+    Ok(Star { position, velocity })
+}
+
+// the smallest and largest x and y coordinates of the stars, i.e. (min_x, max_x, min_y, max_y);
+// None if there are no stars
+fn bounds(stars: &[Star]) -> Option<(i64, i64, i64, i64)> {
+    let max_x = stars.iter().map(|star| star.position.0).max()?;
+    let min_x = stars.iter().map(|star| star.position.0).min()?;
+    let max_y = stars.iter().map(|star| star.position.1).max()?;
+    let min_y = stars.iter().map(|star| star.position.1).min()?;
+
+    Some((min_x, max_x, min_y, max_y))
+}
+
+// the area of the bounding box of the stars after waiting for the given number of seconds;
+// None if there are no stars
+fn area_at(stars: &[Star], seconds: i64) -> Option<i128> {
+    let positions = || {
+        stars.iter().map(|star| {
+            let (x, y) = star.position;
+            let (x_velocity, y_velocity) = star.velocity;
+            (
+                x as i128 + x_velocity as i128 * seconds as i128,
+                y as i128 + y_velocity as i128 * seconds as i128,
+            )
+        })
+    };
+
+    let max_x = positions().map(|(x, _y)| x).max()?;
+    let min_x = positions().map(|(x, _y)| x).min()?;
+    let max_y = positions().map(|(_x, y)| y).max()?;
+    let min_y = positions().map(|(_x, y)| y).min()?;
+
+    Some((max_x - min_x + 1) * (max_y - min_y + 1))
+}
+
+// The number of seconds after which the stars are the closest together; i.e. when the sum of
+// the squared distances between every pair of stars is the smallest. That sum is a quadratic in
+// the number of seconds, and is the smallest at:
+//
+//   -sum((p_i - p_j) . (v_i - v_j)) / sum(|v_i - v_j|^2)
+//
+// over every pair of stars i and j; which is the same as the sum over every star of the
+// positions and velocities relative to their mean.
+//
+// None if there are no stars.
+fn estimate_alignment(stars: &[Star]) -> Option<i64> {
+    if stars.is_empty() {
+        return None;
+    }
+
+    let num_of_stars = stars.len() as f64;
+
+    let mean = |value: fn(&Star) -> (i64, i64)| {
+        let (sum_x, sum_y) = stars.iter().fold((0.0, 0.0), |(sum_x, sum_y), star| {
+            let (x, y) = value(star);
+            (sum_x + x as f64, sum_y + y as f64)
+        });
+        (sum_x / num_of_stars, sum_y / num_of_stars)
+    };
+
+    let (mean_x, mean_y) = mean(|star| star.position);
+    let (mean_x_velocity, mean_y_velocity) = mean(|star| star.velocity);
+
+    let (numerator, denominator) =
+        stars
+            .iter()
+            .fold((0.0, 0.0), |(numerator, denominator), star| {
+                let x = star.position.0 as f64 - mean_x;
+                let y = star.position.1 as f64 - mean_y;
+                let x_velocity = star.velocity.0 as f64 - mean_x_velocity;
+                let y_velocity = star.velocity.1 as f64 - mean_y_velocity;
+
+                (
+                    numerator + x * x_velocity + y * y_velocity,
+                    denominator + x_velocity * x_velocity + y_velocity * y_velocity,
+                )
+            });
+
+    // the stars move together, and never come any closer
+    if denominator == 0.0 {
+        return Some(0);
+    }
+
+    Some((-numerator / denominator).round().max(0.0) as i64)
+}
+
+// returns the number of seconds waited for the stars to align, and the aligned stars;
+// None if there are no stars
+//
+// The bounding box of the stars is the smallest around the time that they are the closest
+// together, and is found by stepping from that time towards smaller areas.
+fn find_smallest_area(stars: Vec<Star>) -> Option<(i64, Vec<Star>)> {
+    let mut seconds = estimate_alignment(&stars)?;
+
+    while seconds > 0 && area_at(&stars, seconds - 1)? < area_at(&stars, seconds)? {
+        seconds -= 1;
+    }
+
+    while area_at(&stars, seconds + 1)? < area_at(&stars, seconds)? {
+        seconds += 1;
+    }
+
+    let stars = stars
+        .into_iter()
+        .map(|mut star| {
+            star.wait_for(seconds);
+            star
+        })
+        .collect();
+
+    Some((seconds, stars))
+}
+
+// returns the number of seconds waited for the stars to align, and the aligned stars;
+// None if there are no stars
+fn find_smallest_area_stepping(stars: Vec<Star>) -> Option<(i64, Vec<Star>)> {
+    // let wait_for = 10124;
+    // let mut wait_for = 10000;
+    let mut wait_for = 0;
+
+    let mut best_stars: Vec<Star> = stars
+        .into_iter()
+        .map(|mut star| {
+            star.wait_for(wait_for);
+            star
+        })
+        .collect();
+
+    let mut smallest_area = None;
+
+    loop {
+        let stars: Vec<Star> = best_stars
+            .iter()
+            .map(|star| {
+                let mut star = star.clone();
+                star.wait_for(1);
+                star
+            })
+            .collect();
+
+        let (min_x, max_x, min_y, max_y) = bounds(&stars)?;
+
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+
+        assert!(width >= 0);
+        assert!(height >= 0);
+        let area: i64 = height * width;
+        // println!("wait for: {} area: {}", wait_for, area);
+
+        match smallest_area {
+            None => {
+                smallest_area = Some(area);
+            }
+            Some(best_area) => {
+                if area > best_area {
+                    // The stars align if you wait for wait_for - 1 seconds.
+                    return Some((wait_for - 1, best_stars));
+                }
+
+                best_stars = stars;
+                smallest_area = Some(area);
+            }
+        }
+
+        wait_for += 1;
+    }
+}
+
+// None if the puzzle input is malformed, or has no stars
+pub fn generate_img_buffer(input_string: String) -> Option<image::GrayImage> {
+    let (_seconds, inputs) = find_smallest_area(parse_input(&input_string).ok()?)?;
+
+    let (min_x, max_x, min_y, max_y) = bounds(&inputs)?;
+
+    // println!("min_x: {}", min_x);
+    // println!("min_y: {}", min_y);
+
+    // println!("max_x: {}", max_x);
+    // println!("max_y: {}", max_y);
+
+    assert!(max_x > 0);
+    assert!(min_x >= 0);
+    assert!(max_y > 0);
+    assert!(min_y >= 0);
+
+    let margin_gap = 10;
+
+    let x_adjustment = if min_x < 0 { min_x.abs() } else { -min_x };
+
+    let y_adjustment = if min_y < 0 { min_y.abs() } else { -min_y };
+
+    let width = (max_x - min_x + 1) + margin_gap * 2;
+    let height = (max_y - min_y + 1) + margin_gap * 2;
+
+    let mut img_buffer = image::GrayImage::new(width as u32, height as u32);
+
+    for star in inputs.iter() {
+        let (x, y) = star.position;
+
+        let x = x_adjustment + x + margin_gap;
+        let y = y_adjustment + y + margin_gap;
+
+        assert!(x < width);
+        assert!(x >= 0);
+        assert!(y < height);
+        assert!(y >= 0);
+
+        let pixel = img_buffer.get_pixel_mut(x as u32, y as u32);
+
+        let color: u8 = 255u8;
+        *pixel = image::Luma([color]);
+    }
+
+    Some(img_buffer)
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Star>, ParseError> {
+    scan_lines(input_string).map(parse_input_to_star).collect()
+}
+
+// the rows of the area the stars are in, where a star is a '#'; no rows if there are no stars
+fn to_rows(stars: &[Star]) -> Vec<String> {
+    let (min_x, max_x, min_y, max_y) = match bounds(stars) {
+        None => return vec![],
+        Some(bounds) => bounds,
+    };
+
+    let positions: HashSet<(i64, i64)> = stars.iter().map(|star| star.position).collect();
+
+    let mut rows: Vec<String> = vec![];
+
+    for y in min_y..=max_y {
+        let row: String = (min_x..=max_x)
+            .map(|x| {
+                if positions.contains(&(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        rows.push(row);
+    }
+
+    rows
+}
+
+fn render_message(stars: &[Star]) -> String {
+    // start on a new line so that the message is aligned when printed
+    format!("\n{}", to_rows(stars).join("\n"))
+}
+
+// The letters of the fonts of the messages in Advent of Code puzzles; e.g. 2018 day 10 uses the
+// font that is 10 rows tall, and 2016 day 8 uses the font that is 6 rows tall.
+//
+// The glyphs are stored without the blank columns on either side of them; which is how they are
+// cut out of a message.
+
+const FONT_6X10: &[(char, &[&str])] = &[
+    (
+        'A',
+        &[
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        &[
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        &[
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        &[
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        &[
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        &[
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        &[
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        &[
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+const FONT_4X6: &[(char, &[&str])] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// the letters of the message, if the stars spell out letters of either font
+fn read_message(stars: &[Star]) -> Option<String> {
+    let rows: Vec<Vec<char>> = to_rows(stars)
+        .iter()
+        .map(|row| row.chars().collect())
+        .collect();
+
+    let font = match rows.len() {
+        10 => FONT_6X10,
+        6 => FONT_4X6,
+        _ => return None,
+    };
+
+    let is_blank_column = |x: usize| rows.iter().all(|row| row[x] == '.');
+    let width = rows[0].len();
+
+    let mut message = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if is_blank_column(x) {
+            x += 1;
+            continue;
+        }
+
+        // the letters are told apart by the blank columns between them
+        let start = x;
+        while x < width && !is_blank_column(x) {
+            x += 1;
+        }
+
+        let glyph: Vec<String> = rows
+            .iter()
+            .map(|row| row[start..x].iter().collect())
+            .collect();
+
+        let (letter, _glyph) = font.iter().find(|(_letter, font_glyph)| {
+            font_glyph
+                .iter()
+                .zip(glyph.iter())
+                .all(|(font_row, row)| font_row == row)
+        })?;
+
+        message.push(*letter);
+    }
+
+    Some(message)
+}
+
+// What message will eventually appear in the sky?
+//
+// The stars are drawn as they are, if they do not spell out letters of a known font.
+fn part_1(input_string: &str) -> Option<String> {
+    let (_seconds, stars) = find_smallest_area(parse_input(input_string).ok()?)?;
+    Some(read_message(&stars).unwrap_or_else(|| render_message(&stars)))
+}
+
+// how many seconds would they have needed to wait for that message to appear?
+fn part_2(input_string: &str) -> Option<i64> {
+    let (seconds, _stars) = find_smallest_area(parse_input(input_string).ok()?)?;
+    Some(seconds)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string)
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![Variant {
+            name: "stepping",
+            part: Part::Two,
+            solve: |input_string| {
+                let (seconds, _stars) =
+                    find_smallest_area_stepping(parse_input(input_string).ok()?)?;
+                Some(seconds.to_string())
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r###"
+position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>
+"###;
+
+    fn stars_from_rows(rows: &[&str]) -> Vec<Star> {
+        rows.iter()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .filter(|(_x, cell)| *cell == '#')
+                    .map(move |(x, _cell)| Star {
+                        position: (x as i64, y as i64),
+                        velocity: (0, 0),
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_find_smallest_area() {
+        assert_eq!(
+            find_smallest_area_stepping(parse_input(EXAMPLE).unwrap())
+                .unwrap()
+                .0,
+            3
+        );
+        assert_eq!(
+            find_smallest_area(parse_input(EXAMPLE).unwrap()).unwrap().0,
+            3
+        );
+
+        let stars = parse_input(include_str!("input.txt")).unwrap();
+        assert_eq!(find_smallest_area_stepping(stars.clone()).unwrap().0, 10124);
+        assert_eq!(find_smallest_area(stars.clone()).unwrap().0, 10124);
+
+        // the same stars, going back in time until they align in tens of millions of seconds
+        let extra_seconds = 50_000_000;
+        let stars: Vec<Star> = stars
+            .into_iter()
+            .map(|mut star| {
+                star.wait_for(-extra_seconds);
+                star
+            })
+            .collect();
+
+        let (seconds, stars) = find_smallest_area(stars).unwrap();
+        assert_eq!(seconds, 10124 + extra_seconds);
+        assert_eq!(read_message(&stars), Some("XECXBPZB".to_string()));
+
+        // stars that move together are aligned from the start
+        let stars = vec![
+            Star {
+                position: (0, 0),
+                velocity: (1, 2),
+            },
+            Star {
+                position: (5, 5),
+                velocity: (1, 2),
+            },
+        ];
+        assert_eq!(find_smallest_area(stars).unwrap().0, 0);
+    }
+
+    #[test]
+    fn test_no_stars() {
+        assert_eq!(area_at(&[], 3), None);
+        assert_eq!(estimate_alignment(&[]), None);
+        assert!(find_smallest_area(vec![]).is_none());
+        assert!(find_smallest_area_stepping(vec![]).is_none());
+        assert!(to_rows(&[]).is_empty());
+
+        assert_eq!(part_1(""), None);
+        assert_eq!(part_2(""), None);
+        assert!(generate_img_buffer("".to_string()).is_none());
+    }
+
+    #[test]
+    fn test_read_message() {
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_1(input_string), Some("XECXBPZB".to_string()));
+
+        let stars = stars_from_rows(&[
+            "#..#..###..#...#..##.",
+            "#..#...#...#...#.#..#",
+            "####...#....#.#..#..#",
+            "#..#...#.....#...####",
+            "#..#...#.....#...#..#",
+            "#..#..###....#...#..#",
+        ]);
+        assert_eq!(read_message(&stars), Some("HIYA".to_string()));
+
+        // the example is in neither font
+        let (_seconds, stars) = find_smallest_area(parse_input(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(read_message(&stars), None);
+        assert_eq!(
+            part_1(EXAMPLE).unwrap(),
+            r###"
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###"###
+        );
+
+        let error = parse_input("position=< 9,  1> velocity=< 0  2>").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 31: expected \",\", found \" \""
+        );
+    }
+
+    #[test]
+    fn test_generate_img_buffer() {
+        let input_string = EXAMPLE;
+
+        let expected = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0,
+            255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0,
+            0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 255, 255,
+            255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+
+        let img_buffer = generate_img_buffer(input_string.to_string()).unwrap();
+
+        assert_eq!(img_buffer.into_vec(), expected);
+
+        let input_string = include_str!("input.txt");
+
+        let expected = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255,
+            255, 255, 255, 255, 255, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0,
+            0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 255, 255,
+            255, 255, 255, 0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0,
+            0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0,
+            0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0,
+            0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0,
+            255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0,
+            0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0,
+            255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 255,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0,
+            255, 255, 255, 255, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0,
+            255, 255, 255, 255, 255, 0, 0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 255, 0, 0,
+            0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 255, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            255, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0,
+            0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 0, 0,
+            0, 0, 255, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0,
+            0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0,
+            0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0,
+            255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0,
+            255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0, 255, 255,
+            255, 255, 255, 255, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 255, 0, 0, 0, 0, 255, 0, 0,
+            255, 255, 255, 255, 255, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255,
+            255, 0, 0, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0,
+        ];
+
+        let img_buffer = generate_img_buffer(input_string.to_string()).unwrap();
+
+        assert_eq!(img_buffer.into_vec(), expected);
+    }
+}
@@ -0,0 +1,778 @@
+// https://adventofcode.com/2018/day/12
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Part, Scanner, Solution, Variant};
+use automaton::{Cycle, Simulation};
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+// helpers and types
+
+type PotIndex = i32;
+type State = BTreeMap<PotIndex, PotState>;
+type Rules = HashMap<InitialRule, Rule>;
+
+fn has_plant(x: char) -> bool {
+    x == '#'
+}
+
+fn is_valid_plant_state(x: char) -> bool {
+    x == '.' || x == '#'
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+enum PotState {
+    NoPlant,
+    HasPlant,
+}
+
+impl PotState {
+    fn from_char(x: char) -> PotState {
+        assert!(is_valid_plant_state(x));
+
+        if has_plant(x) {
+            return PotState::HasPlant;
+        }
+
+        PotState::NoPlant
+    }
+
+    fn has_plant(&self) -> bool {
+        match self {
+            PotState::HasPlant => true,
+            PotState::NoPlant => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct InitialRule {
+    left: (PotState, PotState),
+    current: PotState,
+    right: (PotState, PotState),
+}
+
+impl InitialRule {
+    // the pots LLCRR as a binary number, where the leftmost pot is the highest bit
+    fn to_pattern(&self) -> usize {
+        let pots = [
+            &self.left.0,
+            &self.left.1,
+            &self.current,
+            &self.right.0,
+            &self.right.1,
+        ];
+
+        pots.iter().fold(0, |pattern, pot| {
+            (pattern << 1) | (pot.has_plant() as usize)
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    // Representation of LLCRR => N
+    // where L are pots to the left,
+    // C is the current pot being considered,
+    // R are the pots to the right,
+    // and N is whether the current pot will have a plant in the next generation.
+    initial_rule: InitialRule,
+    next: PotState,
+}
+
+impl Rule {
+    // e.g. ..#.. => #
+    fn parse(mut scanner: Scanner) -> Result<Rule, ParseError> {
+        let mut parse_pot = || -> Result<PotState, ParseError> {
+            let pot = scanner.char("\"#\" or \".\"", is_valid_plant_state)?;
+            Ok(PotState::from_char(pot))
+        };
+
+        let initial_rule = InitialRule {
+            left: (parse_pot()?, parse_pot()?),
+            current: parse_pot()?,
+            right: (parse_pot()?, parse_pot()?),
+        };
+
+        scanner.skip_whitespace();
+        scanner.literal("=>")?;
+        scanner.skip_whitespace();
+
+        let next_state = PotState::from_char(scanner.char("\"#\" or \".\"", is_valid_plant_state)?);
+
+        scanner.end()?;
+
+        Ok(Rule {
+            initial_rule,
+
+            next: next_state,
+        })
+    }
+}
+
+fn get_pot_from_state(state: &State, index: PotIndex) -> PotState {
+    match state.get(&index) {
+        None => PotState::NoPlant,
+        Some(plant_state) => plant_state.clone(),
+    }
+}
+
+fn generate_next_state(mut state: State, rules: &Rules) -> State {
+    {
+        // extend state by considering two pots on both ends;
+        // only if there are pots with plants on either ends
+        let keys: Vec<PotIndex> = state.keys().cloned().collect();
+        let highest: PotIndex = *keys.iter().max().unwrap();
+        let lowest: PotIndex = *keys.iter().min().unwrap();
+
+        if state.get(&highest).unwrap().has_plant() {
+            state.insert(highest + 1, PotState::NoPlant);
+            state.insert(highest + 2, PotState::NoPlant);
+        }
+
+        if state.get(&lowest).unwrap().has_plant() {
+            state.insert(lowest - 1, PotState::NoPlant);
+            state.insert(lowest - 2, PotState::NoPlant);
+        }
+    };
+
+    let mut next_state = state.clone();
+
+    for (pot_index, plant_state) in state.iter() {
+        let left_plant_2: PotState = get_pot_from_state(&state, pot_index - 2);
+        let left_plant_1: PotState = get_pot_from_state(&state, pot_index - 1);
+        let right_plant_1: PotState = get_pot_from_state(&state, pot_index + 1);
+        let right_plant_2: PotState = get_pot_from_state(&state, pot_index + 2);
+
+        let rule_needle = InitialRule {
+            left: (left_plant_2, left_plant_1),
+            current: plant_state.clone(),
+            right: (right_plant_1, right_plant_2),
+        };
+
+        if rules.contains_key(&rule_needle) {
+            // assert!(rules.contains_key(&rule_needle));
+
+            let next_plant_state = &rules.get(&rule_needle).unwrap().next;
+
+            next_state.insert(*pot_index, next_plant_state.clone());
+        } else {
+            next_state.insert(*pot_index, PotState::NoPlant);
+        }
+    }
+
+    next_state
+}
+
+#[allow(dead_code)]
+fn state_to_string(state: &State) -> String {
+    let state_string: String = state
+        .iter()
+        .map(|(key, plant_state)| {
+            if *key < 0 || *key > 120 {
+                return "";
+            }
+
+            match plant_state {
+                PotState::HasPlant => "#",
+                PotState::NoPlant => ".",
+            }
+        })
+        .collect();
+    state_string
+}
+
+fn state_to_sum(state: &State) -> i32 {
+    state
+        .iter()
+        .map(|(pot_index, plant_state)| -> i32 {
+            match plant_state {
+                PotState::HasPlant => *pot_index,
+                PotState::NoPlant => 0,
+            }
+        })
+        .sum()
+}
+
+// the plants relative to the leftmost plant; a pattern that keeps its shape while drifting along
+// the pots has the same key in every generation
+fn state_to_key(state: &State) -> Vec<PotIndex> {
+    let mut plants = state
+        .iter()
+        .filter(|(_pot_index, plant_state)| plant_state.has_plant())
+        .map(|(pot_index, _plant_state)| *pot_index);
+
+    let first_plant = match plants.next() {
+        Some(pot_index) => pot_index,
+        None => return vec![],
+    };
+
+    std::iter::once(0)
+        .chain(plants.map(|pot_index| pot_index - first_plant))
+        .collect()
+}
+
+// The rules as a lookup table of the next state of a pot, indexed by the pattern of the pots
+// around it.
+#[derive(Debug, Clone, Copy)]
+struct RuleTable(u32);
+
+impl RuleTable {
+    fn new(rules: &Rules) -> RuleTable {
+        let table = rules
+            .values()
+            .filter(|rule| rule.next.has_plant())
+            .fold(0, |table, rule| {
+                table | (1 << rule.initial_rule.to_pattern())
+            });
+
+        RuleTable(table)
+    }
+
+    fn has_plant(&self, pattern: usize) -> bool {
+        (self.0 >> pattern) & 1 == 1
+    }
+}
+
+const WORD_SIZE: usize = 64;
+
+fn get_bit(words: &[u64], index: usize) -> bool {
+    (words[index / WORD_SIZE] >> (index % WORD_SIZE)) & 1 == 1
+}
+
+fn set_bit(words: &mut [u64], index: usize) {
+    words[index / WORD_SIZE] |= 1 << (index % WORD_SIZE);
+}
+
+// A row of pots that goes on forever in both directions. The pots outside of the cells are all
+// in the background state; e.g. with a ..... => # rule, the empty pots on either side of the
+// plants all grow a plant in the next generation.
+//
+// The cells are trimmed, so that the first and last cells differ from the background.
+#[derive(Debug, Clone)]
+struct Tape {
+    background: bool,
+    // the pot index of the first cell
+    offset: i64,
+    len: usize,
+    // a bit for every cell; the bits after the last cell are unset
+    words: Vec<u64>,
+}
+
+// a tape with its offset left out; which is the same for a pattern wherever it is along the row
+type Pattern = (bool, usize, Vec<u64>);
+type ToPattern = fn(&Tape) -> Pattern;
+
+impl Tape {
+    fn from_state(state: &State) -> Tape {
+        let plants: Vec<i64> = state
+            .iter()
+            .filter(|(_pot_index, plant_state)| plant_state.has_plant())
+            .map(|(pot_index, _plant_state)| *pot_index as i64)
+            .collect();
+
+        let offset = plants.first().copied().unwrap_or(0);
+        let len = plants.last().map_or(0, |last| (last - offset + 1) as usize);
+
+        let mut words = vec![0; len.div_ceil(WORD_SIZE)];
+        for pot_index in plants {
+            set_bit(&mut words, (pot_index - offset) as usize);
+        }
+
+        Tape {
+            background: false,
+            offset,
+            len,
+            words,
+        }
+    }
+
+    fn has_plant(&self, pot_index: i64) -> bool {
+        if pot_index < self.offset || pot_index >= self.offset + self.len as i64 {
+            return self.background;
+        }
+
+        get_bit(&self.words, (pot_index - self.offset) as usize)
+    }
+
+    fn step(&mut self, rules: RuleTable) {
+        let background_pattern = if self.background { 0b11111 } else { 0 };
+        let background = rules.has_plant(background_pattern);
+
+        // only the pots within two pots of the cells can end up differing from the background
+        let first_pot = self.offset - 2;
+        let num_of_pots = self.len + 4;
+
+        let mut next: Vec<bool> = Vec::with_capacity(num_of_pots);
+        let mut pattern = (first_pot - 2..first_pot + 2).fold(0, |pattern, pot_index| {
+            (pattern << 1) | (self.has_plant(pot_index) as usize)
+        });
+
+        for pot_index in first_pot..first_pot + num_of_pots as i64 {
+            pattern = ((pattern << 1) | (self.has_plant(pot_index + 2) as usize)) & 0b11111;
+            next.push(rules.has_plant(pattern));
+        }
+
+        let first = next.iter().position(|pot| *pot != background);
+        let last = next.iter().rposition(|pot| *pot != background);
+
+        let (first, last) = match (first, last) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                *self = Tape {
+                    background,
+                    offset: 0,
+                    len: 0,
+                    words: vec![],
+                };
+                return;
+            }
+        };
+
+        let len = last - first + 1;
+        let mut words = vec![0; len.div_ceil(WORD_SIZE)];
+        for (index, pot) in next[first..=last].iter().enumerate() {
+            if *pot {
+                set_bit(&mut words, index);
+            }
+        }
+
+        *self = Tape {
+            background,
+            offset: first_pot + first as i64,
+            len,
+            words,
+        };
+    }
+
+    fn to_pattern(&self) -> Pattern {
+        (self.background, self.len, self.words.clone())
+    }
+
+    // None when every pot in the background has a plant, which are infinitely many
+    fn num_of_plants(&self) -> Option<i128> {
+        if self.background {
+            return None;
+        }
+
+        Some(
+            self.words
+                .iter()
+                .map(|word| word.count_ones() as i128)
+                .sum(),
+        )
+    }
+
+    fn pot_sum(&self) -> Option<i128> {
+        if self.background {
+            return None;
+        }
+
+        let sum = (0..self.len)
+            .filter(|index| get_bit(&self.words, *index))
+            .map(|index| self.offset as i128 + index as i128)
+            .sum();
+
+        Some(sum)
+    }
+}
+
+// The most generations that are simulated while looking for the pots to repeat, by default.
+const GENERATION_LIMIT: usize = 10_000;
+
+// How the pots repeat, from the start generation onwards, every length generations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Recurrence {
+    // the same pots come back
+    Cycle {
+        start: usize,
+        length: usize,
+    },
+    // the same pattern comes back, shifted along the row by the given number of pots
+    Glider {
+        start: usize,
+        length: usize,
+        shift: i64,
+    },
+}
+
+pub struct Pots {
+    tape: Tape,
+    rules: RuleTable,
+    generation_limit: usize,
+}
+
+impl Pots {
+    pub fn parse(input_string: &str) -> Result<Pots, ParseError> {
+        let (state, rules) = parse_input(input_string)?;
+
+        Ok(Pots {
+            tape: Tape::from_state(&state),
+            rules: RuleTable::new(&rules),
+            generation_limit: GENERATION_LIMIT,
+        })
+    }
+
+    pub fn with_generation_limit(mut self, generation_limit: usize) -> Self {
+        self.generation_limit = generation_limit;
+        self
+    }
+
+    fn simulation(&self) -> Simulation<Tape, impl Fn(&mut Tape), ToPattern> {
+        let rules = self.rules;
+
+        Simulation::new(
+            self.tape.clone(),
+            move |tape: &mut Tape| tape.step(rules),
+            Tape::to_pattern as ToPattern,
+        )
+        .with_step_limit(self.generation_limit)
+    }
+
+    // None if the pattern does not repeat within the generation limit; e.g. when it keeps growing
+    pub fn recurrence(&self) -> Option<Recurrence> {
+        let simulation = self.simulation();
+        let Cycle { start, length } = simulation.find_cycle()?;
+
+        let shift = simulation.run(start + length).offset - simulation.run(start).offset;
+
+        let recurrence = if shift == 0 {
+            Recurrence::Cycle { start, length }
+        } else {
+            Recurrence::Glider {
+                start,
+                length,
+                shift,
+            }
+        };
+
+        Some(recurrence)
+    }
+
+    // The sum of the indices of the pots with a plant after the given number of generations.
+    // None if there are infinitely many plants, or if the pattern does not repeat within the
+    // generation limit and the generation is beyond it.
+    pub fn sum_at(&self, generation: u64) -> Option<i128> {
+        let simulation = self.simulation();
+
+        let (start, length, shift) = match self.recurrence() {
+            Some(Recurrence::Cycle { start, length }) => (start, length, 0),
+            Some(Recurrence::Glider {
+                start,
+                length,
+                shift,
+            }) => (start, length, shift),
+            None if generation <= self.generation_limit as u64 => {
+                return simulation.run(generation as usize).pot_sum();
+            }
+            None => return None,
+        };
+
+        let (generation, num_of_passes) = Cycle { start, length }.reduce(generation);
+        let tape = simulation.run(generation);
+
+        // every plant moves along by the shift on every pass
+        let shifted = (num_of_passes as i128)
+            .checked_mul(shift as i128)?
+            .checked_mul(tape.num_of_plants()?)?;
+
+        tape.pot_sum()?.checked_add(shifted)
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<(State, Rules), ParseError> {
+    let mut lines = scan_lines(input_string);
+
+    let mut state: State = BTreeMap::new();
+
+    // add initial state
+
+    let mut initial_state = match lines.next() {
+        Some(scanner) => scanner,
+        None => return Err(Scanner::new(1, "").error("the initial state")),
+    };
+
+    initial_state.literal("initial state:")?;
+    initial_state.skip_whitespace();
+
+    let mut pot_index = 0;
+    while !initial_state.is_at_end() {
+        let has_plant_char = initial_state.char("\"#\" or \".\"", is_valid_plant_state)?;
+        state.insert(pot_index, PotState::from_char(has_plant_char));
+        pot_index += 1;
+    }
+
+    // blank lines are skipped by scan_lines
+
+    let mut rules: Rules = HashMap::new();
+
+    for scanner in lines {
+        let rule = Rule::parse(scanner)?;
+        let initial_rule = rule.initial_rule.clone();
+
+        if rules.contains_key(&initial_rule) {
+            return Err(scanner.error("a rule for a pattern that has no rule yet"));
+        }
+
+        rules.insert(initial_rule, rule);
+    }
+
+    Ok((state, rules))
+}
+
+fn part_1(pots: &Pots) -> Option<i128> {
+    pots.sum_at(20)
+}
+
+fn part_2(pots: &Pots) -> Option<i128> {
+    pots.sum_at(50_000_000_000)
+}
+
+fn part_1_btree(mut state: State, rules: &Rules) -> i32 {
+    let num_of_generations = 20;
+
+    for _generation in 1..=num_of_generations {
+        state = generate_next_state(state, rules);
+
+        // Debug
+        // println!("{}", state_to_string(&state));
+    }
+
+    state_to_sum(&state)
+}
+
+fn part_2_btree(state: State, rules: &Rules) -> Option<i64> {
+    let simulation = Simulation::new(
+        state,
+        |state: &mut State| *state = generate_next_state(std::mem::take(state), rules),
+        state_to_key,
+    )
+    .with_step_limit(2000);
+
+    let total = simulation.value_at(50_000_000_000, |state| state_to_sum(state) as i128)?;
+    i64::try_from(total).ok()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        let pots = Pots::parse(input_string).ok()?;
+        Some(part_1(&pots)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        let pots = Pots::parse(input_string).ok()?;
+        Some(part_2(&pots)?.to_string())
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![
+            Variant {
+                name: "btree",
+                part: Part::One,
+                solve: |input_string| {
+                    let (state, rules) = parse_input(input_string).ok()?;
+                    Some(part_1_btree(state, &rules).to_string())
+                },
+            },
+            Variant {
+                name: "btree",
+                part: Part::Two,
+                solve: |input_string| {
+                    let (state, rules) = parse_input(input_string).ok()?;
+                    Some(part_2_btree(state, &rules)?.to_string())
+                },
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r###"
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #
+"###;
+
+    #[test]
+    fn test_part_1() {
+        let (state, rules) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_1_btree(state, &rules), 325);
+
+        let pots = Pots::parse(EXAMPLE).unwrap();
+        assert_eq!(part_1(&pots), Some(325));
+    }
+
+    #[test]
+    fn test_tape() {
+        let (mut state, rules) = parse_input(EXAMPLE).unwrap();
+        let rule_table = RuleTable::new(&rules);
+        let mut tape = Tape::from_state(&state);
+
+        for _generation in 0..200 {
+            assert_eq!(tape.pot_sum(), Some(state_to_sum(&state) as i128));
+
+            let plants: Vec<i64> = (tape.offset..tape.offset + tape.len as i64)
+                .filter(|pot_index| tape.has_plant(*pot_index))
+                .collect();
+            let expected: Vec<i64> = state
+                .iter()
+                .filter(|(_pot_index, plant_state)| plant_state.has_plant())
+                .map(|(pot_index, _plant_state)| *pot_index as i64)
+                .collect();
+            assert_eq!(plants, expected);
+
+            // trimmed
+            assert!(tape.has_plant(tape.offset));
+            assert!(tape.has_plant(tape.offset + tape.len as i64 - 1));
+
+            tape.step(rule_table);
+            state = generate_next_state(state, &rules);
+        }
+    }
+
+    #[test]
+    fn test_recurrence() {
+        let pots = Pots::parse(EXAMPLE).unwrap();
+        assert_eq!(
+            pots.recurrence(),
+            Some(Recurrence::Glider {
+                start: 86,
+                length: 1,
+                shift: 1
+            })
+        );
+
+        let (state, rules) = parse_input(EXAMPLE).unwrap();
+        let state = (0..150).fold(state, |state, _generation| {
+            generate_next_state(state, &rules)
+        });
+        assert_eq!(pots.sum_at(150), Some(state_to_sum(&state) as i128));
+
+        // a plant that stays where it is
+        let pots = Pots::parse("initial state: ..#\n\n..#.. => #").unwrap();
+        assert_eq!(
+            pots.recurrence(),
+            Some(Recurrence::Cycle {
+                start: 0,
+                length: 1
+            })
+        );
+        assert_eq!(pots.sum_at(u64::MAX), Some(2));
+
+        // a plant that moves two pots to the left in every generation
+        let pots = Pots::parse("initial state: #\n\n....# => #").unwrap();
+        assert_eq!(
+            pots.recurrence(),
+            Some(Recurrence::Glider {
+                start: 0,
+                length: 1,
+                shift: -2
+            })
+        );
+        assert_eq!(pots.sum_at(10), Some(-20));
+        assert_eq!(pots.sum_at(u64::MAX), Some(-2 * (u64::MAX as i128)));
+
+        // a row of plants that keeps growing to the left never repeats
+        let rules: String = (0..32)
+            .filter(|pattern| pattern & 0b00110 != 0)
+            .map(|pattern: usize| {
+                let pots: String = (0..5)
+                    .rev()
+                    .map(|bit| if (pattern >> bit) & 1 == 1 { '#' } else { '.' })
+                    .collect();
+                format!("{} => #\n", pots)
+            })
+            .collect();
+        let pots = Pots::parse(&format!("initial state: #\n\n{}", rules))
+            .unwrap()
+            .with_generation_limit(100);
+        assert_eq!(pots.recurrence(), None);
+        assert_eq!(pots.sum_at(3), Some(-6));
+        assert_eq!(pots.sum_at(100), Some(-5050));
+        assert_eq!(pots.sum_at(101), None);
+        assert_eq!(pots.sum_at(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_infinite_tape() {
+        // every pot surrounded by empty pots grows a plant, and every pot surrounded by plants
+        // is left empty
+        let pots = Pots::parse("initial state: #\n\n..... => #\n##### => .").unwrap();
+
+        // every pot grows a plant, except for the pots near the plant at pot 0
+        let mut tape = pots.tape.clone();
+        tape.step(pots.rules);
+        assert!(tape.background);
+        assert_eq!((tape.offset, tape.len), (-2, 5));
+        assert!(!tape.has_plant(0));
+        assert!(tape.has_plant(-1_000_000));
+        assert_eq!(tape.pot_sum(), None);
+
+        // and only the pot in the middle of those grows a plant again
+        tape.step(pots.rules);
+        assert!(!tape.background);
+        assert_eq!((tape.offset, tape.len), (0, 1));
+
+        assert_eq!(
+            pots.recurrence(),
+            Some(Recurrence::Cycle {
+                start: 0,
+                length: 2
+            })
+        );
+        assert_eq!(pots.sum_at(0), Some(0));
+        assert_eq!(pots.sum_at(1), None);
+        assert_eq!(pots.sum_at(2), Some(0));
+        assert_eq!(pots.sum_at(u64::MAX), None);
+        assert_eq!(pots.sum_at(u64::MAX - 1), Some(0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("initial state: #..x").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 19: expected \"#\" or \".\", found \"x\""
+        );
+
+        let error = parse_input("initial state: #..#\n\n...## -> #").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 7));
+        assert_eq!(error.expected, "\"=>\"");
+
+        let error = parse_input("initial state: #\n\n...## => #\n...## => .").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+    }
+}
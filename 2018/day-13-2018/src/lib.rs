@@ -0,0 +1,1273 @@
+// https://adventofcode.com/2018/day/13
+
+// imports
+
+use aoc_core::{ParseError, Scanner, Solution};
+use aoc_grid::{reading_order, Coordinate};
+use core::cmp::Ordering;
+use core::panic;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{PrintStyledContent, Stylize};
+use crossterm::{cursor, queue, terminal};
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt;
+use std::io::{self, Write};
+use std::time::Duration;
+
+// code
+
+#[derive(PartialEq, Hash, Eq, Clone, Debug)]
+struct OrderedCoordinate(Coordinate);
+
+impl OrderedCoordinate {
+    fn coordinate(&self) -> Coordinate {
+        self.0
+    }
+}
+
+impl PartialOrd for OrderedCoordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedCoordinate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        reading_order(&self.0, &other.0)
+    }
+}
+
+impl From<Coordinate> for OrderedCoordinate {
+    fn from(val: Coordinate) -> Self {
+        OrderedCoordinate(val)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Track {
+    // |
+    Vertical,
+    // -
+    Horizontal,
+    // +
+    Intersection,
+
+    // curves
+    // invariant: Curves connect exactly two perpendicular pieces of track
+
+    // match configuration:
+    //   /-
+    //   |
+    BottomAndRight,
+
+    // match configuration:
+    //    |
+    //   -/
+    TopAndLeft,
+
+    // match configuration:
+    //   -\
+    //    |
+    BottomAndLeft,
+    // match configuration:
+    //   |
+    //   \-
+    TopAndRight,
+}
+
+impl fmt::Display for Track {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let result = match self {
+            Track::Vertical => "|",
+            Track::Horizontal => "-",
+            Track::Intersection => "+",
+            Track::BottomAndRight | Track::TopAndLeft => "/",
+            Track::BottomAndLeft | Track::TopAndRight => "\\",
+        };
+
+        write!(f, "{}", result)
+    }
+}
+
+fn is_horizontal(cell: char) -> bool {
+    matches!(cell, '-' | '+')
+}
+
+fn is_vertical(cell: char) -> bool {
+    matches!(cell, '|' | '+')
+}
+
+type Map = HashMap<Coordinate, Track>;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+enum TurningOption {
+    Left,
+    Straight,
+    Right,
+}
+
+impl TurningOption {
+    fn next(&self) -> TurningOption {
+        match self {
+            TurningOption::Left => TurningOption::Straight,
+            TurningOption::Straight => TurningOption::Right,
+            TurningOption::Right => TurningOption::Left,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+enum Orientation {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Orientation {
+    fn turn(&self, turning_option: &TurningOption) -> Orientation {
+        match self {
+            Orientation::Up => match turning_option {
+                TurningOption::Left => Orientation::Left,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Right,
+            },
+            Orientation::Down => match turning_option {
+                TurningOption::Left => Orientation::Right,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Left,
+            },
+            Orientation::Left => match turning_option {
+                TurningOption::Left => Orientation::Down,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Up,
+            },
+            Orientation::Right => match turning_option {
+                TurningOption::Left => Orientation::Up,
+                TurningOption::Straight => self.clone(),
+                TurningOption::Right => Orientation::Down,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+struct Cart {
+    // carts are numbered in reading order of where they start
+    id: usize,
+    orientation: Orientation,
+    // current position
+    position: Coordinate,
+    // when a cart arrives at an intersection, this rule determines the cart's
+    // next destination
+    turning_option: TurningOption,
+}
+
+impl Cart {
+    fn is_cart(cell: char) -> bool {
+        matches!(cell, '^' | 'v' | '<' | '>')
+    }
+
+    fn new(id: usize, cell: char, position: Coordinate) -> Cart {
+        assert!(Cart::is_cart(cell));
+
+        let orientation = match cell {
+            '^' => Orientation::Up,
+            'v' => Orientation::Down,
+            '<' => Orientation::Left,
+            '>' => Orientation::Right,
+            _ => {
+                unreachable!();
+            }
+        };
+
+        Cart {
+            id,
+            orientation,
+            position,
+            turning_option: TurningOption::Left,
+        }
+    }
+
+    fn to_str(&self) -> String {
+        let orientation = match self.orientation {
+            Orientation::Up => "^",
+            Orientation::Down => "v",
+            Orientation::Left => "<",
+            Orientation::Right => ">",
+        };
+
+        orientation.to_string()
+    }
+
+    fn tick(&self, map: &Map) -> Cart {
+        let (x, y) = self.position;
+
+        // println!("{:?}", self.position);
+
+        // generate next position
+
+        let next_position = match self.orientation {
+            Orientation::Up => (x, y - 1),
+            Orientation::Down => (x, y + 1),
+            Orientation::Left => (x - 1, y),
+            Orientation::Right => (x + 1, y),
+        };
+
+        // generate next orientation
+
+        let next_track: Track = match map.get(&next_position) {
+            None => {
+                panic!("No track found at: {:?}", next_position);
+            }
+            Some(track) => track.clone(),
+        };
+
+        let mut next_orientation = self.orientation.clone();
+        let mut next_turning_option = self.turning_option.clone();
+
+        match next_track {
+            Track::BottomAndRight => {
+                next_orientation = match self.orientation {
+                    Orientation::Up => Orientation::Right,
+                    Orientation::Left => Orientation::Down,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::TopAndLeft => {
+                next_orientation = match self.orientation {
+                    Orientation::Down => Orientation::Left,
+                    Orientation::Right => Orientation::Up,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::BottomAndLeft => {
+                next_orientation = match self.orientation {
+                    Orientation::Up => Orientation::Left,
+                    Orientation::Right => Orientation::Down,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::TopAndRight => {
+                next_orientation = match self.orientation {
+                    Orientation::Down => Orientation::Right,
+                    Orientation::Left => Orientation::Up,
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                }
+            }
+            Track::Intersection => {
+                next_orientation = self.orientation.turn(&self.turning_option);
+                next_turning_option = self.turning_option.next();
+            }
+            Track::Vertical => {
+                match self.orientation {
+                    Orientation::Up | Orientation::Down => {}
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                };
+            }
+            Track::Horizontal => {
+                match self.orientation {
+                    Orientation::Left | Orientation::Right => {}
+                    _ => {
+                        unreachable!("Unexpected orientation: {:?}", self.orientation);
+                    }
+                };
+            }
+        }
+
+        Cart {
+            id: self.id,
+            orientation: next_orientation,
+            position: next_position,
+            turning_option: next_turning_option,
+        }
+    }
+}
+
+type CrashedCarts = HashSet<Coordinate>;
+
+struct Carts {
+    carts: BTreeMap<OrderedCoordinate, Cart>,
+}
+
+impl Carts {
+    fn new() -> Carts {
+        Carts {
+            carts: BTreeMap::new(),
+        }
+    }
+
+    fn add_cart(&mut self, cart: Cart) {
+        self.carts.insert(cart.position.into(), cart);
+    }
+
+    fn get_cart(&self, position: &Coordinate) -> Option<&Cart> {
+        let position: Coordinate = *position;
+        self.carts.get(&position.into())
+    }
+
+    fn tick(&mut self, map: &Map) -> Option<CrashedCarts> {
+        let mut crashed_positions: HashSet<Coordinate> = HashSet::new();
+
+        let (_, next_carts) = self.carts.iter().fold(
+            (self.carts.clone(), BTreeMap::new()),
+            |acc, (_current_position, current_cart)| {
+                let (mut prev_carts, mut next_carts): (
+                    BTreeMap<OrderedCoordinate, Cart>,
+                    BTreeMap<OrderedCoordinate, Cart>,
+                ) = acc;
+
+                // remove current cart from the current map state
+                prev_carts.remove(&current_cart.position.into());
+
+                if crashed_positions.contains(&current_cart.position) {
+                    // current cart was crashed by another cart that moved before itself.
+                    return (prev_carts, next_carts);
+                }
+
+                let next_cart = current_cart.tick(map);
+
+                // does the next cart collide with any other cart in the map state?
+                if prev_carts.contains_key(&next_cart.position.into()) {
+                    crashed_positions.insert(next_cart.position);
+                    return (prev_carts, next_carts);
+                }
+
+                // does the next cart collide with carts that already have moved?
+                if next_carts.contains_key(&next_cart.position.into()) {
+                    crashed_positions.insert(next_cart.position);
+                    next_carts.remove(&next_cart.position.into());
+                    return (prev_carts, next_carts);
+                }
+
+                next_carts.insert(next_cart.position.into(), next_cart);
+
+                (prev_carts, next_carts)
+            },
+        );
+
+        // invariant: by the end of this tick, next_carts contain carts that haven't crashed
+
+        self.carts = next_carts;
+
+        if !crashed_positions.is_empty() {
+            return Some(crashed_positions);
+        }
+
+        None
+    }
+}
+
+#[allow(dead_code)]
+fn print_map(map: &Map, carts: &Carts, max_x: i32, max_y: i32) {
+    for y in 0..=max_y {
+        for x in 0..=max_x {
+            let position = (x, y);
+
+            match carts.get_cart(&position) {
+                None => match map.get(&position) {
+                    None => {
+                        print!(" ");
+                    }
+                    Some(track) => {
+                        print!("{}", track);
+                    }
+                },
+                Some(cart) => {
+                    print!("{}", cart.to_str());
+                }
+            }
+        }
+
+        println!();
+    }
+}
+
+// The lines are not trimmed, since the leading whitespace is where the tracks are.
+fn parse_input(input_string: &str) -> Result<(Map, Carts), ParseError> {
+    let mut carts: Carts = Carts::new();
+
+    let map: Map = {
+        let mut map: Map = HashMap::new();
+
+        let mut cell_map: HashMap<Coordinate, char> = HashMap::new();
+        // the cells in reading order, with a scanner at each of them to report errors
+        let mut cells: Vec<(Coordinate, Scanner)> = vec![];
+
+        for (y, line) in input_string.lines().enumerate() {
+            let mut scanner = Scanner::new(y + 1, line);
+            let mut x = 0;
+
+            while !scanner.is_at_end() {
+                let start = scanner;
+                let cell = scanner.char("a track, a cart, or a space", |cell| {
+                    "|-+/\\ ^v<>".contains(cell)
+                })?;
+                let position: Coordinate = (x, y as i32);
+                x += 1;
+
+                // add carts
+                if Cart::is_cart(cell) {
+                    let cart = Cart::new(carts.carts.len(), cell, position);
+                    carts.add_cart(cart);
+                }
+
+                // convert cart to appropriate track
+                let cell = match cell {
+                    'v' | '^' => '|',
+                    '<' | '>' => '-',
+                    _ => cell,
+                };
+
+                cell_map.insert(position, cell);
+                cells.push((position, start));
+            }
+        }
+
+        for (position, start) in cells.iter() {
+            let (x, y) = *position;
+            let position = *position;
+            let cell = &cell_map[&position];
+
+            match cell {
+                '|' => {
+                    map.insert(position, Track::Vertical);
+                }
+                '-' => {
+                    map.insert(position, Track::Horizontal);
+                }
+                '+' => {
+                    map.insert(position, Track::Intersection);
+                }
+                '/' => {
+                    // match configuration:
+                    //   /-
+                    //   |
+                    let is_configuration_1 = {
+                        let valid_right_side = match cell_map.get(&(x + 1, y)) {
+                            None => false,
+                            Some(cell) => is_horizontal(*cell),
+                        };
+
+                        let valid_bottom_side = match cell_map.get(&(x, y + 1)) {
+                            None => false,
+                            Some(cell) => is_vertical(*cell),
+                        };
+
+                        valid_right_side && valid_bottom_side
+                    };
+
+                    // match configuration:
+                    //    |
+                    //   -/
+                    let is_configuration_2 = {
+                        let valid_left_side = match cell_map.get(&(x - 1, y)) {
+                            None => false,
+                            Some(cell) => is_horizontal(*cell),
+                        };
+
+                        let valid_top_side = match cell_map.get(&(x, y - 1)) {
+                            None => false,
+                            Some(cell) => is_vertical(*cell),
+                        };
+
+                        valid_left_side && valid_top_side
+                    };
+
+                    if is_configuration_1 && !is_configuration_2 {
+                        map.insert(position, Track::BottomAndRight);
+                        continue;
+                    }
+
+                    if !is_configuration_1 && is_configuration_2 {
+                        map.insert(position, Track::TopAndLeft);
+                        continue;
+                    }
+
+                    return Err(start.error("a curve between a horizontal and a vertical track"));
+                }
+                '\\' => {
+                    // match configuration:
+                    //   -\
+                    //    |
+                    let is_configuration_1 = {
+                        let valid_left_side = match cell_map.get(&(x - 1, y)) {
+                            None => false,
+                            Some(cell) => is_horizontal(*cell),
+                        };
+
+                        let valid_bottom_side = match cell_map.get(&(x, y + 1)) {
+                            None => false,
+                            Some(cell) => is_vertical(*cell),
+                        };
+
+                        valid_left_side && valid_bottom_side
+                    };
+
+                    // match configuration:
+                    //   |
+                    //   \-
+                    let is_configuration_2 = {
+                        let valid_top_side = match cell_map.get(&(x, y - 1)) {
+                            None => false,
+                            Some(cell) => is_vertical(*cell),
+                        };
+
+                        let valid_right_side = match cell_map.get(&(x + 1, y)) {
+                            None => false,
+                            Some(cell) => is_horizontal(*cell),
+                        };
+
+                        valid_top_side && valid_right_side
+                    };
+
+                    if is_configuration_1 && !is_configuration_2 {
+                        map.insert(position, Track::BottomAndLeft);
+                        continue;
+                    }
+
+                    if !is_configuration_1 && is_configuration_2 {
+                        map.insert(position, Track::TopAndRight);
+                        continue;
+                    }
+
+                    return Err(start.error("a curve between a horizontal and a vertical track"));
+                }
+                _ => {}
+            }
+        }
+
+        map
+    };
+
+    Ok((map, carts))
+}
+
+// the ticks per second that the viewer plays at
+const SPEEDS: [usize; 10] = [1, 2, 5, 10, 20, 50, 100, 200, 500, 1000];
+
+// the viewer redraws the screen at most this many times per second
+const FRAMES_PER_SECOND: usize = 50;
+
+// the lines below the track; the status, and the controls
+const NUM_OF_STATUS_LINES: u16 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Highlight {
+    Track,
+    Cart,
+    FollowedCart,
+    CrashSite,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    TogglePlaying,
+    Step,
+    Faster,
+    Slower,
+    Scroll(i32, i32),
+    FollowNext,
+    FollowPrevious,
+    StopFollowing,
+}
+
+struct Viewer {
+    map: Map,
+    carts: Carts,
+    // the position of the bottom right corner of the map
+    max_position: Coordinate,
+    num_of_ticks: usize,
+    // the tick of every crash, and where it happened
+    crash_sites: Vec<(usize, Coordinate)>,
+    is_playing: bool,
+    // an index into SPEEDS
+    speed: usize,
+    // the position of the top left corner of the track that is shown
+    viewport: Coordinate,
+    following: Option<usize>,
+}
+
+impl Viewer {
+    fn new(map: Map, carts: Carts) -> Viewer {
+        let max_x = map.keys().map(|(x, _y)| *x).max().unwrap_or(0);
+        let max_y = map.keys().map(|(_x, y)| *y).max().unwrap_or(0);
+
+        Viewer {
+            map,
+            carts,
+            max_position: (max_x, max_y),
+            num_of_ticks: 0,
+            crash_sites: vec![],
+            is_playing: false,
+            speed: 3,
+            viewport: (0, 0),
+            following: None,
+        }
+    }
+
+    // the carts stop once at most one cart is left, which is the answer to part 2
+    fn is_finished(&self) -> bool {
+        self.carts.carts.len() <= 1
+    }
+
+    fn get_cart_by_id(&self, id: usize) -> Option<&Cart> {
+        self.carts.carts.values().find(|cart| cart.id == id)
+    }
+
+    fn tick(&mut self) {
+        if self.is_finished() {
+            self.is_playing = false;
+            return;
+        }
+
+        self.num_of_ticks += 1;
+
+        if let Some(crashed_carts) = self.carts.tick(&self.map) {
+            let mut crashed_carts: Vec<Coordinate> = crashed_carts.into_iter().collect();
+            crashed_carts.sort_by(reading_order);
+
+            for position in crashed_carts {
+                self.crash_sites.push((self.num_of_ticks, position));
+            }
+        }
+
+        if let Some(id) = self.following {
+            if self.get_cart_by_id(id).is_none() {
+                self.following = None;
+            }
+        }
+
+        if self.is_finished() {
+            self.is_playing = false;
+        }
+    }
+
+    // the number of ticks per frame, and the time between frames
+    fn frame(&self) -> (usize, Duration) {
+        let ticks_per_second = SPEEDS[self.speed];
+
+        if ticks_per_second <= FRAMES_PER_SECOND {
+            return (1, Duration::from_millis(1000 / ticks_per_second as u64));
+        }
+
+        (
+            ticks_per_second / FRAMES_PER_SECOND,
+            Duration::from_millis(1000 / FRAMES_PER_SECOND as u64),
+        )
+    }
+
+    // the ids of the carts that are left, to follow the next or previous one from the followed one
+    fn follow(&mut self, step: isize) {
+        let mut ids: Vec<usize> = self.carts.carts.values().map(|cart| cart.id).collect();
+        ids.sort_unstable();
+
+        if ids.is_empty() {
+            self.following = None;
+            return;
+        }
+
+        let next_index = match self
+            .following
+            .and_then(|id| ids.iter().position(|x| *x == id))
+        {
+            None if step > 0 => 0,
+            None => ids.len() - 1,
+            Some(index) => (index as isize + step).rem_euclid(ids.len() as isize) as usize,
+        };
+
+        self.following = Some(ids[next_index]);
+    }
+
+    fn run_command(&mut self, command: Command) {
+        match command {
+            Command::TogglePlaying => {
+                self.is_playing = !self.is_playing && !self.is_finished();
+            }
+            Command::Step => {
+                self.is_playing = false;
+                self.tick();
+            }
+            Command::Faster => {
+                self.speed = (self.speed + 1).min(SPEEDS.len() - 1);
+            }
+            Command::Slower => {
+                self.speed = self.speed.saturating_sub(1);
+            }
+            Command::Scroll(x_offset, y_offset) => {
+                // scrolling lets go of the followed cart
+                self.following = None;
+
+                let (x, y) = self.viewport;
+                self.viewport = (x + x_offset, y + y_offset);
+            }
+            Command::FollowNext => self.follow(1),
+            Command::FollowPrevious => self.follow(-1),
+            Command::StopFollowing => {
+                self.following = None;
+            }
+        }
+    }
+
+    // keep the followed cart in the middle of the viewport, and the viewport within the map
+    fn update_viewport(&mut self, width: i32, height: i32) {
+        if let Some(cart) = self.following.and_then(|id| self.get_cart_by_id(id)) {
+            let (x, y) = cart.position;
+            self.viewport = (x - width / 2, y - height / 2);
+        }
+
+        let clamp = |value: i32, size: i32, max_value: i32| value.min(max_value + 1 - size).max(0);
+
+        let (x, y) = self.viewport;
+        let (max_x, max_y) = self.max_position;
+        self.viewport = (clamp(x, width, max_x), clamp(y, height, max_y));
+    }
+
+    fn render(&self, width: i32, height: i32) -> Vec<Vec<(char, Highlight)>> {
+        let crash_sites: HashSet<Coordinate> = self
+            .crash_sites
+            .iter()
+            .map(|(_tick, position)| *position)
+            .collect();
+
+        let (viewport_x, viewport_y) = self.viewport;
+
+        (viewport_y..viewport_y + height)
+            .map(|y| {
+                (viewport_x..viewport_x + width)
+                    .map(|x| {
+                        let position = (x, y);
+
+                        if let Some(cart) = self.carts.get_cart(&position) {
+                            if Some(cart.id) == self.following {
+                                return (
+                                    cart.to_str().chars().next().unwrap(),
+                                    Highlight::FollowedCart,
+                                );
+                            }
+                            return (cart.to_str().chars().next().unwrap(), Highlight::Cart);
+                        }
+
+                        if crash_sites.contains(&position) {
+                            return ('X', Highlight::CrashSite);
+                        }
+
+                        match self.map.get(&position) {
+                            None => (' ', Highlight::Track),
+                            Some(track) => {
+                                (track.to_string().chars().next().unwrap(), Highlight::Track)
+                            }
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn status(&self) -> String {
+        let state = if self.is_finished() {
+            "finished"
+        } else if self.is_playing {
+            "playing"
+        } else {
+            "paused"
+        };
+
+        let mut status = format!(
+            "tick {}  carts {}  {} ticks/s  {}",
+            self.num_of_ticks,
+            self.carts.carts.len(),
+            SPEEDS[self.speed],
+            state
+        );
+
+        if let Some((tick, (x, y))) = self.crash_sites.first() {
+            status.push_str(&format!(
+                "  crashes {} (first at {},{} on tick {})",
+                self.crash_sites.len(),
+                x,
+                y,
+                tick
+            ));
+        }
+
+        if let Some(cart) = self.following.and_then(|id| self.get_cart_by_id(id)) {
+            let (x, y) = cart.position;
+            status.push_str(&format!("  following cart {} at {},{}", cart.id, x, y));
+        }
+
+        status
+    }
+}
+
+fn to_command(key: KeyEvent) -> Option<Command> {
+    let command = match key.code {
+        KeyCode::Char(' ') => Command::TogglePlaying,
+        KeyCode::Char('.') | KeyCode::Char('n') => Command::Step,
+        KeyCode::Char('+') | KeyCode::Char('=') => Command::Faster,
+        KeyCode::Char('-') => Command::Slower,
+        KeyCode::Left | KeyCode::Char('h') => Command::Scroll(-1, 0),
+        KeyCode::Right | KeyCode::Char('l') => Command::Scroll(1, 0),
+        KeyCode::Up | KeyCode::Char('k') => Command::Scroll(0, -1),
+        KeyCode::Down | KeyCode::Char('j') => Command::Scroll(0, 1),
+        KeyCode::Tab | KeyCode::Char('f') => Command::FollowNext,
+        KeyCode::BackTab | KeyCode::Char('F') => Command::FollowPrevious,
+        KeyCode::Esc => Command::StopFollowing,
+        _ => return None,
+    };
+
+    Some(command)
+}
+
+const CONTROLS: &str = concat!(
+    "space play/pause  . step  +/- speed  arrows scroll  ",
+    "tab/f follow  shift-tab/F previous  esc unfollow  q quit"
+);
+
+fn draw(stdout: &mut io::Stdout, viewer: &mut Viewer) -> io::Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let width = columns as i32;
+    let height = rows.saturating_sub(NUM_OF_STATUS_LINES) as i32;
+
+    viewer.update_viewport(width, height);
+
+    queue!(stdout, terminal::Clear(terminal::ClearType::All))?;
+
+    for (row, cells) in viewer.render(width, height).into_iter().enumerate() {
+        queue!(stdout, cursor::MoveTo(0, row as u16))?;
+
+        for (symbol, highlight) in cells {
+            let content = match highlight {
+                Highlight::Track => symbol.dark_grey(),
+                Highlight::Cart => symbol.white().bold(),
+                Highlight::FollowedCart => symbol.black().on_yellow().bold(),
+                Highlight::CrashSite => symbol.white().on_red().bold(),
+            };
+            queue!(stdout, PrintStyledContent(content))?;
+        }
+    }
+
+    let status_lines = [viewer.status(), CONTROLS.to_string()];
+    for (index, line) in status_lines.iter().enumerate() {
+        let line: String = line.chars().take(columns as usize).collect();
+        queue!(
+            stdout,
+            cursor::MoveTo(0, rows.saturating_sub(NUM_OF_STATUS_LINES) + index as u16),
+            PrintStyledContent(line.reverse())
+        )?;
+    }
+
+    stdout.flush()
+}
+
+// the terminal is put back the way it was, even when the viewer panics
+struct FullScreen;
+
+impl FullScreen {
+    fn enter() -> io::Result<FullScreen> {
+        terminal::enable_raw_mode()?;
+        queue!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        io::stdout().flush()?;
+        Ok(FullScreen)
+    }
+}
+
+impl Drop for FullScreen {
+    fn drop(&mut self) {
+        let _ = queue!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = io::stdout().flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+// step through the ticks of the carts full screen, with the keys listed in CONTROLS
+pub fn view(input_string: &str) -> io::Result<()> {
+    let (map, carts) = parse_input(input_string)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    let mut viewer = Viewer::new(map, carts);
+
+    let _full_screen = FullScreen::enter()?;
+    let mut stdout = io::stdout();
+
+    loop {
+        draw(&mut stdout, &mut viewer)?;
+
+        let (ticks_per_frame, frame_time) = viewer.frame();
+
+        // wait for a key for up to a frame while playing; otherwise, until a key is pressed
+        let has_event = if viewer.is_playing {
+            event::poll(frame_time)?
+        } else {
+            true
+        };
+
+        if !has_event {
+            for _tick in 0..ticks_per_frame {
+                viewer.tick();
+            }
+            continue;
+        }
+
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                if matches!(key.code, KeyCode::Char('q')) {
+                    break;
+                }
+
+                if let Some(command) = to_command(key) {
+                    viewer.run_command(command);
+                }
+            }
+            // a resize is redrawn on the next pass
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn part_1(input_string: &str) -> Option<Coordinate> {
+    let (map, mut carts) = parse_input(input_string).ok()?;
+
+    // the carts would go around forever
+    if carts.carts.len() < 2 {
+        return None;
+    }
+
+    // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
+
+    loop {
+        let crashed_carts = carts.tick(&map);
+        // num_of_ticks += 1;
+        // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
+        // println!("==========");
+        // use std::thread;
+        // use std::time::Duration;
+        // thread::sleep(Duration::from_millis(100));
+
+        match crashed_carts {
+            None => {}
+            Some(crashed_carts) => {
+                // not: 29,104
+
+                let mut crashed_carts: Vec<Coordinate> = crashed_carts.into_iter().collect();
+                crashed_carts.sort();
+
+                // println!("{:?}", crashed_carts);
+                // println!("crashed at tick: {}", num_of_ticks);
+
+                return crashed_carts.first().copied();
+            }
+        }
+    }
+}
+
+fn part_2(input_string: &str) -> Option<Coordinate> {
+    let (map, mut carts) = parse_input(input_string).ok()?;
+
+    // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
+
+    loop {
+        carts.tick(&map);
+        // num_of_ticks += 1;
+        // print_map(&map, &carts, num_of_cols - 1, num_of_lines - 1);
+        // println!("==========");
+        // use std::thread;
+        // use std::time::Duration;
+        // thread::sleep(Duration::from_millis(100));
+
+        if carts.carts.len() <= 1 {
+            return carts
+                .carts
+                .keys()
+                .map(|position| position.coordinate())
+                .next();
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        let (x, y) = part_1(input_string)?;
+        Some(format!("{},{}", x, y))
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        let (x, y) = part_2(input_string)?;
+        Some(format!("{},{}", x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordered_coordinate() {
+        let test: Vec<Coordinate> = vec![(2, 27), (3, 26), (2, 26), (1, 26), (2, 25)];
+        let test: Vec<OrderedCoordinate> = test.into_iter().map(|x| x.into()).collect();
+        let expected = {
+            let mut test = test.clone();
+            test.reverse();
+            test
+        };
+
+        let mut actual = test;
+        actual.sort();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn near_miss() {
+        let input_string = r###"
+   |
+   |
+->-+---
+   |
+   |
+   ^
+"###;
+
+        part_1(input_string);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/
+        ";
+
+        assert_eq!(part_1(input_string), Some((7, 3)));
+
+        // first crash occurs from left to right
+        let input_string = r###"->-<-->-<--"###;
+
+        assert_eq!(part_1(input_string), Some((2, 0)));
+
+        // carts shouldn't pass through each other
+        let input_string = r###"--->--<---"###;
+
+        assert_eq!(part_1(input_string), Some((5, 0)));
+
+        let input_string = r###"|
+|
+v
+|
+|
+^
+|
+|
+"###;
+
+        assert_eq!(part_1(input_string), Some((0, 4)));
+
+        let input_string = r###"->+<-
+  ^  "###;
+
+        assert_eq!(part_1(input_string), Some((2, 0)));
+
+        let input_string = r###"-->>--"###;
+
+        assert_eq!(part_1(input_string), Some((3, 0)));
+
+        let input_string = include_str!("input.txt");
+
+        assert_eq!(part_1(input_string), Some((76, 108)));
+
+        assert_eq!(part_1("->--"), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input_string = "/->-\\\n|   |\n\\-*-/";
+        let error = parse_input(input_string).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: expected a track, a cart, or a space, found \"*\""
+        );
+
+        let error = parse_input("-/-").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 2: expected a curve between a horizontal and a vertical track, \
+             found \"/\""
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/
+        ";
+
+        assert_eq!(part_2(input_string), Some((6, 4)));
+    }
+
+    fn to_strings(cells: &[Vec<(char, Highlight)>]) -> Vec<String> {
+        cells
+            .iter()
+            .map(|row| row.iter().map(|(symbol, _highlight)| *symbol).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_viewer() {
+        let input_string = r"/->-\
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/
+        ";
+
+        let (map, carts) = parse_input(input_string).unwrap();
+        let mut viewer = Viewer::new(map, carts);
+        assert_eq!(viewer.max_position, (12, 5));
+
+        assert_eq!(
+            to_strings(&viewer.render(13, 6)),
+            vec![
+                "/->-\\        ",
+                "|   |  /----\\",
+                "| /-+--+-\\  |",
+                "| | |  | v  |",
+                "\\-+-/  \\-+--/",
+                "  \\------/   ",
+            ]
+        );
+
+        // following the carts in the order of their ids
+        viewer.run_command(Command::FollowNext);
+        assert_eq!(viewer.following, Some(0));
+        viewer.run_command(Command::FollowNext);
+        assert_eq!(viewer.following, Some(1));
+        viewer.run_command(Command::FollowNext);
+        assert_eq!(viewer.following, Some(0));
+        viewer.run_command(Command::FollowPrevious);
+        assert_eq!(viewer.following, Some(1));
+
+        // the followed cart is in the middle of the viewport, which stays within the map
+        viewer.update_viewport(4, 3);
+        assert_eq!(viewer.viewport, (7, 2));
+        let cells = viewer.render(4, 3);
+        assert_eq!(to_strings(&cells), vec!["+-\\ ", "| v ", "\\-+-"]);
+        assert_eq!(cells[1][2], ('v', Highlight::FollowedCart));
+
+        viewer.run_command(Command::Scroll(-100, 0));
+        assert_eq!(viewer.following, None);
+        viewer.update_viewport(4, 3);
+        assert_eq!(viewer.viewport, (0, 2));
+
+        // the first crash
+        while viewer.crash_sites.is_empty() {
+            viewer.run_command(Command::Step);
+        }
+        assert_eq!(viewer.num_of_ticks, 14);
+        assert_eq!(viewer.crash_sites, vec![(14, (7, 3))]);
+        assert!(viewer.is_finished());
+        viewer.update_viewport(13, 6);
+        assert_eq!(viewer.viewport, (0, 0));
+        assert_eq!(viewer.render(13, 6)[3][7], ('X', Highlight::CrashSite));
+        assert_eq!(
+            viewer.status(),
+            "tick 14  carts 0  10 ticks/s  finished  crashes 1 (first at 7,3 on tick 14)"
+        );
+
+        // nothing is left to play
+        viewer.run_command(Command::TogglePlaying);
+        assert!(!viewer.is_playing);
+        viewer.run_command(Command::Step);
+        assert_eq!(viewer.num_of_ticks, 14);
+    }
+
+    #[test]
+    fn test_viewer_until_one_cart_is_left() {
+        let input_string = r"/>-<\
+|   |
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/
+        ";
+
+        let (map, carts) = parse_input(input_string).unwrap();
+        let mut viewer = Viewer::new(map, carts);
+
+        viewer.run_command(Command::TogglePlaying);
+        assert!(viewer.is_playing);
+
+        viewer.run_command(Command::FollowPrevious);
+        assert_eq!(viewer.following, Some(8));
+
+        while viewer.is_playing {
+            viewer.tick();
+        }
+
+        assert_eq!(viewer.num_of_ticks, 3);
+        assert_eq!(viewer.crash_sites.len(), 4);
+        assert_eq!(viewer.carts.carts.len(), 1);
+        assert_eq!(viewer.get_cart_by_id(8).unwrap().position, (6, 4));
+        assert_eq!(
+            viewer.status(),
+            "tick 3  carts 1  10 ticks/s  finished  crashes 4 (first at 2,0 on tick 1)  following cart 8 at 6,4"
+        );
+    }
+
+    #[test]
+    fn test_viewer_speed() {
+        let (map, carts) = parse_input("->-<-").unwrap();
+        let mut viewer = Viewer::new(map, carts);
+
+        assert_eq!(viewer.frame(), (1, Duration::from_millis(100)));
+
+        viewer.run_command(Command::Slower);
+        viewer.run_command(Command::Slower);
+        viewer.run_command(Command::Slower);
+        viewer.run_command(Command::Slower);
+        assert_eq!(viewer.frame(), (1, Duration::from_millis(1000)));
+
+        for _step in 0..20 {
+            viewer.run_command(Command::Faster);
+        }
+        assert_eq!(viewer.frame(), (20, Duration::from_millis(20)));
+    }
+}
@@ -0,0 +1,245 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+type Recipe = i32;
+
+struct Elf {
+    position: usize,
+}
+
+impl Elf {
+    fn get_recipe(&self, scoreboard: &[Recipe]) -> Recipe {
+        *scoreboard.get(self.position).unwrap()
+    }
+
+    fn move_to_new_position(&mut self, current_recipe: Recipe, scoreboard: &[Recipe]) {
+        let num_of_moves: usize = (1 + current_recipe) as usize;
+        self.position = (self.position + num_of_moves) % scoreboard.len();
+    }
+}
+
+fn part_1(part_1_input: usize) -> String {
+    let part_1_guard = part_1_input + 10;
+
+    let mut scoreboard = vec![3, 7];
+
+    let mut first_elf = Elf { position: 0 };
+    let mut second_elf = Elf { position: 1 };
+
+    loop {
+        // each elf gets a recipe from the scoreboard
+
+        let recipe_1 = first_elf.get_recipe(&scoreboard);
+        let recipe_2 = second_elf.get_recipe(&scoreboard);
+
+        // each elf combine their current recipes
+
+        let recipe_result = recipe_1 + recipe_2;
+        assert!(recipe_result <= 18);
+
+        // the resulting recipes are added to the scoreboard
+
+        if recipe_result >= 10 {
+            scoreboard.push(recipe_result / 10);
+            scoreboard.push(recipe_result % 10);
+        } else {
+            scoreboard.push(recipe_result);
+        }
+
+        first_elf.move_to_new_position(recipe_1, &scoreboard);
+        second_elf.move_to_new_position(recipe_2, &scoreboard);
+
+        if scoreboard.len() >= part_1_guard {
+            let result: String = scoreboard
+                .iter()
+                .skip(part_1_input)
+                .take(10)
+                .map(std::string::ToString::to_string)
+                .collect::<String>();
+
+            return result;
+        }
+    }
+}
+
+fn compare_vectors(needle: &[Recipe], scoreboard: &[Recipe], start_index: usize) -> (usize, bool) {
+    assert!(start_index < scoreboard.len());
+
+    // compare needle to recipes within scoreboard starting at index start_index
+
+    if needle.len() > scoreboard.len() {
+        return (start_index, false);
+    }
+
+    if start_index + (needle.len() - 1) >= scoreboard.len() {
+        return (start_index, false);
+    }
+
+    for (index, recipe_needle) in needle.iter().enumerate() {
+        let fetch_index = start_index + index;
+
+        if fetch_index >= scoreboard.len() {
+            return (start_index, false);
+        }
+
+        let recipe = scoreboard.get(fetch_index).unwrap();
+
+        if recipe != recipe_needle {
+            if fetch_index > start_index {
+                return (fetch_index, false);
+            }
+            return (start_index + 1, false);
+        }
+    }
+
+    (start_index, true)
+}
+
+fn part_2(needle: Vec<Recipe>) -> usize {
+    let mut num_of_recipes_to_skip = 0;
+
+    let mut scoreboard = vec![3, 7];
+
+    let mut first_elf = Elf { position: 0 };
+    let mut second_elf = Elf { position: 1 };
+
+    loop {
+        // each elf gets a recipe from the scoreboard
+
+        let recipe_1 = first_elf.get_recipe(&scoreboard);
+        let recipe_2 = second_elf.get_recipe(&scoreboard);
+
+        // each elf combine their current recipes
+
+        let recipe_result = recipe_1 + recipe_2;
+        assert!(recipe_result <= 18);
+
+        // the resulting recipes are added to the scoreboard
+
+        if recipe_result >= 10 {
+            scoreboard.push(recipe_result / 10);
+            scoreboard.push(recipe_result % 10);
+        } else {
+            scoreboard.push(recipe_result);
+        }
+
+        first_elf.move_to_new_position(recipe_1, &scoreboard);
+        second_elf.move_to_new_position(recipe_2, &scoreboard);
+
+        let (offset, found) = compare_vectors(&needle, &scoreboard, num_of_recipes_to_skip);
+
+        if found {
+            return num_of_recipes_to_skip;
+        }
+
+        // println!("num_of_recipes_to_skip: {}", offset);
+        num_of_recipes_to_skip = offset;
+    }
+}
+
+// The digits on the only line of the puzzle input; as the number of recipes for part 1, and as
+// the sequence of scores for part 2.
+fn parse_input(input_string: &str) -> Result<(usize, Vec<Recipe>), ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+
+    let mut start = scanner;
+    let mut digits = vec![];
+
+    loop {
+        let digit = scanner.char("a digit", |c| c.is_ascii_digit())?;
+        digits.push((digit as u8 - b'0') as Recipe);
+
+        if scanner.is_at_end() {
+            break;
+        }
+    }
+
+    let num_of_recipes = start.integer()?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok((num_of_recipes, digits))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        let (num_of_recipes, _digits) = parse_input(input_string).ok()?;
+        Some(part_1(num_of_recipes))
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        let (_num_of_recipes, digits) = parse_input(input_string).ok()?;
+        Some(part_2(digits).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(9), "5158916779".to_string());
+        assert_eq!(part_1(5), "0124515891".to_string());
+        assert_eq!(part_1(18), "9251071085".to_string());
+        assert_eq!(part_1(2018), "5941429882".to_string());
+    }
+
+    #[test]
+    fn test_compare_vectors() {
+        assert_eq!(
+            compare_vectors(&[2, 3, 4, 5], &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 1),
+            (1, true)
+        );
+        assert_eq!(
+            compare_vectors(&[2, 3, 4, 9], &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 1),
+            (4, false)
+        );
+        assert_eq!(
+            compare_vectors(&[9, 2, 3, 4, 9], &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 1),
+            (2, false)
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(vec![5, 1, 5, 8, 9]), 9);
+        assert_eq!(part_2(vec![0, 1, 2, 4, 5]), 5);
+        assert_eq!(part_2(vec![9, 2, 5, 1, 0]), 18);
+        assert_eq!(part_2(vec![5, 9, 4, 1, 4]), 2018);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("01245\n"), Ok((1245, vec![0, 1, 2, 4, 5])));
+
+        let error = parse_input("").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected a digit, found end of line"
+        );
+
+        let error = parse_input("512x").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+
+        let error = parse_input("99999999999999999999999").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
@@ -0,0 +1,2495 @@
+// https://adventofcode.com/2018/day/15
+
+// imports
+use rayon::prelude::*;
+
+use aoc_core::{scan_lines, ParseError, Part, Scanner, Solution, Variant};
+use aoc_grid::{reading_order, Coordinate, Grid};
+use core::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+
+// code
+
+#[derive(Debug)]
+enum RoundState {
+    Incomplete,
+    Complete,
+}
+
+type Path = Vec<Coordinate>;
+
+#[derive(PartialEq, Hash, Eq, Clone, Debug)]
+struct DistanceCoordinate(Distance, Coordinate);
+
+impl PartialOrd for DistanceCoordinate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DistanceCoordinate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // reversed for the binary heap which is a max-heap
+        if self.0 != other.0 {
+            return other.0.cmp(&self.0);
+        }
+        reading_order(&other.1, &self.1)
+
+        // match ord {
+        //     Ordering::Greater => Ordering::Less,
+        //     Ordering::Less => Ordering::Greater,
+        //     Ordering::Equal => ord,
+        // }
+    }
+}
+
+impl From<DistanceCoordinate> for Coordinate {
+    fn from(val: DistanceCoordinate) -> Self {
+        val.1
+    }
+}
+
+// the offsets of the squares next to a square, in reading order
+const ORTHOGONAL_NEIGHBOURS: [Coordinate; 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const ALL_NEIGHBOURS: [Coordinate; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+type Distance = i32;
+
+#[derive(Debug, Clone)]
+enum MapState {
+    Wall,
+    Cavern,
+}
+
+// What happened during a round, in the order that it happened. Every event can be undone, which
+// is what lets the replay step backwards through the rounds.
+#[derive(Debug, Clone)]
+enum Event {
+    Moved {
+        unit: Unit,
+        from: Coordinate,
+        to: Coordinate,
+    },
+    Attacked {
+        unit: Unit,
+        position: Coordinate,
+        // the target after the attack
+        target: Unit,
+        position_of_target: Coordinate,
+        damage: i32,
+    },
+    Died {
+        unit: Unit,
+        position: Coordinate,
+    },
+}
+
+impl Event {
+    fn to_str(&self) -> String {
+        match self {
+            Event::Moved { unit, from, to } => {
+                format!("{} moved from {:?} to {:?}", unit.to_id_string(), from, to)
+            }
+            Event::Attacked {
+                unit,
+                position,
+                target,
+                position_of_target,
+                damage,
+            } => format!(
+                "{} at {:?} attacked {} at {:?} for {}, leaving {} hit points",
+                unit.to_id_string(),
+                position,
+                target.to_id_string(),
+                position_of_target,
+                damage,
+                target.hit_points
+            ),
+            Event::Died { unit, position } => {
+                format!("{} died at {:?}", unit.to_id_string(), position)
+            }
+        }
+    }
+}
+
+// how units find the square to move to
+#[derive(Debug, Clone, Copy)]
+enum Movement {
+    // a single breadth-first search from the unit
+    SingleSearch,
+    // a search from every square in range of a target back to the unit
+    SearchPerTarget,
+}
+
+const ELF: char = 'E';
+const GOBLIN: char = 'G';
+
+// A side of the combat, and what each of its units starts with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Faction {
+    // the symbol of its units on the map
+    symbol: char,
+    hit_points: i32,
+    attack_power: i32,
+    // the number of squares that its units may move by on their turn
+    move_range: usize,
+}
+
+impl Faction {
+    fn new(symbol: char) -> Faction {
+        Faction {
+            symbol,
+            hit_points: 200,
+            attack_power: 3,
+            move_range: 1,
+        }
+    }
+}
+
+// The rules of combat, which default to those of the puzzle: elves and goblins with 200 hit
+// points and 3 attack power, that move by a single square, and only reach the squares up, down,
+// left and right of them.
+//
+// Lines before the map (or a file of such lines) change the rules, e.g.
+//
+// faction E attack 10
+// faction O hp 300 attack 5 move 2
+// adjacency diagonal
+//
+// where a faction keeps whatever is not given, and a new symbol adds a faction. Every faction
+// fights every other faction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    factions: Vec<Faction>,
+    // whether the squares at the corners of a square are next to it as well
+    diagonal_adjacency: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            factions: vec![Faction::new(ELF), Faction::new(GOBLIN)],
+            diagonal_adjacency: false,
+        }
+    }
+}
+
+impl Rules {
+    pub fn parse(input_string: &str) -> Result<Rules, ParseError> {
+        let mut rules = Rules::default();
+
+        for mut scanner in scan_lines(input_string) {
+            rules.parse_rule(&mut scanner)?;
+        }
+
+        Ok(rules)
+    }
+
+    fn is_rule(scanner: &Scanner) -> bool {
+        scanner.rest().starts_with("faction") || scanner.rest().starts_with("adjacency")
+    }
+
+    fn parse_rule(&mut self, scanner: &mut Scanner) -> Result<(), ParseError> {
+        if scanner.eat("adjacency") {
+            scanner.skip_whitespace();
+            let start = *scanner;
+            self.diagonal_adjacency = match scanner.word("diagonal or orthogonal")? {
+                "diagonal" => true,
+                "orthogonal" => false,
+                _ => return Err(start.error("diagonal or orthogonal")),
+            };
+            return scanner.end();
+        }
+
+        if !scanner.eat("faction") {
+            return Err(scanner.error("faction or adjacency"));
+        }
+
+        scanner.skip_whitespace();
+        let start = *scanner;
+        let mut symbol = scanner.word("the symbol of a faction")?.chars();
+        let symbol = match (symbol.next(), symbol.next()) {
+            (Some(symbol), None) if symbol != '#' && symbol != '.' => symbol,
+            _ => {
+                return Err(start.error("a single character other than # or . for the faction"));
+            }
+        };
+
+        let mut faction = match self.faction(symbol) {
+            Some(faction) => faction.clone(),
+            None => Faction::new(symbol),
+        };
+
+        loop {
+            scanner.skip_whitespace();
+            if scanner.is_at_end() {
+                break;
+            }
+
+            let start = *scanner;
+            let stat = scanner.word("hp, attack or move")?;
+            scanner.skip_whitespace();
+
+            match stat {
+                "hp" => faction.hit_points = parse_positive(scanner)?,
+                "attack" => faction.attack_power = parse_positive(scanner)?,
+                "move" => faction.move_range = scanner.integer()?,
+                _ => return Err(start.error("hp, attack or move")),
+            }
+        }
+
+        match self
+            .factions
+            .iter_mut()
+            .find(|other| other.symbol == symbol)
+        {
+            Some(other) => *other = faction,
+            None => self.factions.push(faction),
+        }
+
+        Ok(())
+    }
+
+    fn faction(&self, symbol: char) -> Option<&Faction> {
+        self.factions
+            .iter()
+            .find(|faction| faction.symbol == symbol)
+    }
+}
+
+// hit points and attack power of zero would keep units from ever dying
+fn parse_positive(scanner: &mut Scanner) -> Result<i32, ParseError> {
+    let start = *scanner;
+    let value = scanner.integer()?;
+
+    if value <= 0 {
+        return Err(start.error("a positive integer"));
+    }
+
+    Ok(value)
+}
+
+type Terrain = Grid<MapState>;
+type UnitPlacement = BTreeMap<Coordinate, Unit>;
+
+#[derive(Debug, Clone)]
+struct Map {
+    terrain: Terrain,
+    units: UnitPlacement,
+    num_of_elves_died: usize,
+    movement: Movement,
+    // whether combat stops as soon as an elf dies, since the elves must all survive
+    abort_when_an_elf_dies: bool,
+    rules: Rules,
+}
+
+impl Map {
+    fn new(width: usize, height: usize, rules: Rules) -> Map {
+        Map {
+            terrain: Grid::new(width, height, MapState::Wall),
+            units: BTreeMap::new(),
+            num_of_elves_died: 0,
+            movement: Movement::SingleSearch,
+            abort_when_an_elf_dies: false,
+            rules,
+        }
+    }
+
+    fn with_movement(&mut self, movement: Movement) {
+        self.movement = movement;
+    }
+
+    fn with_abort_when_an_elf_dies(&mut self) {
+        self.abort_when_an_elf_dies = true;
+    }
+
+    fn has_lost_an_elf(&self) -> bool {
+        self.abort_when_an_elf_dies && self.num_of_elves_died > 0
+    }
+
+    fn with_elf_attack_power(&mut self, elf_attack_power: i32) {
+        for (_, unit) in self.units.iter_mut() {
+            if unit.is_elf() {
+                unit.attack_power = elf_attack_power;
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn to_str(&self) -> String {
+        let max_x = self.terrain.max_x();
+        let max_y = self.terrain.max_y();
+
+        let mut map_string: Vec<String> = vec![];
+
+        for y in 0..=max_y {
+            let mut row_string = String::from("");
+
+            for x in 0..=max_x {
+                let position = (x, y);
+
+                match self.terrain.get(position) {
+                    None => {
+                        row_string.push('#');
+                    }
+                    Some(map_state) => {
+                        match map_state {
+                            MapState::Wall => {
+                                // invariant: a unit cannot be within a wall
+                                assert!(!self.units.contains_key(&position));
+                                row_string.push('#');
+                            }
+                            MapState::Cavern => match self.units.get(&position) {
+                                None => {
+                                    row_string.push('.');
+                                }
+                                Some(unit) => {
+                                    row_string.push_str(&unit.to_str());
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            map_string.push(row_string);
+        }
+
+        map_string.join("\n")
+    }
+
+    fn to_string_with_health(&self) -> String {
+        let max_x = self.terrain.max_x();
+        let max_y = self.terrain.max_y();
+
+        let mut map_string: Vec<String> = vec![];
+
+        for y in 0..=max_y {
+            let mut row_string = String::from("");
+            let mut visited_units = vec![];
+
+            for x in 0..=max_x {
+                let position = (x, y);
+
+                match self.terrain.get(position) {
+                    None => {
+                        row_string.push('#');
+                    }
+                    Some(map_state) => {
+                        match map_state {
+                            MapState::Wall => {
+                                // invariant: a unit cannot be within a wall
+                                assert!(!self.units.contains_key(&position));
+                                row_string.push('#');
+                            }
+                            MapState::Cavern => match self.units.get(&position) {
+                                None => {
+                                    row_string.push('.');
+                                }
+                                Some(unit) => {
+                                    visited_units.push(unit.to_health_string());
+                                    row_string.push_str(&unit.to_str());
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+
+            if !visited_units.is_empty() {
+                let joined_units = visited_units.join(", ");
+                row_string.push_str(&format!("  {}", joined_units));
+            }
+
+            map_string.push(row_string);
+        }
+
+        map_string.join("\n")
+    }
+
+    // returns false if the cell is neither a wall, an open cavern, nor a unit of some faction
+    fn insert(&mut self, position: Coordinate, cell: char) -> bool {
+        match cell {
+            '#' => {
+                self.terrain.set(position, MapState::Wall);
+            }
+            '.' => {
+                self.terrain.set(position, MapState::Cavern);
+            }
+            _ => match self.rules.faction(cell) {
+                None => {
+                    return false;
+                }
+                Some(faction) => {
+                    let unit = Unit::new(faction, self.units.len());
+                    self.terrain.set(position, MapState::Cavern);
+                    self.units.insert(position, unit);
+                }
+            },
+        }
+
+        true
+    }
+
+    // the squares next to the position, in reading order
+    fn neighbours(&self, position: Coordinate) -> impl Iterator<Item = Coordinate> {
+        let offsets: &'static [Coordinate] = if self.rules.diagonal_adjacency {
+            &ALL_NEIGHBOURS
+        } else {
+            &ORTHOGONAL_NEIGHBOURS
+        };
+
+        let (x, y) = position;
+        offsets.iter().map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    // whether the squares are next to each other, or are the same square
+    fn is_in_reach(&self, start: Coordinate, end: Coordinate) -> bool {
+        let (a, b) = start;
+        let (c, d) = end;
+        let (dx, dy) = ((a - c).abs(), (b - d).abs());
+
+        if self.rules.diagonal_adjacency {
+            return dx.max(dy) <= 1;
+        }
+
+        dx + dy <= 1
+    }
+
+    fn is_wall(&self, position: Coordinate) -> bool {
+        match self.terrain.get(position) {
+            None => true,
+            Some(map_state) => match map_state {
+                MapState::Wall => true,
+                MapState::Cavern => false,
+            },
+        }
+    }
+
+    fn is_occupied(&self, position: Coordinate) -> bool {
+        if self.is_wall(position) {
+            return true;
+        }
+
+        // check if the position is occupied by a unit
+        self.units.contains_key(&position)
+    }
+
+    // checks if a round can be executed
+    fn can_run_round(&self) -> bool {
+        if self.units.is_empty() {
+            return false;
+        }
+
+        let units: Vec<(Coordinate, Unit)> = {
+            let mut units: Vec<(Coordinate, Unit)> = self.units.clone().into_par_iter().collect();
+
+            units.sort_by(|item_1, item_2| {
+                let (pos_1, _) = item_1;
+                let (pos_2, _) = item_2;
+
+                reading_order(pos_1, pos_2)
+            });
+            units
+        };
+
+        let (_position, unit) = units.first().unwrap();
+
+        self.units.values().any(|other| unit.is_enemy_of(other))
+    }
+    // get open squares adjacent to position
+    fn get_adjacent_open_squares(&self, position: Coordinate) -> Vec<Coordinate> {
+        self.neighbours(position)
+            .filter(|square| !self.is_occupied(*square))
+            .collect()
+    }
+
+    // the units of every other faction
+    fn get_targets(&self, unit: &Unit) -> Vec<(Coordinate, Unit)> {
+        self.units
+            .par_iter()
+            .filter(|(_position, other)| unit.is_enemy_of(other))
+            .map(|(position, other)| (*position, other.clone()))
+            .collect()
+    }
+
+    fn get_attackable_target(
+        &self,
+        position_of_unit: &Coordinate,
+        attacking_unit: &Unit,
+    ) -> Option<(Coordinate, Unit)> {
+        // check if this unit is still alive.
+        if !self.units.contains_key(position_of_unit) {
+            return None;
+        }
+
+        let targets = self.get_targets(attacking_unit);
+
+        let adjacent_targets: Vec<(Coordinate, Unit)> = {
+            let mut adjacent_targets: Vec<(Coordinate, Unit)> = targets
+                .par_iter()
+                .map(|(position_of_target, target)| -> (Coordinate, Unit) {
+                    (*position_of_target, (*target).clone())
+                })
+                .filter(|(position_of_target, _target)| {
+                    self.is_in_reach(*position_of_unit, *position_of_target)
+                })
+                .collect();
+
+            adjacent_targets.sort_by(|item_1, item_2| {
+                let (position_of_target_1, target_1) = item_1;
+                let (position_of_target_2, target_2) = item_2;
+
+                // the adjacent target with the fewest hit points is selected;
+                if target_1.hit_points != target_2.hit_points {
+                    return target_1.hit_points.cmp(&target_2.hit_points);
+                }
+
+                // in a tie, the adjacent target with the fewest hit points which is first in reading order is selected.
+                reading_order(position_of_target_1, position_of_target_2)
+            });
+
+            adjacent_targets
+        };
+
+        if !adjacent_targets.is_empty() {
+            let (position_of_target, chosen_target) = adjacent_targets.first().unwrap();
+            return Some((*position_of_target, chosen_target.clone()));
+        }
+
+        None
+    }
+
+    fn execute_round(&mut self) -> RoundState {
+        self.execute_round_with_events(&mut vec![])
+    }
+
+    // returns true if combat has ended (i.e. round didn't run)
+    // every move, attack and death is added to events
+    fn execute_round_with_events(&mut self, events: &mut Vec<Event>) -> RoundState {
+        if !self.can_run_round() {
+            return RoundState::Incomplete;
+        }
+
+        // track number of actions performed this round by each unit.
+        // an action is either a movement or an attack
+        let mut num_of_actions_performed = 0;
+
+        let position_of_units: Vec<(Coordinate, Unit)> = {
+            let mut units = vec![];
+            for (position_of_unit, expected_unit) in self.units.clone().into_iter() {
+                units.push((position_of_unit, expected_unit));
+            }
+
+            units.sort_by(|item_1, item_2| {
+                let (pos_1, _) = item_1;
+                let (pos_2, _) = item_2;
+
+                reading_order(pos_1, pos_2)
+            });
+
+            units
+        };
+
+        for (position_of_unit, expected_unit) in position_of_units {
+            let unit = match self.units.get(&position_of_unit) {
+                None => {
+                    // no unit here; it probably died
+                    continue;
+                }
+                Some(actual_unit) => {
+                    if expected_unit.id != actual_unit.id {
+                        // the unit we were expecting to be here probably died,
+                        // and another unit moved into its place
+                        continue;
+                    }
+
+                    if actual_unit.is_dead() {
+                        continue;
+                    }
+
+                    actual_unit.clone()
+                }
+            };
+
+            assert!(unit.is_alive());
+
+            // Each unit begins its turn by identifying all possible targets (enemy units).
+            let targets = self.get_targets(&unit);
+
+            // If no targets remain, combat ends.
+            if targets.is_empty() {
+                return RoundState::Incomplete;
+            }
+
+            // If the unit is already in range of a target,
+            // it does not move, but continues its turn with an attack.
+            if let Some((position_of_target, chosen_target)) =
+                self.get_attackable_target(&position_of_unit, &unit)
+            {
+                num_of_actions_performed += 1;
+
+                assert!(self.units.contains_key(&position_of_target));
+
+                let mut chosen_target: Unit = (chosen_target).clone();
+
+                unit.attack(&mut chosen_target);
+                self.attacked(
+                    events,
+                    &unit,
+                    position_of_unit,
+                    chosen_target,
+                    position_of_target,
+                );
+
+                if self.has_lost_an_elf() {
+                    return RoundState::Incomplete;
+                }
+
+                continue;
+            }
+
+            // Otherwise, since it is not in range of a target, it moves; a square at a time,
+            // for as many squares as its faction may move by, or until it is in range of a target.
+            let mut position = position_of_unit;
+            let mut has_moved = false;
+
+            for _ in 0..unit.move_range {
+                let next_move = match self.movement {
+                    Movement::SingleSearch => self.get_next_move(position, &targets),
+                    Movement::SearchPerTarget => self.get_next_move_per_target(position, &targets),
+                };
+
+                let next_move = match next_move {
+                    None => break,
+                    Some(next_move) => next_move,
+                };
+
+                self.units.remove(&position);
+                self.units.insert(next_move, unit.clone());
+                events.push(Event::Moved {
+                    unit: unit.clone(),
+                    from: position,
+                    to: next_move,
+                });
+
+                position = next_move;
+                has_moved = true;
+
+                if self.get_attackable_target(&position, &unit).is_some() {
+                    break;
+                }
+            }
+
+            if has_moved {
+                num_of_actions_performed += 1;
+
+                // After moving (or if the unit began its turn in range of a target), the unit attacks.
+                if let Some((position_of_target, chosen_target)) =
+                    self.get_attackable_target(&position, &unit)
+                {
+                    num_of_actions_performed += 1;
+
+                    assert!(self.units.contains_key(&position_of_target));
+
+                    let mut chosen_target: Unit = (chosen_target).clone();
+
+                    unit.attack(&mut chosen_target);
+                    self.attacked(events, &unit, position, chosen_target, position_of_target);
+
+                    if self.has_lost_an_elf() {
+                        return RoundState::Incomplete;
+                    }
+                }
+            }
+        }
+
+        // combat has ended if no action was performed
+        if num_of_actions_performed <= 0 {
+            return RoundState::Incomplete;
+        }
+
+        RoundState::Complete
+    }
+
+    // the first step towards the nearest square in range of a target, with a search from every
+    // such square back to the unit; this is slower than get_next_move, but is kept to benchmark
+    // against
+    fn get_next_move_per_target(
+        &self,
+        position_of_unit: Coordinate,
+        targets: &[(Coordinate, Unit)],
+    ) -> Option<Coordinate> {
+        let mut reachable_paths: Vec<(Coordinate, Path)> = targets
+            .par_iter()
+            .map(|(position_of_target, _target)| {
+                // for each target, identify open squares adjacent to position_of_target
+                let adjacent_open_squares = self.get_adjacent_open_squares(*position_of_target);
+
+                let reachable_paths: Vec<(Coordinate, Path)> = adjacent_open_squares
+                    .into_par_iter()
+                    .map(|reachable_square| {
+                        let path = get_reachable_path(self, position_of_unit, reachable_square);
+                        (reachable_square, path)
+                    })
+                    .filter(|(_reachable_square, path)| {
+                        // filter out un-reachable squares
+                        path.is_some()
+                    })
+                    .map(|(reachable_square, path)| (reachable_square, path.unwrap()))
+                    .filter(|(_reachable_square, path)| {
+                        // only consider non-empty paths
+                        !path.is_empty()
+                    })
+                    .collect();
+                reachable_paths
+            })
+            .reduce(
+                Vec::new,
+                |mut acc: Vec<(Coordinate, Path)>,
+                 reachable_paths: Vec<(Coordinate, Path)>|
+                 -> Vec<(Coordinate, Path)> {
+                    acc.extend(reachable_paths);
+                    acc
+                },
+            );
+
+        reachable_paths.sort_by(|item_1, item_2| {
+            let (reachable_square_1, path_1) = item_1;
+            let (reachable_square_2, path_2) = item_2;
+
+            let len_1 = path_1.len();
+            let len_2 = path_2.len();
+
+            if len_1 != len_2 {
+                return len_1.cmp(&len_2);
+            }
+
+            reading_order(reachable_square_1, reachable_square_2)
+        });
+
+        let (_reachable_square, path) = reachable_paths.first()?;
+        Some(*path.first().unwrap())
+    }
+
+    // The first step towards the nearest square in range of a target, where ties are broken by
+    // the reading order of the squares; and then of the first steps.
+    //
+    // A single breadth-first search from the unit finds both: squares are visited in the reading
+    // order of their first step, since the first steps are queued in reading order, so the first
+    // step that each square is reached with comes first in reading order among its shortest paths.
+    fn get_next_move(
+        &self,
+        position_of_unit: Coordinate,
+        targets: &[(Coordinate, Unit)],
+    ) -> Option<Coordinate> {
+        let mut in_range = Grid::new(self.terrain.width(), self.terrain.height(), false);
+        for (position_of_target, _target) in targets {
+            for square in self.neighbours(*position_of_target) {
+                if in_range.contains(square) {
+                    in_range[square] = true;
+                }
+            }
+        }
+
+        // the first step of the shortest path to every square that was reached
+        let mut first_steps: Grid<Option<Coordinate>> =
+            Grid::new(self.terrain.width(), self.terrain.height(), None);
+        let mut queue = VecDeque::new();
+
+        for square in self.neighbours(position_of_unit) {
+            if !self.is_occupied(square) {
+                first_steps[square] = Some(square);
+                queue.push_back((square, 0));
+            }
+        }
+
+        // the nearest square in range, and its distance
+        let mut nearest: Option<(Coordinate, Distance)> = None;
+
+        while let Some((square, distance)) = queue.pop_front() {
+            if let Some((nearest_square, nearest_distance)) = nearest {
+                if distance > nearest_distance {
+                    break;
+                }
+
+                if in_range[square] && reading_order(&square, &nearest_square) == Ordering::Less {
+                    nearest = Some((square, distance));
+                }
+
+                continue;
+            }
+
+            if in_range[square] {
+                nearest = Some((square, distance));
+                continue;
+            }
+
+            let first_step = first_steps[square];
+            for adjacent_square in self.neighbours(square) {
+                if !self.is_occupied(adjacent_square) && first_steps[adjacent_square].is_none() {
+                    first_steps[adjacent_square] = first_step;
+                    queue.push_back((adjacent_square, distance + 1));
+                }
+            }
+        }
+
+        let (nearest_square, _distance) = nearest?;
+        first_steps[nearest_square]
+    }
+
+    // the target was attacked, and is either left with fewer hit points or dies
+    fn attacked(
+        &mut self,
+        events: &mut Vec<Event>,
+        unit: &Unit,
+        position_of_unit: Coordinate,
+        chosen_target: Unit,
+        position_of_target: Coordinate,
+    ) {
+        events.push(Event::Attacked {
+            unit: unit.clone(),
+            position: position_of_unit,
+            target: chosen_target.clone(),
+            position_of_target,
+            damage: unit.attack_power,
+        });
+
+        if chosen_target.is_dead() {
+            self.units.remove(&position_of_target);
+            if chosen_target.is_elf() {
+                self.num_of_elves_died += 1;
+            }
+
+            events.push(Event::Died {
+                unit: chosen_target,
+                position: position_of_target,
+            });
+        } else {
+            self.units.insert(position_of_target, chosen_target);
+        }
+    }
+
+    fn apply_event(&mut self, event: &Event) {
+        match event {
+            Event::Moved { from, to, .. } => {
+                let unit = self.units.remove(from).unwrap();
+                self.units.insert(*to, unit);
+            }
+            Event::Attacked {
+                position_of_target,
+                damage,
+                ..
+            } => {
+                self.units.get_mut(position_of_target).unwrap().hit_points -= damage;
+            }
+            Event::Died { unit, position } => {
+                self.units.remove(position);
+                if unit.is_elf() {
+                    self.num_of_elves_died += 1;
+                }
+            }
+        }
+    }
+
+    fn undo_event(&mut self, event: &Event) {
+        match event {
+            Event::Moved { from, to, .. } => {
+                let unit = self.units.remove(to).unwrap();
+                self.units.insert(*from, unit);
+            }
+            Event::Attacked {
+                position_of_target,
+                damage,
+                ..
+            } => {
+                self.units.get_mut(position_of_target).unwrap().hit_points += damage;
+            }
+            Event::Died { unit, position } => {
+                self.units.insert(*position, unit.clone());
+                if unit.is_elf() {
+                    self.num_of_elves_died -= 1;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Unit {
+    id: usize,
+    // the symbol of its faction
+    faction: char,
+    hit_points: i32,
+    attack_power: i32,
+    move_range: usize,
+}
+
+impl Unit {
+    fn new(faction: &Faction, id: usize) -> Unit {
+        Unit {
+            id,
+            faction: faction.symbol,
+            hit_points: faction.hit_points,
+            attack_power: faction.attack_power,
+            move_range: faction.move_range,
+        }
+    }
+
+    fn attack(&self, other_unit: &mut Unit) {
+        other_unit.hit_points -= self.attack_power;
+    }
+
+    fn to_str(&self) -> String {
+        self.faction.to_string()
+    }
+
+    // e.g. G3, which tells units of the same faction apart
+    fn to_id_string(&self) -> String {
+        format!("{}{}", self.to_str(), self.id)
+    }
+
+    fn to_health_string(&self) -> String {
+        format!("{}({})", self.to_str(), self.hit_points)
+    }
+
+    fn is_alive(&self) -> bool {
+        self.hit_points > 0
+    }
+
+    fn is_dead(&self) -> bool {
+        self.hit_points <= 0
+    }
+
+    fn is_elf(&self) -> bool {
+        self.faction == ELF
+    }
+
+    fn is_enemy_of(&self, other: &Unit) -> bool {
+        self.faction != other.faction
+    }
+}
+
+// checks if there is an open path between start and end
+// an open path means a set of coordinates which are not either a wall or occupied by a unit
+// if a path exists, then the vector containing the coordinates from start to end is returned
+fn get_reachable_path(map: &Map, start: Coordinate, end: Coordinate) -> Option<Vec<Coordinate>> {
+    if start == end {
+        return Some(vec![]);
+    }
+
+    // invariant: start and end are different squares
+
+    if map.is_wall(start) || map.is_wall(end) {
+        return None;
+    }
+
+    if map.is_occupied(end) {
+        return None;
+    }
+
+    // NOTE: start could be occupied
+
+    let mut available_squares: BinaryHeap<DistanceCoordinate> = BinaryHeap::new();
+    // keep track of the best minimum distances for a coordinate
+    let mut distances: HashMap<Coordinate, Distance> = HashMap::new();
+    let mut best_edges: HashMap<Coordinate, Coordinate> = HashMap::new();
+
+    // backtrack from end towards start
+    available_squares.push(DistanceCoordinate(0, end));
+    distances.insert(end, 0);
+
+    while let Some(current_square) = available_squares.pop() {
+        // invariant: current_square has the lowest cost
+        // in case of a tie, positions are sorted according to the reading order
+
+        let DistanceCoordinate(current_distance, current_position) = current_square;
+
+        if map.is_in_reach(start, current_position) {
+            let mut path = vec![current_position];
+            let mut current = current_position;
+            while current != end {
+                let nearest_edge = best_edges.get(&current).unwrap();
+                path.push(*nearest_edge);
+                current = *nearest_edge;
+            }
+
+            return Some(path);
+        }
+
+        match distances.get(&current_position) {
+            None => {
+                unreachable!();
+            }
+            Some(best_distance) => {
+                if current_distance > *best_distance {
+                    continue;
+                }
+            }
+        }
+
+        for adjacent_square in map.get_adjacent_open_squares(current_position) {
+            let adjacent_distance = current_distance + 1;
+
+            match distances.get(&adjacent_square) {
+                None => {
+                    best_edges.insert(adjacent_square, current_position);
+                    distances.insert(adjacent_square, adjacent_distance);
+                    available_squares.push(DistanceCoordinate(adjacent_distance, adjacent_square));
+                }
+                Some(best_distance) => {
+                    // NOTE: this potentially adds duplicates to the available_squares min-heap;
+                    // but that's fine :P
+                    // see: https://www3.cs.stonybrook.edu/~rezaul/papers/TR-07-54.pdf
+
+                    if adjacent_distance < *best_distance {
+                        distances.insert(adjacent_square, adjacent_distance);
+                        available_squares
+                            .push(DistanceCoordinate(adjacent_distance, adjacent_square));
+                        best_edges.insert(adjacent_square, current_position);
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// combat begins in a series of rounds
+// in each round, a unit takes a turn, resolving all of its actions before completing their turn
+// invariant: units cannot attack nor move diagonally, unless the rules allow it
+
+// invariant: Units cannot move into walls or other units.
+
+// Rules for units
+// On each unit's turn, it tries to move into range of an enemy (if it isn't already) and then attack (if it is in range).
+// If the unit is already in range of a target, it does not move, but continues its turn with an attack.
+// Otherwise, since it is not in range of a target, it moves.
+
+fn parse_input(input_string: &str) -> Result<Map, ParseError> {
+    parse_input_with_rules(input_string, Rules::default())
+}
+
+// the map, where the rules before it change the given rules
+fn parse_input_with_rules(input_string: &str, mut rules: Rules) -> Result<Map, ParseError> {
+    let mut lines = scan_lines(input_string).peekable();
+
+    while let Some(mut scanner) = lines.next_if(Rules::is_rule) {
+        rules.parse_rule(&mut scanner)?;
+    }
+
+    let lines: Vec<Scanner> = lines.collect();
+    let width = lines
+        .iter()
+        .map(|scanner| scanner.rest().chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut map = Map::new(width, lines.len(), rules);
+
+    for (y, mut scanner) in lines.into_iter().enumerate() {
+        let mut x = 0;
+
+        while !scanner.is_at_end() {
+            let start = scanner;
+            let map_state_as_char = scanner.char("a square", |_| true)?;
+            let position: Coordinate = (x, y as i32);
+
+            if !map.insert(position, map_state_as_char) {
+                return Err(start.error("a wall, an open cavern, or a unit of a faction"));
+            }
+
+            x += 1;
+        }
+    }
+
+    Ok(map)
+}
+
+fn process_map(mut map: Map) -> i32 {
+    let mut num_of_rounds_completed = 0;
+    loop {
+        let round_state = map.execute_round();
+        match round_state {
+            RoundState::Complete => {
+                num_of_rounds_completed += 1;
+            }
+            RoundState::Incomplete => {
+                break;
+            }
+        }
+    }
+
+    let sum_hit_points: i32 = map
+        .units
+        .values()
+        .fold(0, |acc, unit| acc + unit.hit_points);
+
+    // println!("{}", map.to_str());
+
+    // println!("num_of_rounds_completed: {}", num_of_rounds_completed);
+    // println!("sum_hit_points: {}", sum_hit_points);
+
+    num_of_rounds_completed * sum_hit_points
+}
+
+// the events of a round, and whether every unit took its turn
+#[derive(Debug, Clone)]
+struct Round {
+    events: Vec<Event>,
+    is_complete: bool,
+}
+
+// Every round of a combat, from the map that it started with.
+#[derive(Debug, Clone)]
+struct Recording {
+    initial_map: Map,
+    rounds: Vec<Round>,
+}
+
+impl Recording {
+    fn record(initial_map: Map) -> Recording {
+        let mut map = initial_map.clone();
+        let mut rounds = vec![];
+
+        loop {
+            let mut events = vec![];
+            let round_state = map.execute_round_with_events(&mut events);
+            let is_complete = matches!(round_state, RoundState::Complete);
+
+            // the round where combat ends may still have units that took their turn
+            if is_complete || !events.is_empty() {
+                rounds.push(Round {
+                    events,
+                    is_complete,
+                });
+            }
+
+            if !is_complete {
+                break;
+            }
+        }
+
+        Recording {
+            initial_map,
+            rounds,
+        }
+    }
+}
+
+// Steps forward and backward through the rounds of a recording, by applying or undoing the
+// events of one round at a time.
+struct Replay {
+    recording: Recording,
+    map: Map,
+    // the number of rounds that were applied to the map
+    round: usize,
+}
+
+impl Replay {
+    fn new(recording: Recording) -> Replay {
+        Replay {
+            map: recording.initial_map.clone(),
+            recording,
+            round: 0,
+        }
+    }
+
+    fn num_of_rounds(&self) -> usize {
+        self.recording.rounds.len()
+    }
+
+    fn step_forward(&mut self) -> bool {
+        let round = match self.recording.rounds.get(self.round) {
+            None => return false,
+            Some(round) => round,
+        };
+
+        for event in &round.events {
+            self.map.apply_event(event);
+        }
+
+        self.round += 1;
+        true
+    }
+
+    fn step_backward(&mut self) -> bool {
+        if self.round == 0 {
+            return false;
+        }
+
+        self.round -= 1;
+
+        for event in self.recording.rounds[self.round].events.iter().rev() {
+            self.map.undo_event(event);
+        }
+
+        true
+    }
+
+    fn go_to(&mut self, round: usize) {
+        let round = round.min(self.num_of_rounds());
+
+        while self.round < round {
+            self.step_forward();
+        }
+
+        while self.round > round {
+            self.step_backward();
+        }
+    }
+
+    // the events of the last round that was applied
+    fn events(&self) -> &[Event] {
+        match self.round.checked_sub(1) {
+            None => &[],
+            Some(round) => &self.recording.rounds[round].events,
+        }
+    }
+
+    fn to_str(&self) -> String {
+        let heading = match self.round {
+            0 => "Initially:".to_string(),
+            round if self.recording.rounds[round - 1].is_complete => {
+                format!("After {} of {} rounds:", round, self.num_of_rounds())
+            }
+            round => format!("During round {}, when combat ends:", round),
+        };
+
+        format!("{}\n{}", heading, self.map.to_string_with_health())
+    }
+}
+
+const HELP: &str = r###"next, n [count]   step forward by count rounds (default 1)
+prev, p [count]   step backward by count rounds (default 1)
+round, r <round>  go to the map after the round
+first, last       go to the start or the end of the combat
+events, e         list what happened during the last round
+help, h           show this help
+quit, q           leave the replay"###;
+
+fn parse_count(argument: Option<&str>, default: usize) -> Result<usize, String> {
+    match argument {
+        None => Ok(default),
+        Some(argument) => argument
+            .parse()
+            .map_err(|_| format!("expected a number, found {:?}", argument)),
+    }
+}
+
+// Execute a single replay command, and return the lines to show for it.
+fn run_command(replay: &mut Replay, command_line: &str) -> Result<Vec<String>, String> {
+    let mut words = command_line.split_whitespace();
+    let command = match words.next() {
+        Some(command) => command,
+        None => return Ok(vec![]),
+    };
+    let argument = words.next();
+
+    match command {
+        "help" | "h" => return Ok(vec![HELP.to_string()]),
+        "events" | "e" => return Ok(replay.events().iter().map(Event::to_str).collect()),
+        "next" | "n" => {
+            let count = parse_count(argument, 1)?;
+            replay.go_to(replay.round + count);
+        }
+        "prev" | "p" => {
+            let count = parse_count(argument, 1)?;
+            replay.go_to(replay.round.saturating_sub(count));
+        }
+        "round" | "r" => match argument {
+            None => return Err("expected a round".to_string()),
+            Some(_) => replay.go_to(parse_count(argument, 0)?),
+        },
+        "first" => replay.go_to(0),
+        "last" => replay.go_to(replay.num_of_rounds()),
+        _ => return Err(format!("unknown command {:?}; type help", command)),
+    }
+
+    Ok(vec![replay.to_str()])
+}
+
+// Step through the rounds of the combat, reading commands from stdin. The rules before the map
+// change the given rules.
+pub fn replay(input_string: &str, rules: Rules) -> Result<(), ParseError> {
+    let mut replay = Replay::new(Recording::record(parse_input_with_rules(
+        input_string,
+        rules,
+    )?));
+    println!("{}", replay.to_str());
+
+    let stdin = io::stdin();
+    loop {
+        print!("(replay) ");
+        io::stdout().flush().unwrap();
+
+        let mut command_line = String::new();
+        if stdin.lock().read_line(&mut command_line).unwrap() == 0 {
+            break;
+        }
+
+        if matches!(command_line.trim(), "quit" | "q") {
+            break;
+        }
+
+        match run_command(&mut replay, &command_line) {
+            Ok(lines) => {
+                for line in lines {
+                    println!("{}", line);
+                }
+            }
+            Err(error) => println!("error: {}", error),
+        }
+    }
+
+    Ok(())
+}
+
+fn part_1(mut map: Map, movement: Movement) -> i32 {
+    map.with_movement(movement);
+    process_map(map)
+}
+
+// The outcome of combat where no elf dies; None as soon as one does.
+fn battle_without_elf_deaths(original_map: &Map, elf_attack_power: i32) -> Option<i32> {
+    let mut map = original_map.clone();
+    map.with_elf_attack_power(elf_attack_power);
+    map.with_abort_when_an_elf_dies();
+
+    let mut num_of_rounds_completed = 0;
+    loop {
+        let round_state = map.execute_round();
+
+        if map.num_of_elves_died > 0 {
+            return None;
+        }
+
+        match round_state {
+            RoundState::Complete => {
+                num_of_rounds_completed += 1;
+            }
+            RoundState::Incomplete => {
+                break;
+            }
+        }
+    }
+
+    let sum_hit_points: i32 = map.units.values().map(|unit| unit.hit_points).sum();
+
+    Some(num_of_rounds_completed * sum_hit_points)
+}
+
+// Only the number of hits that it takes to kill an enemy changes how combat goes, since enemies
+// are still picked as targets in the same order. So only the lowest attack power for each
+// number of hits is worth trying. That no longer holds once the enemies start with different hit
+// points, since which of them has fewer hit points left then depends on the attack power.
+// interesting hint from: https://www.reddit.com/r/adventofcode/comments/a6nw3b/2018_day_15_part_2_optimisation_ideas/ebxkt28/
+fn get_candidate_attack_powers(map: &Map) -> Vec<i32> {
+    let hit_points: Vec<i32> = map
+        .rules
+        .factions
+        .iter()
+        .filter(|faction| faction.symbol != ELF)
+        .filter(|faction| {
+            map.units
+                .values()
+                .any(|unit| unit.faction == faction.symbol)
+        })
+        .map(|faction| faction.hit_points)
+        .collect();
+
+    let lowest = map.rules.faction(ELF).unwrap().attack_power + 1;
+    let highest = hit_points
+        .iter()
+        .copied()
+        .max()
+        .unwrap_or(lowest)
+        .max(lowest);
+    let mut powers: Vec<i32> = (lowest..=highest).collect();
+
+    if hit_points.iter().all(|hit_points| *hit_points == highest) {
+        let num_of_hits = |attack_power: i32| (highest + attack_power - 1) / attack_power;
+        powers.dedup_by_key(|attack_power| num_of_hits(*attack_power));
+    }
+
+    powers
+}
+
+// The outcome of the first attack power, in order, whose battle has one. Raising the attack
+// power need not keep the elves from dying, since it changes which goblins die first and so
+// where the others move, so no attack power is skipped. A few battles run in parallel
+// at a time, and a batch is only used when every attack power before it had no outcome.
+fn first_outcome<F>(powers: &[i32], battle: F) -> Option<i32>
+where
+    F: Fn(i32) -> Option<i32> + Sync,
+{
+    let num_of_battles_at_a_time = rayon::current_num_threads().max(1);
+
+    for batch in powers.chunks(num_of_battles_at_a_time) {
+        let outcomes: Vec<Option<i32>> = batch
+            .par_iter()
+            .map(|attack_power| battle(*attack_power))
+            .collect();
+
+        if let Some(outcome) = outcomes.into_iter().flatten().next() {
+            return Some(outcome);
+        }
+    }
+
+    None
+}
+
+// the outcome of combat with the lowest attack power where no elf dies
+fn part_2(original_map: Map) -> Option<i32> {
+    let powers = get_candidate_attack_powers(&original_map);
+
+    first_outcome(&powers, |attack_power| {
+        battle_without_elf_deaths(&original_map, attack_power)
+    })
+}
+
+// tries every attack power in turn; this is slower than part_2, but is kept to benchmark against
+fn part_2_linear(original_map: Map) -> i32 {
+    // interesting hint from: https://www.reddit.com/r/adventofcode/comments/a6nw3b/2018_day_15_part_2_optimisation_ideas/ebxkt28/
+    let powers = vec![
+        4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 19, 20, 23, 25, 29, 34, 40, 50, 67, 100,
+        200,
+    ];
+
+    'attack_power_loop: for elf_attack_power in powers {
+        let mut map = original_map.clone();
+
+        map.with_elf_attack_power(elf_attack_power);
+
+        let mut num_of_rounds_completed = 0;
+        loop {
+            let round_state = map.execute_round();
+
+            if map.num_of_elves_died > 0 {
+                continue 'attack_power_loop;
+            }
+
+            match round_state {
+                RoundState::Complete => {
+                    num_of_rounds_completed += 1;
+                }
+                RoundState::Incomplete => {
+                    break;
+                }
+            }
+        }
+
+        let sum_hit_points: i32 = map
+            .units
+            .values()
+            .fold(0, |acc, unit| acc + unit.hit_points);
+
+        // println!("{}", map.to_str());
+
+        // println!("num_of_rounds_completed: {}", num_of_rounds_completed);
+        // println!("sum_hit_points: {}", sum_hit_points);
+
+        let result = num_of_rounds_completed * sum_hit_points;
+
+        return result;
+    }
+
+    0
+}
+
+fn solve_part_1(input_string: &str, rules: Rules) -> Option<String> {
+    let map = parse_input_with_rules(input_string, rules).ok()?;
+    Some(part_1(map, Movement::SingleSearch).to_string())
+}
+
+fn solve_part_2(input_string: &str, rules: Rules) -> Option<String> {
+    part_2(parse_input_with_rules(input_string, rules).ok()?).map(|outcome| outcome.to_string())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        solve_part_1(input_string, Rules::default())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        solve_part_2(input_string, Rules::default())
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![
+            Variant {
+                name: "search-per-target",
+                part: Part::One,
+                solve: |input_string| {
+                    let map = parse_input(input_string).ok()?;
+                    Some(part_1(map, Movement::SearchPerTarget).to_string())
+                },
+            },
+            Variant {
+                name: "linear-search",
+                part: Part::Two,
+                solve: |input_string| {
+                    Some(part_2_linear(parse_input(input_string).ok()?).to_string())
+                },
+            },
+        ]
+    }
+}
+
+// The puzzle under other rules, e.g. those of a file given with --rules. The rules before the
+// map change these rules.
+pub struct Day15WithRules(pub Rules);
+
+impl Solution for Day15WithRules {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input_with_rules(input_string, self.0.clone())?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        solve_part_1(input_string, self.0.clone())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        solve_part_2(input_string, self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num_of_units(map: &Map, faction: char) -> usize {
+        map.units
+            .values()
+            .filter(|unit| unit.faction == faction)
+            .count()
+    }
+
+    #[test]
+    fn test_coord_reading_order() {
+        let test: Vec<Coordinate> = vec![(2, 27), (3, 26), (2, 26), (1, 26), (2, 25)];
+        let expected = {
+            let mut x = test.clone();
+            x.reverse();
+            x
+        };
+
+        {
+            let mut test = test.clone();
+            test.sort_by(reading_order);
+
+            assert_eq!(test, expected);
+        }
+
+        {
+            let mut available_squares: BinaryHeap<DistanceCoordinate> = BinaryHeap::new();
+
+            let items = vec![
+                DistanceCoordinate(5, (1, 26)),
+                DistanceCoordinate(5, (2, 25)),
+                DistanceCoordinate(4, (2, 30)),
+            ];
+            available_squares.extend(items);
+
+            let mut actual: Vec<Coordinate> = vec![];
+            while let Some(item) = available_squares.pop() {
+                actual.push(item.into());
+            }
+
+            assert_eq!(actual, vec![(2, 30), (2, 25), (1, 26)]);
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let input_string = r###"
+#########
+#G..G..G#
+#.......#
+#.......#
+#G..E..G#
+#.......#
+#.......#
+#G..G..G#
+#########
+        "###
+        .trim();
+
+        let map = parse_input(input_string).unwrap();
+
+        assert_eq!(map.to_str(), input_string);
+
+        assert!(map.is_wall((0, 0)));
+        assert!(map.is_occupied((0, 0)));
+
+        assert!(!map.is_wall((1, 1)));
+        assert!(map.is_occupied((1, 1)));
+
+        assert!(!map.is_wall((2, 1)));
+        assert!(!map.is_occupied((2, 1)));
+
+        assert_eq!(num_of_units(&map, ELF), 1);
+        assert_eq!(num_of_units(&map, GOBLIN), 8);
+    }
+
+    #[test]
+    fn test_is_reachable() {
+        let input_string = r###"
+###################
+#.E...............#
+#################.#
+#...G.............#
+###################
+        "###
+        .trim();
+
+        let map = parse_input(input_string).unwrap();
+
+        assert_eq!(
+            get_reachable_path(
+                &map,
+                (2, 1), /* position of the elf */
+                (5, 3)  /* position of square adjacent to goblin */
+            )
+            .map(|path| path.len()),
+            Some(29)
+        );
+    }
+
+    #[test]
+    fn test_scenario() {
+        let input_string = r###"
+#########
+#G..G..G#
+#.......#
+#.......#
+#G..E..G#
+#.......#
+#.......#
+#G..G..G#
+#########
+        "###
+        .trim();
+
+        let mut map = parse_input(input_string).unwrap();
+
+        // round 1
+        map.execute_round();
+        assert_eq!(
+            map.to_str(),
+            r###"
+#########
+#.G...G.#
+#...G...#
+#...E..G#
+#.G.....#
+#.......#
+#G..G..G#
+#.......#
+#########
+        "###
+            .trim()
+        );
+
+        // round 2
+        map.execute_round();
+        assert_eq!(
+            map.to_str(),
+            r###"
+#########
+#..G.G..#
+#...G...#
+#.G.E.G.#
+#.......#
+#G..G..G#
+#.......#
+#.......#
+#########
+        "###
+            .trim()
+        );
+
+        // round 3
+        map.execute_round();
+        assert_eq!(
+            map.to_str(),
+            r###"
+#########
+#.......#
+#..GGG..#
+#..GEG..#
+#G..G...#
+#......G#
+#.......#
+#.......#
+#########
+        "###
+            .trim()
+        );
+    }
+
+    // both ways of moving have the same outcome
+    fn assert_part_1(input_string: &str, expected: i32) {
+        let map = parse_input(input_string).unwrap();
+        assert_eq!(part_1(map.clone(), Movement::SingleSearch), expected);
+        assert_eq!(part_1(map, Movement::SearchPerTarget), expected);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = r###"
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
+        "###
+        .trim();
+
+        assert_part_1(input_string, 27730);
+
+        let input_string = r###"
+#######
+#G..#E#
+#E#E.E#
+#G.##.#
+#...#E#
+#...E.#
+#######
+        "###
+        .trim();
+
+        assert_part_1(input_string, 36334);
+
+        let input_string = r###"
+#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######
+        "###
+        .trim();
+
+        assert_part_1(input_string, 39514);
+
+        let input_string = r###"
+#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######
+        "###
+        .trim();
+
+        assert_part_1(input_string, 27755);
+
+        let input_string = r###"
+#######
+#.E...#
+#.#..G#
+#.###.#
+#E#G#G#
+#...#G#
+#######
+        "###
+        .trim();
+
+        assert_part_1(input_string, 28944);
+
+        let input_string = r###"
+#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########
+        "###
+        .trim();
+
+        assert_part_1(input_string, 18740);
+
+        // from: https://www.reddit.com/r/adventofcode/comments/a6f100/day_15_details_easy_to_be_wrong_on/
+
+        let input_string = r###"
+####
+##E#
+#GG#
+####
+        "###
+        .trim();
+
+        assert_part_1(input_string, 67 * 200);
+
+        let input_string = r###"
+#####
+#GG##
+#.###
+#..E#
+#.#G#
+#.E##
+#####
+        "###
+        .trim();
+
+        assert_part_1(input_string, 71 * 197);
+    }
+
+    #[test]
+    fn test_edge_case_1() {
+        let input_string = r###"
+#######
+#.E..G#
+#.#####
+#G#####
+#######
+        "###
+        .trim();
+
+        let mut map = parse_input(input_string).unwrap();
+
+        // round 1
+        // after round 1, the elf should not be going left
+        // src: https://www.reddit.com/r/adventofcode/comments/a6f100/day_15_details_easy_to_be_wrong_on/ebvkuxr/
+        map.execute_round();
+        assert_eq!(
+            map.to_str(),
+            r###"
+#######
+#..EG.#
+#G#####
+#.#####
+#######
+        "###
+            .trim()
+        );
+    }
+
+    #[test]
+    fn test_part_2_with_elf_attack() {
+        fn part_2_with_elf_attack(input_string: &str, elf_attack_power: i32) -> i32 {
+            let mut map = parse_input(input_string).unwrap();
+            map.with_elf_attack_power(elf_attack_power);
+            process_map(map)
+        }
+
+        let input_string = r###"
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
+        "###
+        .trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 15), 4988);
+
+        let input_string = r###"
+#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######
+        "###
+        .trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 4), 31284);
+
+        let input_string = r###"
+#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######
+        "###
+        .trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 15), 3478);
+
+        let input_string = r###"
+#######
+#.E...#
+#.#..G#
+#.###.#
+#E#G#G#
+#...#G#
+#######
+        "###
+        .trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 12), 6474);
+
+        let input_string = r###"
+#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########
+        "###
+        .trim();
+
+        assert_eq!(part_2_with_elf_attack(input_string, 34), 1140);
+    }
+
+    // both searches find the same attack power
+    fn assert_part_2(input_string: &str, expected: i32) {
+        let map = parse_input(input_string).unwrap();
+        assert_eq!(part_2(map.clone()), Some(expected));
+        assert_eq!(part_2_linear(map), expected);
+    }
+
+    #[test]
+    fn test_first_outcome() {
+        // the lowest attack power with an outcome, even when a higher one has none
+        let battle = |attack_power: i32| match attack_power {
+            5 | 7 | 8 | 9 => Some(attack_power * 100),
+            _ => None,
+        };
+        let powers: Vec<i32> = (4..=10).collect();
+        assert_eq!(first_outcome(&powers, battle), Some(500));
+        assert_eq!(first_outcome(&powers[2..], battle), Some(700));
+        assert_eq!(first_outcome(&[4, 6, 10], battle), None);
+    }
+
+    #[test]
+    fn test_candidate_attack_powers() {
+        let map = parse_input("#####\n#E.G#\n#####").unwrap();
+        assert_eq!(
+            get_candidate_attack_powers(&map),
+            vec![
+                4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 19, 20, 23, 25, 29, 34, 40, 50,
+                67, 100, 200
+            ]
+        );
+
+        let map = parse_input("faction G hp 20 attack 5\n#####\n#E.G#\n#####").unwrap();
+        assert_eq!(get_candidate_attack_powers(&map), vec![4, 5, 7, 10, 20]);
+
+        // enemies with different hit points may be picked in a different order
+        let map = parse_input("faction O hp 50\n######\n#E.GO#\n######").unwrap();
+        assert_eq!(
+            get_candidate_attack_powers(&map),
+            (4..=200).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_battle_without_elf_deaths() {
+        let input_string = r###"
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
+        "###
+        .trim();
+
+        let map = parse_input(input_string).unwrap();
+        assert_eq!(battle_without_elf_deaths(&map, 15), Some(4988));
+        assert_eq!(battle_without_elf_deaths(&map, 14), None);
+
+        // combat stops during the round where the first elf dies
+        let mut map = parse_input(input_string).unwrap();
+        map.with_elf_attack_power(14);
+        map.with_abort_when_an_elf_dies();
+        while let RoundState::Complete = map.execute_round() {}
+        assert_eq!(map.num_of_elves_died, 1);
+        assert!(num_of_units(&map, GOBLIN) > 0);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = r###"
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
+        "###
+        .trim();
+
+        assert_part_2(input_string, 4988);
+
+        let input_string = r###"
+#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######
+        "###
+        .trim();
+
+        assert_part_2(input_string, 31284);
+
+        let input_string = r###"
+#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######
+        "###
+        .trim();
+
+        assert_part_2(input_string, 3478);
+
+        let input_string = r###"
+#######
+#.E...#
+#.#..G#
+#.###.#
+#E#G#G#
+#...#G#
+#######
+        "###
+        .trim();
+
+        assert_part_2(input_string, 6474);
+
+        let input_string = r###"
+#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########
+        "###
+        .trim();
+
+        assert_part_2(input_string, 1140);
+    }
+
+    #[test]
+    fn test_replay() {
+        let input_string = r###"
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
+        "###
+        .trim();
+
+        // combat ends after 47 full rounds, when the first unit of round 48 has no targets left
+        let recording = Recording::record(parse_input(input_string).unwrap());
+        assert_eq!(recording.rounds.len(), 47);
+        assert!(recording.rounds.iter().all(|round| round.is_complete));
+
+        // stepping forward matches running the rounds
+        let mut map = parse_input(input_string).unwrap();
+        let mut replay = Replay::new(recording);
+        while replay.step_forward() {
+            map.execute_round();
+            assert_eq!(
+                replay.map.to_string_with_health(),
+                map.to_string_with_health()
+            );
+        }
+        assert_eq!(replay.round, 47);
+
+        // and stepping backward goes back through the same maps
+        let initial_map = parse_input(input_string).unwrap().to_string_with_health();
+        replay.go_to(2);
+        let mut map = parse_input(input_string).unwrap();
+        map.execute_round();
+        map.execute_round();
+        assert_eq!(
+            replay.map.to_string_with_health(),
+            map.to_string_with_health()
+        );
+
+        replay.go_to(0);
+        assert_eq!(replay.map.to_string_with_health(), initial_map);
+        assert!(!replay.step_backward());
+
+        // combat ends after 37 full rounds, but some units still take their turn in round 38
+        let input_string = r###"
+#######
+#G..#E#
+#E#E.E#
+#G.##.#
+#...#E#
+#...E.#
+#######
+        "###
+        .trim();
+
+        let recording = Recording::record(parse_input(input_string).unwrap());
+        assert_eq!(recording.rounds.len(), 38);
+        assert!(!recording.rounds[37].is_complete);
+
+        let mut replay = Replay::new(recording);
+        replay.go_to(38);
+        assert!(replay
+            .to_str()
+            .starts_with("During round 38, when combat ends:"));
+    }
+
+    #[test]
+    fn test_run_command() {
+        let input_string = r###"
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######
+        "###
+        .trim();
+
+        let mut replay = Replay::new(Recording::record(parse_input(input_string).unwrap()));
+
+        assert_eq!(
+            run_command(&mut replay, "next").unwrap(),
+            vec![r###"After 1 of 47 rounds:
+#######
+#..G..#   G(200)
+#...EG#   E(197), G(197)
+#.#G#G#   G(200), G(197)
+#...#E#   E(197)
+#.....#
+#######"###
+                .replace("   ", "  ")]
+        );
+
+        assert_eq!(
+            run_command(&mut replay, "events").unwrap(),
+            vec![
+                "G0 moved from (2, 1) to (3, 1)",
+                "E1 at (4, 2) attacked G2 at (5, 2) for 3, leaving 197 hit points",
+                "G2 at (5, 2) attacked E1 at (4, 2) for 3, leaving 197 hit points",
+                "G3 at (5, 3) attacked E5 at (5, 4) for 3, leaving 197 hit points",
+                "G4 moved from (3, 4) to (3, 3)",
+                "E5 at (5, 4) attacked G3 at (5, 3) for 3, leaving 197 hit points",
+            ]
+        );
+
+        run_command(&mut replay, "last").unwrap();
+        let lines = run_command(&mut replay, "p 45").unwrap();
+        assert!(lines[0].starts_with("After 2 of 47 rounds:"));
+
+        assert!(run_command(&mut replay, "round x").is_err());
+        assert!(run_command(&mut replay, "fly").is_err());
+    }
+
+    #[test]
+    fn test_rules() {
+        let rules = Rules::parse(
+            r###"
+faction E attack 10
+faction O hp 300 attack 5 move 2
+adjacency diagonal
+        "###,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rules,
+            Rules {
+                factions: vec![
+                    Faction {
+                        symbol: ELF,
+                        hit_points: 200,
+                        attack_power: 10,
+                        move_range: 1,
+                    },
+                    Faction::new(GOBLIN),
+                    Faction {
+                        symbol: 'O',
+                        hit_points: 300,
+                        attack_power: 5,
+                        move_range: 2,
+                    },
+                ],
+                diagonal_adjacency: true,
+            }
+        );
+
+        let errors = vec![
+            (
+                "faction EE",
+                "line 1, column 9: expected a single character other than # or . for the \
+                 faction, found \"EE\"",
+            ),
+            (
+                "faction E speed 2",
+                "line 1, column 11: expected hp, attack or move, found \"speed\"",
+            ),
+            (
+                "faction E hp 0",
+                "line 1, column 14: expected a positive integer, found \"0\"",
+            ),
+            (
+                "adjacency hex",
+                "line 1, column 11: expected diagonal or orthogonal, found \"hex\"",
+            ),
+            (
+                "attack 4",
+                "line 1, column 1: expected faction or adjacency, found \"attack\"",
+            ),
+        ];
+
+        for (input_string, error) in errors {
+            assert_eq!(Rules::parse(input_string).unwrap_err().to_string(), error);
+        }
+    }
+
+    #[test]
+    fn test_parse_input_with_rules() {
+        let input_string = r###"
+faction E hp 10 attack 4
+
+#####
+#E.G#
+#####
+        "###
+        .trim();
+
+        let map = parse_input(input_string).unwrap();
+        assert_eq!(map.to_str(), "#####\n#E.G#\n#####");
+        assert_eq!(map.units[&(1, 1)].hit_points, 10);
+        assert_eq!(map.units[&(1, 1)].attack_power, 4);
+        assert_eq!(map.units[&(3, 1)].hit_points, 200);
+
+        // the elf takes 4 rounds to die, and leaves the goblin with 200 - 4 * 4 hit points
+        assert_eq!(part_1(map, Movement::SingleSearch), 4 * 184);
+
+        // the rules given are changed by the rules before the map
+        let rules = Rules::parse("faction G hp 5").unwrap();
+        let map = parse_input_with_rules(input_string, rules).unwrap();
+        assert_eq!(map.units[&(3, 1)].hit_points, 5);
+
+        let error = parse_input("#####\n#E.O#\n#####").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a wall, an open cavern, or a unit of a faction, found \
+             \"O\""
+        );
+
+        // rules only come before the map
+        assert!(parse_input("#####\n#E.G#\n#####\nadjacency diagonal").is_err());
+    }
+
+    #[test]
+    fn test_solve_with_rules() {
+        let input_string = r###"
+#####
+#E.G#
+#####
+        "###
+        .trim();
+
+        // the elf kills the goblin in 67 rounds, and is left with 200 - 66 * 3 hit points
+        assert_eq!(Day15.part_1(input_string), Some((67 * 2).to_string()));
+
+        // the elf kills the goblin in 20 rounds instead, and is left with 200 - 19 * 3 hit points
+        let solution = Day15WithRules(Rules::parse("faction E attack 10").unwrap());
+        assert_eq!(solution.parse(input_string), Ok(true));
+        assert_eq!(solution.part_1(input_string), Some((20 * 143).to_string()));
+
+        // the rules before the map still change the given rules
+        let input_string = format!("faction G hp 10\n{}", input_string);
+        assert_eq!(solution.part_1(&input_string), Some(200.to_string()));
+    }
+
+    #[test]
+    fn test_three_factions() {
+        // the elf and the goblin both attack the ogre, which attacks whichever of them has the
+        // fewest hit points
+        let input_string = r###"
+faction O hp 50 attack 50
+#######
+#E.O.G#
+#######
+        "###
+        .trim();
+
+        let map = parse_input(input_string).unwrap();
+        assert_eq!(num_of_units(&map, 'O'), 1);
+
+        let recording = Recording::record(map.clone());
+        assert_eq!(
+            recording.rounds[0]
+                .events
+                .iter()
+                .map(Event::to_str)
+                .collect::<Vec<String>>(),
+            vec![
+                "E0 moved from (1, 1) to (2, 1)",
+                "E0 at (2, 1) attacked O1 at (3, 1) for 3, leaving 47 hit points",
+                "O1 at (3, 1) attacked E0 at (2, 1) for 50, leaving 150 hit points",
+                "G2 moved from (5, 1) to (4, 1)",
+                "G2 at (4, 1) attacked O1 at (3, 1) for 3, leaving 44 hit points",
+            ]
+        );
+
+        // the elf dies in round 4, and the goblin in round 8; leaving the ogre with 17 hit points
+        assert_eq!(part_1(map, Movement::SingleSearch), 8 * 17);
+    }
+
+    #[test]
+    fn test_diagonal_adjacency() {
+        let input_string = r###"
+#####
+#E..#
+#..G#
+#####
+        "###
+        .trim();
+
+        let map = parse_input(input_string).unwrap();
+        assert!(!map.is_in_reach((2, 1), (3, 2)));
+
+        let events = |input_string: &str| -> Vec<String> {
+            let recording = Recording::record(parse_input(input_string).unwrap());
+            recording.rounds[0]
+                .events
+                .iter()
+                .map(Event::to_str)
+                .collect()
+        };
+
+        assert_eq!(
+            events(input_string),
+            vec![
+                "E0 moved from (1, 1) to (2, 1)",
+                "G1 moved from (3, 2) to (3, 1)",
+                "G1 at (3, 1) attacked E0 at (2, 1) for 3, leaving 197 hit points",
+            ]
+        );
+
+        let input_string = format!("adjacency diagonal\n{}", input_string);
+        let map = parse_input(&input_string).unwrap();
+        assert!(map.is_in_reach((2, 1), (3, 2)));
+
+        // the elf moves to the first square in reading order that touches the goblin, and
+        // attacks it across the corner
+        assert_eq!(
+            events(&input_string),
+            vec![
+                "E0 moved from (1, 1) to (2, 1)",
+                "E0 at (2, 1) attacked G1 at (3, 2) for 3, leaving 197 hit points",
+                "G1 at (3, 2) attacked E0 at (2, 1) for 3, leaving 197 hit points",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_move_range() {
+        let events = |input_string: &str| -> Vec<String> {
+            let recording = Recording::record(parse_input(input_string).unwrap());
+            recording.rounds[0]
+                .events
+                .iter()
+                .map(Event::to_str)
+                .collect()
+        };
+
+        // the elf stops once it is in range of the goblin
+        assert_eq!(
+            events("faction E move 5\n#######\n#E...G#\n#######"),
+            vec![
+                "E0 moved from (1, 1) to (2, 1)",
+                "E0 moved from (2, 1) to (3, 1)",
+                "E0 moved from (3, 1) to (4, 1)",
+                "E0 at (4, 1) attacked G1 at (5, 1) for 3, leaving 197 hit points",
+                "G1 at (5, 1) attacked E0 at (4, 1) for 3, leaving 197 hit points",
+            ]
+        );
+
+        assert_eq!(
+            events("faction E move 2\n#######\n#E...G#\n#######"),
+            vec![
+                "E0 moved from (1, 1) to (2, 1)",
+                "E0 moved from (2, 1) to (3, 1)",
+                "G1 moved from (5, 1) to (4, 1)",
+                "G1 at (4, 1) attacked E0 at (3, 1) for 3, leaving 197 hit points",
+            ]
+        );
+
+        // units that do not move still attack
+        assert_eq!(
+            events("faction E move 0\n#####\n#E.G#\n#####"),
+            vec![
+                "G1 moved from (3, 1) to (2, 1)",
+                "G1 at (2, 1) attacked E0 at (1, 1) for 3, leaving 197 hit points",
+            ]
+        );
+
+        // every step is undone when stepping backward
+        let input_string = "faction E move 5\n#######\n#E...G#\n#######";
+        let mut replay = Replay::new(Recording::record(parse_input(input_string).unwrap()));
+        let initially = replay.to_str();
+        replay.step_forward();
+        replay.step_backward();
+        assert_eq!(replay.to_str(), initially);
+    }
+}
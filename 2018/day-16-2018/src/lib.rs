@@ -0,0 +1,263 @@
+// https://adventofcode.com/2018/day/16
+
+// imports
+
+use std::fmt;
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use elfcode::{
+    infer_opcodes, with_opcode, Error, InferenceError, NumberedInstruction, Registers, Sample,
+    Value,
+};
+
+// code
+
+// the device of day 16 has four registers
+const NUM_OF_REGISTERS: usize = 4;
+
+// The puzzle input: the samples of the first section, and the test program of the second
+// section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Manual {
+    samples: Vec<Sample>,
+    program: Vec<NumberedInstruction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Day16Error {
+    Parse(ParseError),
+    Inference(InferenceError),
+    UnknownOpcode { opcode_number: Value },
+    Execution(Error),
+}
+
+impl fmt::Display for Day16Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Day16Error::Parse(error) => write!(f, "{}", error),
+            Day16Error::Inference(error) => write!(f, "{}", error),
+            Day16Error::UnknownOpcode { opcode_number } => write!(
+                f,
+                "no sample shows which opcode number {} stands for",
+                opcode_number
+            ),
+            Day16Error::Execution(error) => write!(f, "unable to run the test program: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for Day16Error {}
+
+// e.g. [3, 2, 1, 1]
+fn parse_registers(mut scanner: Scanner, label: &str) -> Result<Registers, ParseError> {
+    scanner.literal(label)?;
+    scanner.skip_whitespace();
+    scanner.literal("[")?;
+
+    let mut values: Vec<Value> = vec![];
+    for index in 0..NUM_OF_REGISTERS {
+        if index > 0 {
+            scanner.literal(",")?;
+            scanner.skip_whitespace();
+        }
+        values.push(scanner.integer()?);
+    }
+
+    scanner.literal("]")?;
+    scanner.end()?;
+
+    Ok(Registers::from(values))
+}
+
+// e.g. 9 2 1 2
+fn parse_numbered_instruction(mut scanner: Scanner) -> Result<NumberedInstruction, ParseError> {
+    let mut instruction: NumberedInstruction = [0; 4];
+
+    for (index, value) in instruction.iter_mut().enumerate() {
+        if index > 0 {
+            scanner.skip_whitespace();
+        }
+        *value = scanner.integer()?;
+    }
+    scanner.end()?;
+
+    Ok(instruction)
+}
+
+fn parse_input(input_string: &str) -> Result<Manual, ParseError> {
+    let mut scanners = scan_lines(input_string).peekable();
+
+    let mut samples = vec![];
+
+    while let Some(before_scanner) =
+        scanners.next_if(|scanner| scanner.rest().starts_with("Before:"))
+    {
+        let line_number = before_scanner.line_number();
+        let before = parse_registers(before_scanner, "Before:")?;
+
+        let instruction = parse_numbered_instruction(
+            scanners
+                .next()
+                .unwrap_or_else(|| Scanner::new(line_number + 1, "")),
+        )?;
+
+        let after = parse_registers(
+            scanners
+                .next()
+                .unwrap_or_else(|| Scanner::new(line_number + 2, "")),
+            "After:",
+        )?;
+
+        samples.push(Sample {
+            before,
+            instruction,
+            after,
+        });
+    }
+
+    let program = scanners
+        .map(parse_numbered_instruction)
+        .collect::<Result<Vec<NumberedInstruction>, ParseError>>()?;
+
+    Ok(Manual { samples, program })
+}
+
+// the number of samples that behave like three or more opcodes
+fn part_1(input_string: &str) -> Option<usize> {
+    let manual = parse_input(input_string).ok()?;
+
+    Some(
+        manual
+            .samples
+            .iter()
+            .filter(|sample| sample.candidates().len() >= 3)
+            .count(),
+    )
+}
+
+// the value of register 0 after running the test program
+pub fn part_2(input_string: &str) -> Result<Value, Day16Error> {
+    let manual = parse_input(input_string).map_err(Day16Error::Parse)?;
+
+    let opcode_map = infer_opcodes(&manual.samples).map_err(Day16Error::Inference)?;
+
+    // The registers start with the value 0.
+    let mut registers = Registers::new(NUM_OF_REGISTERS);
+
+    for instruction in &manual.program {
+        let opcode_number = instruction[0];
+
+        let opcode = opcode_map
+            .get(&opcode_number)
+            .ok_or(Day16Error::UnknownOpcode { opcode_number })?;
+
+        with_opcode(instruction, *opcode)
+            .execute(&mut registers)
+            .map_err(Day16Error::Execution)?;
+    }
+
+    registers.get(0).map_err(Day16Error::Execution)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string).ok().map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use elfcode::Opcode;
+
+    #[test]
+    fn test_opcode_matches() {
+        {
+            let opcode = Opcode::Addr;
+
+            // valid
+
+            let mut sample = Sample {
+                before: Registers::from(vec![1, 2, 3, 4]),
+                instruction: [99, 0, 1, 0],
+                after: Registers::from(vec![3, 2, 3, 4]),
+            };
+
+            assert!(sample.behaves_like(opcode));
+
+            // invalid
+
+            sample.after = Registers::from(vec![0, 2, 3, 4]);
+
+            assert!(!sample.behaves_like(opcode));
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input_string = "Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n\n\n\n9 0 0 0\n";
+
+        let manual = parse_input(input_string).unwrap();
+        assert_eq!(manual.samples.len(), 1);
+        assert_eq!(manual.samples[0].after, Registers::from(vec![3, 2, 2, 1]));
+        assert_eq!(manual.program, vec![[9, 0, 0, 0]]);
+
+        assert_eq!(part_1(input_string), Some(1));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("Before: [3, 2, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 17: expected \",\", found \"]\""
+        );
+
+        let error = parse_input("Before: [3, 2, 1, 1]\n9 2 1 2\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected \"After:\", found end of line"
+        );
+
+        let error = parse_input("9 2 1 2\n9 2 1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+    }
+
+    #[test]
+    fn test_part_2_errors() {
+        // the sample behaves like several opcodes
+        let input_string = "Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n\n9 0 0 0\n";
+        assert!(matches!(
+            part_2(input_string),
+            Err(Day16Error::Inference(InferenceError::Ambiguous { .. }))
+        ));
+
+        let error = part_2("9 0 0 0\n").unwrap_err();
+        assert_eq!(error, Day16Error::UnknownOpcode { opcode_number: 9 });
+        assert_eq!(
+            error.to_string(),
+            "no sample shows which opcode number 9 stands for"
+        );
+
+        assert!(matches!(part_2("9 0 0"), Err(Day16Error::Parse(_))));
+    }
+}
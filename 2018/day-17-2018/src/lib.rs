@@ -0,0 +1,1030 @@
+// https://adventofcode.com/2018/day/17
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Part, Scanner, Solution, Variant};
+use aoc_grid::{Coordinate, Transitions};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, ImageResult, Rgba, RgbaImage};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+// code
+
+// position of the water spring
+const WATER_SPRING: Coordinate = (500, 0);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Water {
+    AtRest,
+    Flowing,
+}
+
+enum MapState {
+    Clay,
+    Water(Water),
+}
+
+type Terrain = HashMap<Coordinate, MapState>;
+
+// how water floods the ground
+#[derive(Debug, Clone, Copy)]
+enum Flooding {
+    // with a stack of the steps that are left to do
+    WorkList,
+    // with a call for every square that water reaches; a deep enough flow overflows the call
+    // stack
+    Recursive,
+}
+
+// What is left to do while flooding, in place of the calls of flood_recursive.
+enum Step {
+    // water reaches the square
+    Flood(Coordinate),
+    // the water below the square has settled, and the water spreads sideways if it rests on it
+    Spread(Coordinate),
+    // the water below the square that the row scan is at has settled
+    Scanned(RowScan),
+}
+
+// Water spreading sideways from where it landed, first to the left and then to the right, for as
+// long as it rests on clay or on water at rest.
+struct RowScan {
+    origin: Coordinate,
+    current: Coordinate,
+    // whether the water was bounded by clay or water at rest on the left, once that side is done
+    has_left_wall: Option<bool>,
+    // the squares that come to rest if the water is bounded on both sides
+    water_at_rest: Vec<Coordinate>,
+}
+
+impl RowScan {
+    fn new(origin: Coordinate) -> Self {
+        RowScan {
+            origin,
+            current: origin.left(),
+            has_left_wall: None,
+            water_at_rest: vec![origin],
+        }
+    }
+
+    fn step(&mut self) {
+        self.water_at_rest.push(self.current);
+
+        self.current = match self.has_left_wall {
+            None => self.current.left(),
+            Some(_) => self.current.right(),
+        };
+    }
+
+    // The scan reached the end of a side, and turns right after the left side. Once both sides
+    // are done, this is whether the water is bounded on both sides.
+    fn end_side(&mut self, has_wall: bool) -> Option<bool> {
+        match self.has_left_wall {
+            None => {
+                self.has_left_wall = Some(has_wall);
+                self.current = self.origin.right();
+                None
+            }
+            Some(has_left_wall) => Some(has_left_wall && has_wall),
+        }
+    }
+}
+
+// colours of the squares in the images of the map
+const SAND: Rgba<u8> = Rgba([238, 220, 178, 255]);
+const CLAY: Rgba<u8> = Rgba([130, 84, 52, 255]);
+const FLOWING_WATER: Rgba<u8> = Rgba([135, 200, 250, 255]);
+const WATER_AT_REST: Rgba<u8> = Rgba([25, 85, 190, 255]);
+const SPRING: Rgba<u8> = Rgba([215, 40, 40, 255]);
+
+// how long each frame of the animation is shown for, and the last one for longer
+const FRAME_DELAY_MS: u32 = 40;
+const LAST_FRAME_DELAY_MS: u32 = 3000;
+
+struct Map {
+    terrain: Terrain,
+
+    min_y: Option<i32>,
+    max_y: Option<i32>,
+
+    // every square that water flowed into or came to rest at, in order; only once recording
+    changes: Option<Vec<(Coordinate, Water)>>,
+}
+
+impl Map {
+    fn new() -> Self {
+        Map {
+            terrain: HashMap::new(),
+            min_y: None,
+            max_y: None,
+            changes: None,
+        }
+    }
+
+    fn with_recording(&mut self) {
+        self.changes = Some(vec![]);
+    }
+
+    fn num_of_rested_water_tiles(&mut self) -> i32 {
+        let min_y = self.min_y();
+        let max_y = self.max_y();
+
+        let mut total = 0;
+
+        for (position, _tile) in self.terrain.iter() {
+            let (_x, y) = position;
+
+            if y < &min_y {
+                continue;
+            }
+
+            if y > &max_y {
+                continue;
+            }
+
+            if self.is_water_at_rest(position) {
+                total += 1;
+            }
+        }
+
+        total
+    }
+
+    fn num_of_water_tiles(&mut self) -> i32 {
+        let min_y = self.min_y();
+        let max_y = self.max_y();
+
+        let mut total = 0;
+
+        for (position, _tile) in self.terrain.iter() {
+            let (_x, y) = position;
+
+            if y < &min_y {
+                continue;
+            }
+
+            if y > &max_y {
+                continue;
+            }
+
+            if self.is_water(position) {
+                total += 1;
+            }
+        }
+
+        total
+    }
+
+    fn is_coord_out_of_bounds(&mut self, position: &Coordinate) -> bool {
+        let (_x, y) = position;
+
+        let max_y = self.max_y();
+
+        if y > &max_y {
+            return true;
+        }
+
+        if y < &0 {
+            return true;
+        }
+
+        false
+    }
+
+    fn min_y(&mut self) -> i32 {
+        if let Some(value) = self.min_y {
+            return value;
+        }
+
+        let value = self
+            .terrain
+            .iter()
+            .filter(|item| {
+                let (coord, _map_state) = item;
+                self.is_clay(coord)
+            })
+            .map(|item| {
+                let (coord, _map_state) = item;
+                let (_x, y) = coord;
+                *y
+            })
+            .min()
+            .unwrap();
+
+        self.min_y = Some(value);
+
+        value
+    }
+
+    fn max_y(&mut self) -> i32 {
+        if let Some(value) = self.max_y {
+            return value;
+        }
+
+        let value = self
+            .terrain
+            .iter()
+            .filter(|item| {
+                let (coord, _map_state) = item;
+                self.is_clay(coord)
+            })
+            .map(|item| {
+                let (coord, _map_state) = item;
+                let (_x, y) = coord;
+                *y
+            })
+            .max()
+            .unwrap();
+
+        self.max_y = Some(value);
+
+        value
+    }
+
+    fn min_x(&mut self) -> i32 {
+        self.terrain
+            .iter()
+            .map(|item| {
+                let (coord, _map_state) = item;
+                let (x, _y) = coord;
+                *x
+            })
+            .min()
+            .unwrap()
+    }
+
+    fn max_x(&mut self) -> i32 {
+        self.terrain
+            .iter()
+            .map(|item| {
+                let (coord, _map_state) = item;
+                let (x, _y) = coord;
+                *x
+            })
+            .max()
+            .unwrap()
+    }
+
+    fn insert_clay(&mut self, clay_coordinate: &Coordinate) {
+        // clay can never be right where the water spring is positioned
+        assert!(clay_coordinate != &WATER_SPRING);
+
+        self.terrain.insert(*clay_coordinate, MapState::Clay);
+    }
+
+    #[allow(dead_code)]
+    fn to_string_mut(&mut self) -> String {
+        let max_y = self.max_y();
+        let min_x = self.min_x();
+        let max_x = self.max_x();
+
+        let mut map_string: Vec<String> = vec![];
+
+        for y in 0..=max_y {
+            let mut row_string = String::from("");
+
+            for x in min_x..=max_x {
+                let position = (x, y);
+
+                match self.terrain.get(&position) {
+                    None => {
+                        if position == WATER_SPRING {
+                            row_string.push('+');
+                        } else {
+                            row_string.push('.');
+                        }
+                    }
+                    Some(map_state) => {
+                        assert!(position != WATER_SPRING);
+
+                        match map_state {
+                            MapState::Clay => {
+                                row_string.push('#');
+                            }
+                            MapState::Water(water) => match water {
+                                Water::AtRest => {
+                                    row_string.push('~');
+                                }
+                                Water::Flowing => {
+                                    row_string.push('|');
+                                }
+                            },
+                        };
+                    }
+                }
+            }
+
+            map_string.push(row_string);
+        }
+
+        map_string.join("\n")
+    }
+
+    fn is_clay(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(position) {
+            None => false,
+            Some(map_state) => matches!(map_state, MapState::Clay),
+        }
+    }
+
+    fn is_dry_sand(&self, position: &Coordinate) -> bool {
+        !self.terrain.contains_key(position)
+    }
+
+    fn set_water(&mut self, position: &Coordinate, water: Water) {
+        self.terrain.insert(*position, MapState::Water(water));
+
+        if let Some(changes) = &mut self.changes {
+            changes.push((*position, water));
+        }
+    }
+
+    fn upgrade_water(&mut self, position: &Coordinate) {
+        match self.terrain.get(position) {
+            None => {
+                self.set_water(position, Water::Flowing);
+            }
+            Some(map_state) => {
+                match map_state {
+                    MapState::Water(water_state) => match water_state {
+                        Water::Flowing => {
+                            self.set_water(position, Water::AtRest);
+                        }
+                        Water::AtRest => {}
+                    },
+                    MapState::Clay => {
+                        unreachable!();
+                    }
+                };
+            }
+        }
+    }
+
+    fn is_water_at_rest(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(position) {
+            None => false,
+            Some(map_state) => match map_state {
+                MapState::Clay => false,
+                MapState::Water(water_state) => match water_state {
+                    Water::Flowing => false,
+                    Water::AtRest => true,
+                },
+            },
+        }
+    }
+
+    fn is_water_flowing(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(position) {
+            None => false,
+            Some(map_state) => match map_state {
+                MapState::Clay => false,
+                MapState::Water(water_state) => match water_state {
+                    Water::Flowing => true,
+                    Water::AtRest => false,
+                },
+            },
+        }
+    }
+
+    fn is_water(&self, position: &Coordinate) -> bool {
+        match self.terrain.get(position) {
+            None => false,
+            Some(map_state) => match map_state {
+                MapState::Clay => false,
+                MapState::Water(_water_state) => true,
+            },
+        }
+    }
+
+    // whether water on top of the square comes to rest, or keeps falling
+    fn is_supported(&self, position: &Coordinate) -> bool {
+        self.is_clay(position) || self.is_water_at_rest(position)
+    }
+
+    // The same flood as flood_recursive, with the steps that are left to do kept on a stack rather
+    // than the call stack; so the height of the ground, and how far water falls, is only limited
+    // by memory. Every call of flood_recursive becomes a Flood step, and every row scan resumes
+    // where the recursive one would after the call for the square below it returns. So this is
+    // not a scanline fill that settles a row at a time, and water reaches the squares in the same
+    // order as with flood_recursive.
+    fn flood(&mut self, position: &Coordinate) {
+        let mut steps = vec![Step::Flood(*position)];
+
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Flood(position) => {
+                    if self.is_coord_out_of_bounds(&position)
+                        || self.is_water_flowing(&position)
+                        || self.is_supported(&position)
+                    {
+                        continue;
+                    }
+
+                    self.upgrade_water(&position);
+
+                    // flood downward, and spread sideways once that settles
+                    steps.push(Step::Spread(position));
+                    steps.push(Step::Flood(position.down()));
+                }
+                Step::Spread(position) => {
+                    if self.is_supported(&position.down()) {
+                        self.scan_row(RowScan::new(position), &mut steps);
+                    }
+                }
+                Step::Scanned(mut scan) => {
+                    if self.is_supported(&scan.current.down()) {
+                        scan.step();
+                        self.scan_row(scan, &mut steps);
+                    } else {
+                        // the water falls off this side of the row
+                        self.end_side(scan, false, &mut steps);
+                    }
+                }
+            }
+        }
+    }
+
+    // Spread the water along the row, until it has to flood the square below the scan first.
+    fn scan_row(&mut self, scan: RowScan, steps: &mut Vec<Step>) {
+        let current = scan.current;
+
+        if self.is_supported(&current) {
+            self.end_side(scan, true, steps);
+            return;
+        }
+
+        if self.is_dry_sand(&current) {
+            self.upgrade_water(&current);
+        }
+
+        steps.push(Step::Scanned(scan));
+        steps.push(Step::Flood(current.down()));
+    }
+
+    fn end_side(&mut self, mut scan: RowScan, has_wall: bool, steps: &mut Vec<Step>) {
+        match scan.end_side(has_wall) {
+            None => self.scan_row(scan, steps),
+            Some(true) => {
+                for current in scan.water_at_rest {
+                    self.upgrade_water(&current);
+                }
+            }
+            Some(false) => {}
+        }
+    }
+
+    // this is kept to compare and benchmark the work list against
+    fn flood_recursive(&mut self, position: &Coordinate) {
+        if self.is_coord_out_of_bounds(position) {
+            return;
+        }
+
+        if self.is_water_flowing(position) {
+            return;
+        }
+
+        if self.is_clay(position) || self.is_water_at_rest(position) {
+            return;
+        }
+
+        if self.is_dry_sand(position) {
+            self.upgrade_water(position);
+        }
+
+        // flood downward
+        let down_position = position.down();
+
+        self.flood_recursive(&down_position);
+
+        if !(self.is_clay(&down_position) || self.is_water_at_rest(&down_position)) {
+            return;
+        }
+
+        // flood left
+        let mut water_at_rest = vec![];
+        water_at_rest.push(*position);
+
+        let mut current = position.left();
+        let has_left_wall;
+
+        loop {
+            if self.is_clay(&current) || self.is_water_at_rest(&current) {
+                has_left_wall = true;
+                break;
+            }
+
+            if self.is_dry_sand(&current) {
+                self.upgrade_water(&current);
+            }
+
+            let down = current.down();
+            self.flood_recursive(&down);
+
+            if !(self.is_clay(&down) || self.is_water_at_rest(&down)) {
+                has_left_wall = false;
+                break;
+            }
+
+            water_at_rest.push(current);
+
+            current = current.left();
+        }
+
+        // flood right
+        let mut current = position.right();
+        let has_right_wall;
+
+        loop {
+            if self.is_clay(&current) || self.is_water_at_rest(&current) {
+                has_right_wall = true;
+                break;
+            }
+
+            if self.is_dry_sand(&current) {
+                self.upgrade_water(&current);
+            }
+
+            let down = current.down();
+            self.flood_recursive(&down);
+
+            if !(self.is_clay(&down) || self.is_water_at_rest(&down)) {
+                has_right_wall = false;
+                break;
+            }
+
+            water_at_rest.push(current);
+
+            current = current.right();
+        }
+
+        if has_left_wall && has_right_wall {
+            for current in water_at_rest {
+                self.upgrade_water(&current);
+            }
+        }
+    }
+
+    fn run_flood(&mut self) {
+        self.flood(&WATER_SPRING.down());
+    }
+
+    fn run_flood_recursive(&mut self) {
+        self.flood_recursive(&WATER_SPRING.down());
+    }
+
+    // The columns and rows that the images show: from the spring down to the lowest clay, and
+    // one column past the clay on either side, which is as far as water spreads. None if there
+    // is no clay.
+    fn image_bounds(&mut self) -> Option<(i32, i32, i32)> {
+        let clay_xs = || {
+            self.terrain
+                .iter()
+                .filter(|(_coord, map_state)| matches!(map_state, MapState::Clay))
+                .map(|((x, _y), _map_state)| *x)
+        };
+
+        let min_x = clay_xs().min()?.min(WATER_SPRING.0) - 1;
+        let max_x = clay_xs().max()?.max(WATER_SPRING.0) + 1;
+
+        // the row of the spring is shown even when all of the clay is above it
+        Some((min_x, max_x, self.max_y().max(WATER_SPRING.1)))
+    }
+
+    fn colour(map_state: Option<&MapState>) -> Rgba<u8> {
+        match map_state {
+            None => SAND,
+            Some(MapState::Clay) => CLAY,
+            Some(MapState::Water(Water::Flowing)) => FLOWING_WATER,
+            Some(MapState::Water(Water::AtRest)) => WATER_AT_REST,
+        }
+    }
+
+    // a pixel for every square, with the water as it is now
+    fn to_image_mut(&mut self) -> Option<RgbaImage> {
+        let (min_x, max_x, max_y) = self.image_bounds()?;
+
+        let mut image =
+            RgbaImage::from_fn((max_x - min_x + 1) as u32, (max_y + 1) as u32, |x, y| {
+                Map::colour(self.terrain.get(&(min_x + x as i32, y as i32)))
+            });
+
+        image.put_pixel((WATER_SPRING.0 - min_x) as u32, 0, SPRING);
+        Some(image)
+    }
+
+    // An animation of the water filling the map, from the changes that were recorded; the
+    // changes are spread evenly across the frames, and the last frame shows the final state.
+    fn write_gif<W: Write>(&mut self, writer: W, num_of_frames: usize) -> ImageResult<()> {
+        let (min_x, _max_x, _max_y) = self.image_bounds().ok_or_else(no_clay_to_draw)?;
+        let changes = self.changes.clone().unwrap_or_default();
+
+        // the clay, before any water flowed
+        let mut image = self.to_image_mut().ok_or_else(no_clay_to_draw)?;
+        for ((x, y), _water) in changes.iter() {
+            image.put_pixel((x - min_x) as u32, *y as u32, SAND);
+        }
+
+        let num_of_changes_per_frame = changes.len().div_ceil(num_of_frames.max(1)).max(1);
+
+        let mut encoder = GifEncoder::new_with_speed(writer, 30);
+        encoder.set_repeat(Repeat::Infinite)?;
+        encoder.encode_frame(Frame::from_parts(
+            image.clone(),
+            0,
+            0,
+            Delay::from_numer_denom_ms(FRAME_DELAY_MS, 1),
+        ))?;
+
+        let mut frames = changes.chunks(num_of_changes_per_frame).peekable();
+        while let Some(frame_changes) = frames.next() {
+            for ((x, y), water) in frame_changes {
+                let map_state = MapState::Water(*water);
+                image.put_pixel((x - min_x) as u32, *y as u32, Map::colour(Some(&map_state)));
+            }
+
+            let delay = if frames.peek().is_none() {
+                LAST_FRAME_DELAY_MS
+            } else {
+                FRAME_DELAY_MS
+            };
+
+            encoder.encode_frame(Frame::from_parts(
+                image.clone(),
+                0,
+                0,
+                Delay::from_numer_denom_ms(delay, 1),
+            ))?;
+        }
+
+        Ok(())
+    }
+}
+
+// the images only show the squares around the clay
+fn no_clay_to_draw() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "there is no clay to draw")
+}
+
+// e.g. x=495, y=2..7
+fn parse_vein(mut scanner: Scanner) -> Result<Vec<Coordinate>, ParseError> {
+    let axis = scanner.char("x or y", |c| c == 'x' || c == 'y')?;
+    scanner.literal("=")?;
+    let value: i32 = scanner.integer()?;
+    scanner.literal(",")?;
+    scanner.skip_whitespace();
+
+    let other_axis = if axis == 'x' { "y" } else { "x" };
+    scanner.literal(other_axis)?;
+    scanner.literal("=")?;
+    let start: i32 = scanner.integer()?;
+    scanner.literal("..")?;
+    let end_scanner = scanner;
+    let end: i32 = scanner.integer()?;
+
+    if end < start {
+        return Err(end_scanner.error(format!("a range end of at least {}", start)));
+    }
+
+    scanner.end()?;
+
+    let vein = (start..=end)
+        .map(|n| if axis == 'x' { (value, n) } else { (n, value) })
+        .collect();
+
+    Ok(vein)
+}
+
+fn generate_map(input_string: &str) -> Result<Map, ParseError> {
+    // parse positions of clay, and add them to the terrain
+
+    let mut map = Map::new();
+
+    for scanner in scan_lines(input_string) {
+        for coordinate in parse_vein(scanner)? {
+            // clay can never be right where the water spring is positioned
+            if coordinate == WATER_SPRING {
+                return Err(scanner.error("clay that is not at the water spring"));
+            }
+
+            map.insert_clay(&coordinate);
+        }
+    }
+
+    // the water flows down to the lowest clay
+    if map.terrain.is_empty() {
+        return Err(Scanner::new(1, "").error("a vein of clay"));
+    }
+
+    Ok(map)
+}
+
+fn flooded_map(input_string: &str, flooding: Flooding) -> Option<Map> {
+    let mut map = generate_map(input_string).ok()?;
+
+    match flooding {
+        Flooding::WorkList => map.run_flood(),
+        Flooding::Recursive => map.run_flood_recursive(),
+    }
+
+    Some(map)
+}
+
+// Flood the map, and save a PNG image of where the water ended up, and an animated GIF of the
+// water filling the map.
+pub fn export(
+    input_string: &str,
+    png_path: &Path,
+    gif_path: &Path,
+    num_of_frames: usize,
+) -> ImageResult<()> {
+    let mut map = generate_map(input_string)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    map.with_recording();
+    map.run_flood();
+
+    map.to_image_mut()
+        .ok_or_else(no_clay_to_draw)?
+        .save(png_path)?;
+
+    let mut writer = BufWriter::new(File::create(gif_path)?);
+    map.write_gif(&mut writer, num_of_frames)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(generate_map(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        let mut map = flooded_map(input_string, Flooding::WorkList)?;
+        Some(map.num_of_water_tiles().to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        let mut map = flooded_map(input_string, Flooding::WorkList)?;
+        Some(map.num_of_rested_water_tiles().to_string())
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![Variant {
+            name: "recursive",
+            part: Part::One,
+            solve: |input_string| {
+                let mut map = flooded_map(input_string, Flooding::Recursive)?;
+                Some(map.num_of_water_tiles().to_string())
+            },
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn part_1_and_2() {
+        let input_string = include_str!("input.txt");
+        let mut map = generate_map(input_string).unwrap();
+
+        map.run_flood();
+
+        // Part 1
+        assert_eq!(map.num_of_water_tiles(), 33004);
+
+        // Part 2
+        assert_eq!(map.num_of_rested_water_tiles(), 23294);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = generate_map("x=495, y=2..7\ny=7, y=495..501")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected \"x\", found \"y\""
+        );
+
+        let error = generate_map("x=495, y=7..2").err().unwrap();
+        assert_eq!(error.expected, "a range end of at least 7");
+        assert_eq!(error.column, 13);
+
+        let error = generate_map("y=0, x=499..501").err().unwrap();
+        assert_eq!(error.expected, "clay that is not at the water spring");
+
+        let error = generate_map("\n").err().unwrap();
+        assert_eq!(error.expected, "a vein of clay");
+    }
+
+    #[test]
+    fn test_map() {
+        let input_string = r###"
+x=495, y=2..7
+y=7, x=495..501
+x=501, y=3..7
+x=498, y=2..4
+x=506, y=1..2
+x=498, y=10..13
+x=504, y=10..13
+y=13, x=498..504
+        "###
+        .trim();
+
+        let expected = r###"
+.....+......
+...........#
+#..#.......#
+#..#..#.....
+#..#..#.....
+#.....#.....
+#.....#.....
+#######.....
+............
+............
+...#.....#..
+...#.....#..
+...#.....#..
+...#######..
+        "###
+        .trim();
+
+        let mut map = generate_map(input_string).unwrap();
+
+        assert_eq!(map.to_string_mut(), expected);
+
+        map.run_flood();
+
+        assert_eq!(map.num_of_water_tiles(), 57);
+    }
+
+    #[test]
+    fn test_images() {
+        use image::codecs::gif::GifDecoder;
+        use image::AnimationDecoder;
+        use std::io::Cursor;
+
+        let input_string = r###"
+x=495, y=2..7
+y=7, x=495..501
+x=501, y=3..7
+x=498, y=2..4
+x=506, y=1..2
+x=498, y=10..13
+x=504, y=10..13
+y=13, x=498..504
+        "###
+        .trim();
+
+        let mut map = generate_map(input_string).unwrap();
+        map.with_recording();
+        map.run_flood();
+
+        // flowing water turns into water at rest in place, which is recorded as another change
+        let changes = map.changes.clone().unwrap();
+        assert_eq!(
+            changes.len(),
+            map.num_of_water_tiles() as usize + map.num_of_rested_water_tiles() as usize
+        );
+        assert_eq!(changes[0], ((500, 1), Water::Flowing));
+
+        // columns 494 to 507, and rows 0 to 13
+        let image = map.to_image_mut().unwrap();
+        assert_eq!(image.dimensions(), (14, 14));
+        assert_eq!(*image.get_pixel(500 - 494, 0), SPRING);
+        assert_eq!(*image.get_pixel(495 - 494, 2), CLAY);
+        assert_eq!(*image.get_pixel(500 - 494, 1), FLOWING_WATER);
+        assert_eq!(*image.get_pixel(500 - 494, 6), WATER_AT_REST);
+        assert_eq!(*image.get_pixel(494 - 494, 0), SAND);
+
+        let mut gif = vec![];
+        map.write_gif(&mut gif, 10).unwrap();
+
+        let frames = GifDecoder::new(Cursor::new(gif))
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+
+        // the clay on its own, and then 10 frames of water
+        assert_eq!(frames.len(), 11);
+        assert_eq!(*frames[0].buffer().get_pixel(500 - 494, 1), SAND);
+        assert_eq!(*frames[0].buffer().get_pixel(495 - 494, 2), CLAY);
+        assert_eq!(frames[10].buffer(), &image);
+
+        // without clay, there is nothing to draw around
+        let mut map = Map::new();
+        assert!(map.to_image_mut().is_none());
+        assert_eq!(
+            map.write_gif(vec![], 10).unwrap_err().to_string(),
+            "there is no clay to draw"
+        );
+
+        // clay above the spring leaves the row of the spring
+        let mut map = generate_map("y=-3, x=499..501").unwrap();
+        map.run_flood();
+        assert_eq!(map.to_image_mut().unwrap().dimensions(), (5, 1));
+    }
+
+    // Clay veins of the same shapes as in the puzzle input: vertical and horizontal lines, and
+    // basins of three lines that hold water.
+    fn random_veins(rng: &mut StdRng, num_of_veins: usize) -> String {
+        let mut veins = vec![];
+
+        for _ in 0..num_of_veins {
+            let (x, y) = (rng.gen_range(470..530), rng.gen_range(1..80));
+            let length = rng.gen_range(0..12);
+
+            match rng.gen_range(0..3) {
+                0 => veins.push(format!("x={}, y={}..{}", x, y, y + length)),
+                1 => veins.push(format!("y={}, x={}..{}", y, x, x + length)),
+                _ => {
+                    let depth = rng.gen_range(1..8);
+                    veins.push(format!("x={}, y={}..{}", x, y, y + depth));
+                    veins.push(format!("x={}, y={}..{}", x + length + 2, y, y + depth));
+                    veins.push(format!("y={}, x={}..{}", y + depth, x, x + length + 2));
+                }
+            }
+        }
+
+        veins.join("\n")
+    }
+
+    #[test]
+    fn test_work_list_matches_recursion() {
+        let mut rng = StdRng::seed_from_u64(17);
+
+        for _ in 0..500 {
+            let num_of_veins = rng.gen_range(1..40);
+            let input_string = random_veins(&mut rng, num_of_veins);
+
+            let mut map = generate_map(&input_string).unwrap();
+            map.with_recording();
+            map.run_flood();
+
+            let mut expected = generate_map(&input_string).unwrap();
+            expected.with_recording();
+            expected.run_flood_recursive();
+
+            assert_eq!(
+                map.num_of_water_tiles(),
+                expected.num_of_water_tiles(),
+                "{}",
+                input_string
+            );
+            assert_eq!(
+                map.num_of_rested_water_tiles(),
+                expected.num_of_rested_water_tiles(),
+                "{}",
+                input_string
+            );
+
+            // the water even reaches the squares in the same order
+            assert_eq!(map.changes, expected.changes, "{}", input_string);
+        }
+    }
+
+    #[test]
+    fn test_tall_input() {
+        // water falls down a channel that is far deeper than the call stack of a test allows for
+        // with flood_recursive, and fills it up to the top
+        let depth = 100_000;
+        let input_string = format!(
+            "x=499, y=1..{depth}\nx=501, y=1..{depth}\ny={depth}, x=499..501",
+            depth = depth
+        );
+
+        let mut map = generate_map(&input_string).unwrap();
+        map.run_flood();
+
+        assert_eq!(map.num_of_water_tiles(), depth - 1);
+        assert_eq!(map.num_of_rested_water_tiles(), depth - 1);
+
+        // and water that falls all the way through
+        let input_string = format!("x=480, y=1..2\nx=520, y={}..{}", depth - 1, depth);
+
+        let mut map = generate_map(&input_string).unwrap();
+        map.run_flood();
+
+        assert_eq!(map.num_of_water_tiles(), depth);
+        assert_eq!(map.num_of_rested_water_tiles(), 0);
+    }
+}
@@ -0,0 +1,357 @@
+// https://adventofcode.com/2018/day/18
+
+// imports
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Grid, Point};
+use automaton::Simulation;
+use rayon::prelude::*;
+use std::fmt;
+
+// code
+
+type CollectionArea = Grid<Acre>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Acre {
+    Ground,
+    Tree,
+    Lumberyard,
+}
+
+impl Acre {
+    fn from_char(acre: char) -> Self {
+        match acre {
+            '.' => Acre::Ground,
+            '|' => Acre::Tree,
+            '#' => Acre::Lumberyard,
+            _ => {
+                unreachable!();
+            }
+        }
+    }
+
+    fn next(&self, adjacent_acres: Vec<Acre>) -> Self {
+        match self {
+            Acre::Ground => {
+                // An open acre will become filled with trees if three or more
+                // adjacent acres contained trees. Otherwise, nothing happens.
+                let num_of_adjacent_trees = adjacent_acres
+                    .par_iter()
+                    .filter(|s| **s == Acre::Tree)
+                    .count();
+
+                if num_of_adjacent_trees >= 3 {
+                    return Acre::Tree;
+                }
+
+                self.clone()
+            }
+            Acre::Tree => {
+                // An acre filled with trees will become a lumberyard if three or more
+                // adjacent acres were lumberyards. Otherwise, nothing happens.
+                let num_of_adjacent_lumberyards = adjacent_acres
+                    .par_iter()
+                    .filter(|s| **s == Acre::Lumberyard)
+                    .count();
+
+                if num_of_adjacent_lumberyards >= 3 {
+                    return Acre::Lumberyard;
+                }
+                self.clone()
+            }
+            Acre::Lumberyard => {
+                // An acre containing a lumberyard will remain a lumberyard if it was adjacent
+                // to at least one other lumberyard and at least one acre containing trees.
+                // Otherwise, it becomes open.
+                let num_of_adjacent_lumberyards = adjacent_acres
+                    .par_iter()
+                    .filter(|s| **s == Acre::Lumberyard)
+                    .count();
+
+                let num_of_adjacent_trees = adjacent_acres
+                    .par_iter()
+                    .filter(|s| **s == Acre::Tree)
+                    .count();
+
+                if num_of_adjacent_lumberyards >= 1 && num_of_adjacent_trees >= 1 {
+                    return self.clone();
+                }
+
+                Acre::Ground
+            }
+        }
+    }
+}
+
+impl fmt::Display for Acre {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let result = match self {
+            Acre::Ground => '.',
+            Acre::Tree => '|',
+            Acre::Lumberyard => '#',
+        };
+
+        write!(f, "{}", result)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Area {
+    area: CollectionArea,
+}
+
+impl Area {
+    fn num_of_trees(&self) -> usize {
+        self.area.values().filter(|s| **s == Acre::Tree).count()
+    }
+
+    fn num_of_lumberyards(&self) -> usize {
+        self.area
+            .values()
+            .filter(|s| **s == Acre::Lumberyard)
+            .count()
+    }
+
+    #[allow(dead_code)]
+    fn to_str(&self) -> String {
+        self.area.to_string()
+    }
+
+    fn tick(&mut self) {
+        let prev_area = &self.area;
+        let positions: Vec<Point> = prev_area.points().collect();
+
+        let next_area: Vec<Acre> = positions
+            .par_iter()
+            .map(|coord| {
+                let adjacent = get_adjacent(prev_area, coord);
+
+                // Changes happen across all acres simultaneously,
+                // each of them using the state of all acres at the beginning of the minute
+                // and changing to their new form by the end of that same minute.
+                // Changes that happen during the minute don't affect each other.
+
+                // ✨ magic
+                prev_area[*coord].next(adjacent)
+            })
+            .collect();
+
+        self.area = Grid::from_vec(prev_area.width(), prev_area.height(), next_area);
+    }
+}
+
+fn get_adjacent(area: &CollectionArea, position: &Point) -> Vec<Acre> {
+    area.neighbours_8(*position)
+        .map(|coord| area[coord].clone())
+        .collect()
+}
+
+fn generate_area(input_string: &str) -> Area {
+    let input_string: Vec<&str> = input_string
+        .trim()
+        .lines()
+        .map(|line| line.trim())
+        .collect();
+    let area = Grid::parse(&input_string.join("\n"), |_position, acre| {
+        Acre::from_char(acre)
+    })
+    .unwrap();
+
+    Area { area }
+}
+
+fn part_1(input_string: &str, ticks: i32) -> usize {
+    let mut area = generate_area(input_string);
+
+    for _ in 1..=ticks {
+        area.tick();
+    }
+
+    area.num_of_lumberyards() * area.num_of_trees()
+}
+
+fn part_2(input_string: &str) -> Option<usize> {
+    // the areas repeat long before a billion minutes have passed
+    let simulation = Simulation::new(generate_area(input_string), Area::tick, Area::clone);
+    let area = simulation.state_at(1_000_000_000)?;
+
+    Some(area.num_of_lumberyards() * area.num_of_trees())
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(generate_area(input_string));
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string, 10).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area_string() {
+        let expected_string = include_str!("input.txt");
+
+        let area = generate_area(expected_string);
+
+        assert_eq!(area.to_str(), expected_string);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input_string = r###"
+.#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.
+    "###
+        .trim();
+
+        assert_eq!(part_1(input_string, 10), 1147);
+    }
+
+    #[test]
+    fn example() {
+        let input_string = r###"
+.#.#...|#.
+.....#|##|
+.|..|...#.
+..|#.....#
+#.#|||#|#|
+...#.||...
+.|....|...
+||...#|.#|
+|.||||..|.
+...#.|..|.
+    "###
+        .trim();
+
+        let mut area = generate_area(input_string);
+
+        // initial
+        assert_eq!(area.to_str(), input_string);
+
+        // after 1 minute
+        area.tick();
+
+        let result = r###"
+.......##.
+......|###
+.|..|...#.
+..|#||...#
+..##||.|#|
+...#||||..
+||...|||..
+|||||.||.|
+||||||||||
+....||..|.
+    "###
+        .trim();
+
+        assert_eq!(area.to_str(), result);
+
+        // after 2 minutes
+        area.tick();
+
+        let result = r###"
+.......#..
+......|#..
+.|.|||....
+..##|||..#
+..###|||#|
+...#|||||.
+|||||||||.
+||||||||||
+||||||||||
+.|||||||||
+    "###
+        .trim();
+
+        assert_eq!(area.to_str(), result);
+
+        // after 3 minutes
+        area.tick();
+
+        let result = r###"
+.......#..
+....|||#..
+.|.||||...
+..###|||.#
+...##|||#|
+.||##|||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+    "###
+        .trim();
+
+        assert_eq!(area.to_str(), result);
+
+        // after 4 minutes
+        area.tick();
+
+        let result = r###"
+.....|.#..
+...||||#..
+.|.#||||..
+..###||||#
+...###||#|
+|||##|||||
+||||||||||
+||||||||||
+||||||||||
+||||||||||
+    "###
+        .trim();
+
+        assert_eq!(area.to_str(), result);
+
+        // after 10 minutes
+        for _ in 5..=10 {
+            area.tick();
+        }
+
+        let result = r###"
+.||##.....
+||###.....
+||##......
+|##.....##
+|##.....##
+|##....##|
+||##.####|
+||#####|||
+||||#|||||
+||||||||||
+    "###
+        .trim();
+
+        assert_eq!(area.to_str(), result);
+    }
+}
@@ -0,0 +1,186 @@
+// https://adventofcode.com/2018/day/19
+
+use aoc_core::{ParseError, Solution};
+use elfcode::{OptimisedProgram, Program};
+
+// code
+
+// the device of day 19 has six registers
+const NUM_OF_REGISTERS: usize = 6;
+
+fn parse_input(input_string: &str) -> Result<Program, ParseError> {
+    Program::parse(input_string, NUM_OF_REGISTERS)
+}
+
+fn run_program(program: Program) -> i64 {
+    let mut program = OptimisedProgram::new(program);
+    program.run().unwrap();
+    program.program().registers().get(0).unwrap()
+}
+
+fn part_1(program: Program) -> i64 {
+    run_program(program)
+}
+
+// without the optimiser,
+// part 2 spends quite a lot of time on these instructions
+
+// instruction 2:
+//
+// seti 1 9 1
+//
+// reg[1] = 1
+
+// (BEGIN do while loop body)
+//
+// instruction 3:
+//
+// OpcodeInstruction(Mulr, 3, 1, Two)
+// reg[2] = reg[3] * reg[1]
+//
+// part of if block guard
+
+// instruction 4:
+//
+// OpcodeInstruction(Eqrr, 2, 5, Two)
+//
+// reg[2] = reg[2] == reg[5]
+//
+// if block guard result
+
+// instruction 5:
+//
+// OpcodeInstruction(Addr, 2, 4, Four)
+//
+// reg[4] = reg[2] + reg[4]
+//
+// if reg[2] == 0, go to instruction 6 (else block)
+// if reg[2] == 1, go to instruction 7 (if block)
+
+// instruction 6:
+//
+// OpcodeInstruction(Addi, 4, 1, Four)
+//
+// reg[4] = reg[4] + 1
+//
+// go to instruction 8
+//
+// NOTE: notice that there is no else block; the if-block was skipped
+
+// instruction 7:
+//
+// addr 3 0 0
+//
+// reg[0] = reg[3] + reg[0]
+//
+// if block
+
+// instruction 8:
+//
+// OpcodeInstruction(Addi, 1, 1, One)
+//
+// reg[1] = reg[1] + 1
+
+// (END do while loop body)
+
+// instruction 9:
+//
+// OpcodeInstruction(Gtrr, 1, 5, Two)
+//
+// reg[2] = reg[1] > reg[5]
+//
+// part of do while loop guard
+
+// instruction 10:
+//
+// OpcodeInstruction(Addr, 4, 2, Four)
+//
+// reg[4] = reg[4] + reg[2]
+//
+// if reg[2] == 0, go to instruction 11, (this is like a not operation on the while-loop guard)
+// otherwise if reg[2] == 1, go to instruction 12 (exit the loop)
+
+// instruction 11:
+//
+// OpcodeInstruction(Seti, 2, 9, Four)
+//
+// reg[4] = 2
+//
+// go to instruction 3 (i.e. run do-while loop body again)
+//
+
+// instruction 12:
+//
+// addi 3 1 3
+//
+// reg[3] = reg[3] + 1
+
+/*
+
+naive interpretation:
+
+reg[1] = 1
+do {
+
+    if(reg[3] * reg[1] == reg[5]) {
+        reg[0] = reg[0] + reg[3]
+    }
+
+    reg[1] = reg[1] + 1
+
+} while ( reg[1] <= reg[5] )
+
+interpretation:
+    the while loop is incrementing reg[1] from 1 to reg[5].
+
+    if reg[5] is a multiple of reg[3] during this loop,
+    i.e. if there exists a positive integer reg[1], ranging from 1 to reg[5]
+
+        then reg[3] is added to reg[0]
+
+    in other words, check if reg[5] % reg[3] == 0
+
+invariants:
+    reg[3] cannot be 0; since no integer value of reg[1] exists to satisfy reg[5] = reg[3] * reg[1]
+
+*/
+
+// the optimiser recognises both loops as a divisor sum, and adds up the divisors of reg[5] directly
+fn part_2(program: Program) -> i64 {
+    run_program(program)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        let program = parse_input(input_string).ok()?;
+
+        // A new background process immediately spins up in its place.
+        // It appears identical, but on closer inspection, you notice that this time,
+        // register 0 started with the value 1.
+
+        let mut other_program = program.fork();
+
+        other_program.registers_mut().set(0, 1).unwrap();
+
+        Some(part_2(other_program).to_string())
+    }
+}
@@ -0,0 +1,1058 @@
+// https://adventofcode.com/2018/day/20
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use aoc_grid::{reading_order, Coordinate, Grid, Transitions};
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+// code
+
+/*
+
+Based on https://adriann.github.io/rust_parser.html
+
+Grammar:
+
+branches:
+    routes |            (with a skip option; i.e. the entire branch group can be effectively skipped)
+    routes | routes
+    routes | branches
+
+
+branch_group:
+    ( branches )
+
+routes:
+    route
+    branch_group
+    route routes
+    branch_group routes
+
+route:
+    direction route
+    direction
+
+direction:
+    N, S, W, E
+
+start := ^
+end := $
+input:
+    start routes end
+
+*/
+
+// https://en.wikipedia.org/wiki/Lexical_analysis#Tokenization
+#[derive(Debug, PartialEq)]
+enum Tokens {
+    Start,
+    End,
+    OpenDirection(OpenDirections),
+    ParenOpen,
+    ParenClose,
+    BranchOr,
+}
+
+// The tokens of the directions, which are on a single line, along with a scanner at each token
+// to report errors; and one more scanner at the end of the line.
+fn tokenize(input_string: &str) -> Result<(Vec<Tokens>, Vec<Scanner<'_>>), ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+
+    let mut tokens = vec![];
+    let mut positions = vec![];
+
+    while !scanner.is_at_end() {
+        positions.push(scanner);
+
+        let c = scanner.char("one of ^, $, (, ), |, N, S, W or E", |c| {
+            "^$()|NSWE".contains(c)
+        })?;
+
+        let token = match c {
+            '^' => Tokens::Start,
+            '$' => Tokens::End,
+            '(' => Tokens::ParenOpen,
+            ')' => Tokens::ParenClose,
+            '|' => Tokens::BranchOr,
+            _ => Tokens::OpenDirection(OpenDirections::from_char(c)),
+        };
+
+        tokens.push(token);
+    }
+
+    positions.push(scanner);
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok((tokens, positions))
+}
+
+#[derive(Debug, Clone)]
+struct Route(Vec<OpenDirections>);
+
+impl Route {
+    fn to_str(&self) -> String {
+        let directions = &self.0;
+        let directions: Vec<String> = directions.iter().map(|d| d.to_str()).collect();
+
+        directions.join("")
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Branches {
+    // invariant: branches must have at least one option
+
+    // this allows any of the options to be skipped
+    CanSkip(Box<Routes>, Vec<Routes>),
+
+    // only one of the options must be chosen
+    CannotSkip(Box<Routes>, Vec<Routes>),
+}
+
+impl Branches {
+    fn to_str(&self) -> String {
+        match self {
+            Branches::CanSkip(routes, rest_routes) => {
+                let rest_str: Vec<String> =
+                    rest_routes.iter().map(|routes| routes.to_str()).collect();
+
+                if rest_str.is_empty() {
+                    return format!("{}|", routes.to_str());
+                }
+
+                format!("{}|{}|", routes.to_str(), rest_str.join("|"))
+            }
+            Branches::CannotSkip(routes, rest_routes) => {
+                let rest_str: Vec<String> =
+                    rest_routes.iter().map(|routes| routes.to_str()).collect();
+                format!("{}|{}", routes.to_str(), rest_str.join("|"))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BranchGroup(Branches);
+
+impl BranchGroup {
+    fn to_str(&self) -> String {
+        let branches = &self.0;
+        format!("({})", branches.to_str())
+    }
+}
+
+// NOTE: wrap in box because recursive types is illegal in Rust
+// https://stackoverflow.com/questions/25296195/why-are-recursive-struct-types-illegal-in-rust
+#[derive(Debug, Clone)]
+enum Routes {
+    Route(Route, Box<Option<Routes>>),
+    Branch(BranchGroup, Box<Option<Routes>>),
+}
+
+impl Routes {
+    fn to_str(&self) -> String {
+        match self {
+            Routes::Route(route, routes) => {
+                let rest: String = if routes.is_none() {
+                    "".to_string()
+                } else {
+                    routes.clone().unwrap().to_str()
+                };
+                format!("{}{}", route.to_str(), rest)
+            }
+            Routes::Branch(branch_group, routes) => {
+                let rest: String = if routes.is_none() {
+                    "".to_string()
+                } else {
+                    routes.clone().unwrap().to_str()
+                };
+                format!("{}{}", branch_group.to_str(), rest)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Directions(Routes);
+
+impl Directions {
+    #[allow(dead_code)]
+    fn to_str(&self) -> String {
+        format!("^{}$", self.0.to_str())
+    }
+}
+
+// keep track where in the token stream to start reading tokens from
+type TokenPosition = usize;
+
+// after parsing/consuming a portion of the token stream; a result T might be generated.
+// the starting position of the token stream for the next parse is also returned
+//
+// if None was returned, this indicates that parsing wasn't successful,
+// and the token stream was not consumed
+//
+type ParseResult<T> = Option<(T, TokenPosition)>;
+
+fn parse_start(tokens: &[Tokens], start_at: TokenPosition) -> ParseResult<()> {
+    match tokens.get(start_at) {
+        None => None,
+        Some(token) => {
+            if token == &Tokens::Start {
+                return Some(((), start_at + 1));
+            }
+            None
+        }
+    }
+}
+
+fn parse_end(tokens: &[Tokens], start_at: TokenPosition) -> ParseResult<()> {
+    match tokens.get(start_at) {
+        None => None,
+        Some(token) => {
+            if token == &Tokens::End {
+                return Some(((), start_at + 1));
+            }
+            None
+        }
+    }
+}
+
+fn parse_route(tokens: &[Tokens], start_at: TokenPosition) -> ParseResult<Route> {
+    let mut current_position = start_at;
+    let mut route = vec![];
+
+    loop {
+        match tokens.get(current_position) {
+            None => {
+                break;
+            }
+            Some(token) => {
+                if let Tokens::OpenDirection(direction) = token {
+                    route.push(direction.clone());
+                    current_position += 1;
+                    continue;
+                }
+                break;
+            }
+        }
+    }
+
+    if route.is_empty() {
+        // no route was parsed
+        return None;
+    }
+
+    let next_position = start_at + route.len();
+    let result = Route(route);
+
+    Some((result, next_position))
+}
+
+fn parse_branches(tokens: &Vec<Tokens>, start_at: TokenPosition) -> ParseResult<Branches> {
+    let mut current_position = start_at;
+
+    let starting_routes: Box<Routes> = match parse_routes(tokens, current_position) {
+        None => {
+            return None;
+        }
+        Some((routes, next_position)) => {
+            current_position = next_position;
+            Box::new(routes)
+        }
+    };
+
+    // parse branch or: |
+
+    match tokens.get(current_position) {
+        Some(Tokens::BranchOr) => {
+            current_position += 1;
+        }
+        _ => {
+            return None;
+        }
+    }
+
+    // parse for more routes
+
+    let mut can_skip_all = true;
+    let mut rest_routes: Vec<Routes> = vec![];
+
+    loop {
+        match parse_routes(tokens, current_position) {
+            None => {
+                break;
+            }
+            Some((routes, next_position)) => {
+                current_position = next_position;
+                rest_routes.push(routes);
+            }
+        }
+
+        match tokens.get(current_position) {
+            None => {
+                return None;
+            }
+            Some(token) => {
+                match token {
+                    Tokens::BranchOr => {
+                        current_position += 1;
+                        can_skip_all = true;
+                    }
+                    Tokens::ParenClose => {
+                        // this is a peek
+                        can_skip_all = false;
+                        break;
+                    }
+                    _ => {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    // expect next token to be a closing ).
+    // peek on next token
+    match tokens.get(current_position) {
+        Some(Tokens::ParenClose) => {
+            // this is a peek
+        }
+        _ => {
+            return None;
+        }
+    }
+
+    let result = if can_skip_all {
+        Branches::CanSkip(starting_routes, rest_routes)
+    } else {
+        Branches::CannotSkip(starting_routes, rest_routes)
+    };
+
+    Some((result, current_position))
+}
+
+fn parse_branch_group(tokens: &Vec<Tokens>, start_at: TokenPosition) -> ParseResult<BranchGroup> {
+    let mut current_position = start_at;
+
+    // parse opening parentheses
+
+    match tokens.get(current_position) {
+        Some(Tokens::ParenOpen) => {
+            current_position += 1;
+        }
+        _ => {
+            return None;
+        }
+    }
+
+    // parse branches
+    let branches: Branches = match parse_branches(tokens, current_position) {
+        None => {
+            return None;
+        }
+        Some((branches, next_position)) => {
+            current_position = next_position;
+            branches
+        }
+    };
+
+    // parse closing parentheses
+
+    match tokens.get(current_position) {
+        Some(Tokens::ParenClose) => {
+            current_position += 1;
+        }
+        _ => {
+            return None;
+        }
+    }
+
+    let result = (BranchGroup(branches), current_position);
+    Some(result)
+}
+
+fn parse_routes(tokens: &Vec<Tokens>, start_at: TokenPosition) -> ParseResult<Routes> {
+    match parse_branch_group(tokens, start_at) {
+        None => {}
+        Some((branch_group, next_position)) => match parse_routes(tokens, next_position) {
+            None => {
+                let result = Routes::Branch(branch_group, Box::new(None));
+                return Some((result, next_position));
+            }
+            Some((routes, next_position)) => {
+                let result = Routes::Branch(branch_group, Box::new(Some(routes)));
+                return Some((result, next_position));
+            }
+        },
+    }
+
+    match parse_route(tokens, start_at) {
+        None => None,
+        Some((starting_route, next_position)) => match parse_routes(tokens, next_position) {
+            None => {
+                let result = Routes::Route(starting_route, Box::new(None));
+                Some((result, next_position))
+            }
+            Some((routes, next_position)) => {
+                let result = Routes::Route(starting_route, Box::new(Some(routes)));
+                Some((result, next_position))
+            }
+        },
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<Directions, ParseError> {
+    let (tokenized, positions) = tokenize(input_string)?;
+
+    // starting position in the token stream
+    let mut current_position = 0;
+
+    // parse starting token
+
+    match parse_start(&tokenized, current_position) {
+        None => {
+            return Err(positions[current_position].error("\"^\""));
+        }
+        Some((_, next_position)) => {
+            current_position = next_position;
+        }
+    }
+
+    // parse routes
+    let routes: Routes = match parse_routes(&tokenized, current_position) {
+        None => {
+            return Err(positions[current_position].error("a direction or a branch"));
+        }
+        Some((routes, next_position)) => {
+            current_position = next_position;
+            routes
+        }
+    };
+
+    // parse ending token; the routes end where they can no longer be parsed
+    match parse_end(&tokenized, current_position) {
+        None => {
+            return Err(positions[current_position].error("\"$\""));
+        }
+        Some((_, next_position)) => {
+            current_position = next_position;
+        }
+    }
+
+    if current_position < tokenized.len() {
+        return Err(positions[current_position].error("end of line"));
+    }
+
+    Ok(Directions(routes))
+}
+
+pub type Distance = usize;
+
+#[derive(Debug, PartialEq, Clone)]
+enum OpenDirections {
+    North,
+    South,
+    West,
+    East,
+}
+
+impl OpenDirections {
+    fn to_str(&self) -> String {
+        let result = match self {
+            OpenDirections::North => "N",
+            OpenDirections::South => "S",
+            OpenDirections::West => "W",
+            OpenDirections::East => "E",
+        };
+
+        result.to_string()
+    }
+
+    fn from_char(d: char) -> Self {
+        match d {
+            'N' => OpenDirections::North,
+            'S' => OpenDirections::South,
+            'W' => OpenDirections::West,
+            'E' => OpenDirections::East,
+            _ => {
+                unreachable!();
+            }
+        }
+    }
+}
+
+// the room that the routes start from
+const START: Coordinate = (0, 0);
+
+// The rooms of the facility, and the doors between them; where a door connects a room to the
+// room next to it, to the north, south, west or east.
+#[derive(Debug, Clone, Default)]
+pub struct Facility {
+    doors: HashMap<Coordinate, BTreeSet<Coordinate>>,
+}
+
+impl Facility {
+    pub fn new() -> Self {
+        Facility {
+            doors: HashMap::new(),
+        }
+    }
+
+    pub fn add_room(&mut self, room: Coordinate) {
+        self.doors.entry(room).or_default();
+    }
+
+    pub fn add_door(&mut self, room: Coordinate, other_room: Coordinate) {
+        let (x, y) = room;
+        let (other_x, other_y) = other_room;
+        assert!((x - other_x).abs() + (y - other_y).abs() == 1);
+
+        self.doors.entry(room).or_default().insert(other_room);
+        self.doors.entry(other_room).or_default().insert(room);
+    }
+
+    pub fn has_door(&self, room: Coordinate, other_room: Coordinate) -> bool {
+        match self.doors.get(&room) {
+            None => false,
+            Some(doors) => doors.contains(&other_room),
+        }
+    }
+
+    // the rooms in reading order
+    pub fn rooms(&self) -> Vec<Coordinate> {
+        let mut rooms: Vec<Coordinate> = self.doors.keys().copied().collect();
+        rooms.sort_by(reading_order);
+        rooms
+    }
+
+    pub fn num_of_rooms(&self) -> usize {
+        self.doors.len()
+    }
+
+    pub fn num_of_doors(&self) -> usize {
+        self.doors.values().map(|doors| doors.len()).sum::<usize>() / 2
+    }
+
+    // the rooms on the other side of the doors of the room
+    pub fn neighbours(&self, room: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        self.doors.get(&room).into_iter().flatten().copied()
+    }
+
+    // the fewest number of doors to pass through to reach every room that can be reached
+    pub fn distances_from(&self, start: Coordinate) -> HashMap<Coordinate, Distance> {
+        let mut distances: HashMap<Coordinate, Distance> = HashMap::new();
+        let mut queue: VecDeque<Coordinate> = VecDeque::new();
+
+        if !self.doors.contains_key(&start) {
+            return distances;
+        }
+
+        distances.insert(start, 0);
+        queue.push_back(start);
+
+        while let Some(room) = queue.pop_front() {
+            let distance = distances[&room];
+
+            for neighbour in self.neighbours(room) {
+                if distances.contains_key(&neighbour) {
+                    continue;
+                }
+
+                distances.insert(neighbour, distance + 1);
+                queue.push_back(neighbour);
+            }
+        }
+
+        distances
+    }
+
+    // the bounds of the rooms; the top left and the bottom right rooms
+    fn bounds(&self) -> (Coordinate, Coordinate) {
+        let xs = || self.doors.keys().map(|(x, _y)| *x);
+        let ys = || self.doors.keys().map(|(_x, y)| *y);
+
+        (
+            (xs().min().unwrap_or(0), ys().min().unwrap_or(0)),
+            (xs().max().unwrap_or(0), ys().max().unwrap_or(0)),
+        )
+    }
+
+    // e.g.
+    //   #####
+    //   #.|.#
+    //   #-###
+    //   #X#.#
+    //   #####
+    // where the start is an X, a door between rooms side by side is a |, and a door between rooms
+    // above and below each other is a -
+    pub fn to_ascii(&self) -> String {
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds();
+
+        let width = 2 * (max_x - min_x + 1) as usize + 1;
+        let height = 2 * (max_y - min_y + 1) as usize + 1;
+        let mut grid: Grid<char> = Grid::new(width, height, '#');
+
+        let to_grid = |(x, y): Coordinate| (2 * (x - min_x) + 1, 2 * (y - min_y) + 1);
+
+        for room in self.doors.keys() {
+            let (x, y) = to_grid(*room);
+            let cell = if *room == START { 'X' } else { '.' };
+            grid.set((x, y), cell);
+
+            if self.has_door(*room, room.east()) {
+                grid.set((x + 1, y), '|');
+            }
+
+            if self.has_door(*room, room.south()) {
+                grid.set((x, y + 1), '-');
+            }
+        }
+
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        rows.join("\n")
+    }
+
+    // A Graphviz graph of the rooms, which are labelled with their position and the fewest
+    // number of doors to pass through to reach them; e.g. neato -n -Tsvg facility.dot
+    pub fn to_dot(&self) -> String {
+        let distances = self.distances_from(START);
+        let name = |(x, y): Coordinate| format!("\"{},{}\"", x, y);
+
+        let mut lines: Vec<String> = vec![
+            "graph facility {".to_string(),
+            "    node [shape=box];".to_string(),
+        ];
+
+        for room in self.rooms() {
+            let (x, y) = room;
+            let distance = match distances.get(&room) {
+                None => "unreachable".to_string(),
+                Some(distance) => distance.to_string(),
+            };
+            let style = if room == START { ", style=bold" } else { "" };
+
+            // y is flipped, since Graphviz places larger y higher up
+            lines.push(format!(
+                "    {} [label=\"{},{}\\n{}\", pos=\"{},{}\"{}];",
+                name(room),
+                x,
+                y,
+                distance,
+                x * 72,
+                -y * 72,
+                style
+            ));
+        }
+
+        for room in self.rooms() {
+            for neighbour in self.neighbours(room) {
+                // every door once
+                if reading_order(&room, &neighbour) == std::cmp::Ordering::Less {
+                    lines.push(format!("    {} -- {};", name(room), name(neighbour)));
+                }
+            }
+        }
+
+        lines.push("}".to_string());
+        lines.join("\n")
+    }
+}
+
+// Takes the routes of the directions, to find the rooms and doors of the facility.
+struct Map {
+    facility: Facility,
+}
+
+// invariant: the routes will take you through every door in the facility at least once
+
+impl Map {
+    fn new() -> Self {
+        Map {
+            facility: Facility::new(),
+        }
+    }
+
+    fn distance_to_farthest_room(&self) -> Distance {
+        *self.facility.distances_from(START).values().max().unwrap()
+    }
+
+    fn visit_room(
+        &mut self,
+        direction: OpenDirections,
+        current_position: Coordinate,
+    ) -> Coordinate {
+        // generate new position
+
+        let new_position = match direction {
+            OpenDirections::North => current_position.north(),
+            OpenDirections::South => current_position.south(),
+            OpenDirections::West => current_position.west(),
+            OpenDirections::East => current_position.east(),
+        };
+
+        self.facility.add_door(current_position, new_position);
+
+        new_position
+    }
+
+    fn parse_route(&mut self, route: Route, current_position: Coordinate) -> Coordinate {
+        let Route(directions) = route;
+
+        if directions.is_empty() {
+            return current_position;
+        }
+
+        // generate new position
+
+        let mut new_position = current_position;
+
+        for direction in directions {
+            new_position = self.visit_room(direction, new_position);
+        }
+
+        new_position
+    }
+
+    fn generate_positions(
+        &mut self,
+        choices: Vec<Routes>,
+        current_position: Coordinate,
+    ) -> HashSet<Coordinate> {
+        // generate new positions for every choice taken
+
+        let new_positions: HashSet<Coordinate> =
+            choices
+                .into_iter()
+                .fold(HashSet::new(), |mut acc, routes_choice| {
+                    let new_positions = self.parse_routes(routes_choice, current_position);
+                    acc.extend(new_positions);
+                    acc
+                });
+
+        new_positions
+    }
+
+    fn parse_branch_group(
+        &mut self,
+        branch_group: BranchGroup,
+        more_routes: Option<Routes>,
+        current_position: Coordinate,
+    ) -> HashSet<Coordinate> {
+        let BranchGroup(branches) = branch_group;
+
+        let new_positions: HashSet<Coordinate> = match branches {
+            Branches::CanSkip(first_choice, other_choices) => {
+                let mut choices = vec![*first_choice];
+                choices.extend(other_choices);
+                let mut new_positions = self.generate_positions(choices, current_position);
+
+                // all of these choices could be skipped, so current_position can be a position to start from
+                new_positions.insert(current_position);
+
+                new_positions
+            }
+            Branches::CannotSkip(first_choice, other_choices) => {
+                let mut choices = vec![*first_choice];
+                choices.extend(other_choices);
+
+                self.generate_positions(choices, current_position)
+            }
+        };
+
+        // for each new positions, continue taking more_routes
+
+        match more_routes {
+            None => new_positions,
+            Some(more_routes) => {
+                new_positions
+                    .into_iter()
+                    .fold(HashSet::new(), |mut acc, position: Coordinate| {
+                        let new_positions = self.parse_routes(more_routes.clone(), position);
+                        acc.extend(new_positions);
+                        acc
+                    })
+            }
+        }
+    }
+
+    fn parse_routes(
+        &mut self,
+        routes: Routes,
+        current_position: Coordinate,
+    ) -> HashSet<Coordinate> {
+        match routes {
+            Routes::Route(route, more_routes) => {
+                let new_position = self.parse_route(route, current_position);
+                match *more_routes {
+                    None => {
+                        let mut set = HashSet::new();
+                        set.insert(new_position);
+                        set
+                    }
+                    Some(more_routes) => self.parse_routes(more_routes, new_position),
+                }
+            }
+            Routes::Branch(branch_group, more_routes) => {
+                self.parse_branch_group(branch_group, *more_routes, current_position)
+            }
+        }
+    }
+
+    fn parse_directions(&mut self, directions: Directions) {
+        let Directions(routes) = directions;
+
+        self.facility.add_room(START);
+
+        self.parse_routes(routes, START);
+    }
+}
+
+fn generate_map(input_string: &str) -> Result<Map, ParseError> {
+    let directions = parse_input(input_string)?;
+    let mut map = Map::new();
+    map.parse_directions(directions);
+    Ok(map)
+}
+
+// the rooms and doors that the directions of the input go through, starting from room 0,0
+pub fn generate_facility(input_string: &str) -> Result<Facility, ParseError> {
+    Ok(generate_map(input_string)?.facility)
+}
+
+fn part_1(input_string: &str) -> Option<Distance> {
+    Some(generate_map(input_string).ok()?.distance_to_farthest_room())
+}
+
+fn part_2(input_string: &str) -> Option<usize> {
+    let facility = generate_facility(input_string).ok()?;
+
+    // find number of rooms that are reachable by at least 1000 doors
+    let num_of_rooms = facility
+        .distances_from(START)
+        .values()
+        .filter(|x| *x >= &1000)
+        .count();
+
+    Some(num_of_rooms)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let examples: Vec<(&str, Distance)> = vec![
+            ("^WNE$", 3),
+            ("^ENWWW(NEEE|SSE(EE|N))$", 10),
+            ("^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$", 18),
+            ("^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$", 23),
+            (
+                "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$",
+                31,
+            ),
+        ];
+
+        for (input_string, distance) in examples {
+            let directions = parse_input(input_string).unwrap();
+            assert_eq!(directions.to_str(), input_string);
+
+            let mut map = Map::new();
+            map.parse_directions(directions);
+
+            assert_eq!(map.distance_to_farthest_room(), distance);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let errors = vec![
+            (
+                "^ENX$",
+                "line 1, column 4: expected one of ^, $, (, ), |, N, S, W or E, found \"X\"",
+            ),
+            ("ENWS$", "line 1, column 1: expected \"^\", found \"ENWS\""),
+            (
+                "^$",
+                "line 1, column 2: expected a direction or a branch, found \"$\"",
+            ),
+            ("^EN(W|S$", "line 1, column 4: expected \"$\", found \"(\""),
+            ("^EN", "line 1, column 4: expected \"$\", found end of line"),
+            (
+                "^EN$N",
+                "line 1, column 5: expected end of line, found \"N\"",
+            ),
+            (
+                "^EN$\n^S$",
+                "line 2, column 1: expected end of input, found \"^\"",
+            ),
+        ];
+
+        for (input_string, expected) in errors {
+            let error = parse_input(input_string).err().unwrap();
+            assert_eq!(error.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_to_ascii() {
+        let examples: Vec<(&str, &str)> = vec![
+            (
+                "^ENWWW(NEEE|SSE(EE|N))$",
+                r"#########
+#.|.|.|.#
+#-#######
+#.|.|.|.#
+#-#####-#
+#.#.#X|.#
+#-#-#####
+#.|.|.|.#
+#########",
+            ),
+            (
+                "^ENNWSWW(NEWS|)SSSEEN(WNSE|)EE(SWEN|)NNN$",
+                r"###########
+#.|.#.|.#.#
+#-###-#-#-#
+#.|.|.#.#.#
+#-#####-#-#
+#.#.#X|.#.#
+#-#-#####-#
+#.#.|.|.|.#
+#-###-###-#
+#.|.|.#.|.#
+###########",
+            ),
+            (
+                "^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$",
+                r"#############
+#.|.|.|.|.|.#
+#-#####-###-#
+#.#.|.#.#.#.#
+#-#-###-#-#-#
+#.#.#.|.#.|.#
+#-#-#-#####-#
+#.#.#.#X|.#.#
+#-#-#-###-#-#
+#.|.#.|.#.#.#
+###-#-###-#-#
+#.|.#.|.|.#.#
+#############",
+            ),
+            (
+                "^WSSEESWWWNW(S|NENNEEEENN(ESSSSW(NWSW|SSEN)|WSWWN(E|WWS(E|SS))))$",
+                r"###############
+#.|.|.|.#.|.|.#
+#-###-###-#-#-#
+#.|.#.|.|.#.#.#
+#-#########-#-#
+#.#.|.|.|.|.#.#
+#-#-#########-#
+#.#.#.|X#.|.#.#
+###-#-###-#-#-#
+#.|.#.#.|.#.|.#
+#-###-#####-###
+#.|.#.|.|.#.#.#
+#-#-#####-#-#-#
+#.#.|.|.|.#.|.#
+###############",
+            ),
+        ];
+
+        for (input_string, expected) in examples {
+            assert_eq!(
+                generate_facility(input_string).unwrap().to_ascii(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_facility() {
+        let facility = generate_facility("^ENWWW(NEEE|SSE(EE|N))$").unwrap();
+
+        assert_eq!(facility.num_of_rooms(), 16);
+        assert_eq!(facility.num_of_doors(), 15);
+        assert_eq!(facility.rooms().first(), Some(&(-2, -2)));
+        assert!(facility.has_door((0, 0), (1, 0)));
+        assert!(!facility.has_door((0, 0), (-1, 0)));
+
+        let neighbours: Vec<Coordinate> = facility.neighbours((-2, 0)).collect();
+        assert_eq!(neighbours, vec![(-2, -1), (-2, 1)]);
+
+        let distances = facility.distances_from(START);
+        assert_eq!(distances.len(), 16);
+        assert_eq!(distances.values().max(), Some(&10));
+        assert_eq!(distances[&(1, -2)], 9);
+        assert_eq!(distances[&(1, 1)], 10);
+
+        // the last door leads back to the start, which is a shorter way to the rooms before it
+        let input_string = "^ESWWNE$";
+        let facility = generate_facility(input_string).unwrap();
+        let distances = facility.distances_from(START);
+        assert_eq!(distances[&(-1, 0)], 1);
+        assert_eq!(distances[&(-1, 1)], 2);
+        assert_eq!(part_1(input_string), Some(3));
+
+        let input_string = include_str!("input.txt");
+        let facility = generate_facility(input_string).unwrap();
+        let distances = facility.distances_from(START);
+        assert_eq!(distances.len(), facility.num_of_rooms());
+        assert_eq!(
+            facility.to_ascii().matches('.').count() + 1,
+            distances.len()
+        );
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let facility = generate_facility("^EN(W|)$").unwrap();
+
+        assert_eq!(
+            facility.to_dot(),
+            r#"graph facility {
+    node [shape=box];
+    "0,-1" [label="0,-1\n3", pos="0,72"];
+    "1,-1" [label="1,-1\n2", pos="72,72"];
+    "0,0" [label="0,0\n0", pos="0,0", style=bold];
+    "1,0" [label="1,0\n1", pos="72,0"];
+    "0,-1" -- "1,-1";
+    "1,-1" -- "1,0";
+    "0,0" -- "1,0";
+}"#
+        );
+    }
+}
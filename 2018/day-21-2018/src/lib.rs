@@ -0,0 +1,172 @@
+// https://adventofcode.com/2018/day/21
+
+// imports
+
+use aoc_core::{ParseError, Solution};
+use elfcode::{Opcode, OptimisedProgram, Program, Status, Value};
+use std::collections::HashSet;
+
+// code
+
+// the device of day 21 has six registers
+const NUM_OF_REGISTERS: usize = 6;
+
+fn parse_input(input_string: &str) -> Result<Program, ParseError> {
+    Program::parse(input_string, NUM_OF_REGISTERS)
+}
+
+// the program halts once register 0 equals the value it is compared with, e.g. eqrr 5 0 3;
+// returns the position of the comparison and the register holding that value
+fn find_halting_check(program: &Program) -> Option<(Value, Value)> {
+    program
+        .instructions()
+        .iter()
+        .enumerate()
+        .find_map(|(position, instruction)| {
+            if instruction.opcode != Opcode::Eqrr {
+                return None;
+            }
+
+            let register = match (instruction.a, instruction.b) {
+                (0, 0) => return None,
+                (register, 0) | (0, register) => register,
+                _ => return None,
+            };
+
+            Some((position as Value, register))
+        })
+}
+
+// the values compared with register 0, in the order the program first compares them,
+// until the program repeats itself
+//
+// see the pseudo-code printed by:
+// cargo run -p elfcode -- decompile 2018/day-21-2018/src/input.txt
+//
+// loop A runs natively, as the optimiser recognises it as a counting loop
+fn find_halting_values(program: Program) -> Option<Vec<Value>> {
+    let (check_position, register) = find_halting_check(&program)?;
+
+    let mut program = OptimisedProgram::new(program);
+    let mut seen: HashSet<Value> = HashSet::new();
+    let mut halting_values = vec![];
+
+    loop {
+        if program.program().instruction_pointer() == check_position {
+            let value = program.program().registers().get(register).ok()?;
+
+            if !seen.insert(value) {
+                return Some(halting_values);
+            }
+
+            halting_values.push(value);
+        }
+
+        if program.execute_instruction().ok()? == Status::Halted {
+            return Some(halting_values);
+        }
+    }
+}
+
+// the value of register 0 that halts the program after the fewest instructions
+fn part_1(program: Program) -> Option<Value> {
+    find_halting_values(program)?.first().copied()
+}
+
+// the value of register 0 that halts the program after the most instructions
+fn part_2(program: Program) -> Option<Value> {
+    find_halting_values(program)?.last().copied()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(parse_input(input_string).ok()?).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(parse_input(input_string).ok()?).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompile() {
+        let program = parse_input(include_str!("input.txt")).unwrap();
+
+        let expected = r#"
+r5 = 123
+do {
+    r5 = (r5 & 456) == 72
+} while r5 == 0
+r5 = 0
+loop {
+    r4 = r5 | 65536
+    r5 = 13159625
+    loop {
+        r5 = (((r5 + (r4 & 255)) & 16777215) * 65899) & 16777215
+        if 256 > r4 {
+            break
+        }
+        r3 = 0
+        while ((r3 + 1) * 256) <= r4 {
+            r3 += 1
+        }
+        r4 = r3
+    }
+    if r5 == r0 {
+        halt
+    }
+}
+"#;
+
+        assert_eq!(
+            elfcode::decompile(&program).to_string(),
+            expected.trim_start()
+        );
+    }
+
+    #[test]
+    fn test_find_halting_check() {
+        let program = parse_input(include_str!("input.txt")).unwrap();
+        assert_eq!(find_halting_check(&program), Some((28, 5)));
+
+        let program = parse_input("#ip 1\nseti 5 0 1").unwrap();
+        assert_eq!(find_halting_check(&program), None);
+    }
+
+    #[test]
+    fn test_halting_values() {
+        let program = parse_input(include_str!("input.txt")).unwrap();
+        let halting_values = find_halting_values(program.fork()).unwrap();
+
+        // each value halts the program, after more instructions than the value before it
+        let mut num_of_instructions = 0;
+        for reg_0 in [halting_values[0], halting_values[1], halting_values[2]] {
+            let mut program = OptimisedProgram::new(program.fork());
+            program.program_mut().registers_mut().set(0, reg_0).unwrap();
+            program.run().unwrap();
+
+            let executed = program.program().num_of_instructions_executed();
+            assert!(executed > num_of_instructions);
+            num_of_instructions = executed;
+        }
+    }
+}
@@ -0,0 +1,604 @@
+// https://adventofcode.com/2018/day/23
+
+// imports
+
+extern crate combine;
+
+use crate::combine::EasyParser;
+use aoc_core::{scan_lines, ParseError, Part, Scanner, Solution, Variant};
+use combine::parser::char::{char, digit, letter, spaces};
+use combine::parser::token::token;
+use combine::stream::easy;
+use combine::stream::position::{self, SourcePosition};
+use combine::{between, choice, eof, many1, Parser};
+use std::cmp;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
+
+// code
+
+// adapted from day 6
+// https://math.stackexchange.com/a/139604/10247
+type Distance = i32;
+fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
+    let (a, b, e) = start;
+    let (c, d, f) = end;
+
+    (a - c).abs() + (b - d).abs() + (e - f).abs()
+}
+
+type Radius = i32;
+type Coordinate = (i32, i32, i32);
+
+#[derive(Debug, Clone)]
+struct NanoBot {
+    position: Coordinate,
+    radius: Radius,
+}
+
+impl NanoBot {
+    fn new(position: Coordinate, radius: Radius) -> Self {
+        NanoBot { position, radius }
+    }
+
+    fn is_in_range(&self, position: Coordinate) -> bool {
+        get_manhattan_distance(self.position, position) <= self.radius
+    }
+
+    fn is_in_range_of_cube(&self, cube: &Cube) -> bool {
+        cube.distance_to(to_wide(self.position)) <= self.radius as i64
+    }
+}
+
+// coordinates of cubes are wider, since cubes can reach beyond the range of i32
+type WideCoordinate = (i64, i64, i64);
+
+fn to_wide((x, y, z): Coordinate) -> WideCoordinate {
+    (x as i64, y as i64, z as i64)
+}
+
+// the distance from a value to the closest value within the range from min to max
+fn distance_to_range(value: i64, min: i64, max: i64) -> i64 {
+    cmp::max(0, cmp::max(min - value, value - max))
+}
+
+#[derive(Debug, Clone)]
+struct Cube {
+    // the corner with the lowest coordinates
+    min: WideCoordinate,
+    // the length of the sides, which is a power of two
+    size: i64,
+}
+
+impl Cube {
+    // the smallest cube that contains every position in range of any nanobot
+    fn bounding(nanobots: &[NanoBot]) -> Option<Cube> {
+        if nanobots.is_empty() {
+            return None;
+        }
+
+        let mut min = (i64::MAX, i64::MAX, i64::MAX);
+        let mut max = (i64::MIN, i64::MIN, i64::MIN);
+
+        for bot in nanobots {
+            let (x, y, z) = to_wide(bot.position);
+            let radius = bot.radius as i64;
+
+            min = (
+                cmp::min(min.0, x - radius),
+                cmp::min(min.1, y - radius),
+                cmp::min(min.2, z - radius),
+            );
+            max = (
+                cmp::max(max.0, x + radius),
+                cmp::max(max.1, y + radius),
+                cmp::max(max.2, z + radius),
+            );
+        }
+
+        let extent = cmp::max(max.0 - min.0, cmp::max(max.1 - min.1, max.2 - min.2)) + 1;
+
+        let mut size = 1;
+        while size < extent {
+            size *= 2;
+        }
+
+        Some(Cube { min, size })
+    }
+
+    fn max(&self) -> WideCoordinate {
+        let (x, y, z) = self.min;
+        let last = self.size - 1;
+        (x + last, y + last, z + last)
+    }
+
+    // the manhattan distance from the position to the closest position in the cube
+    fn distance_to(&self, position: WideCoordinate) -> i64 {
+        let (x, y, z) = position;
+        let (min_x, min_y, min_z) = self.min;
+        let (max_x, max_y, max_z) = self.max();
+
+        distance_to_range(x, min_x, max_x)
+            + distance_to_range(y, min_y, max_y)
+            + distance_to_range(z, min_z, max_z)
+    }
+
+    fn split(&self) -> impl Iterator<Item = Cube> {
+        let (x, y, z) = self.min;
+        let size = self.size / 2;
+
+        (0..8).map(move |octant| Cube {
+            min: (
+                x + (octant & 1) * size,
+                y + ((octant >> 1) & 1) * size,
+                z + ((octant >> 2) & 1) * size,
+            ),
+            size,
+        })
+    }
+}
+
+#[derive(Debug)]
+struct SearchCube {
+    // the most nanobots that any position in the cube can be in range of
+    num_in_range: usize,
+    distance_to_origin: i64,
+    cube: Cube,
+}
+
+impl SearchCube {
+    fn new(cube: Cube, nanobots: &[NanoBot]) -> Self {
+        SearchCube {
+            num_in_range: nanobots
+                .iter()
+                .filter(|bot| bot.is_in_range_of_cube(&cube))
+                .count(),
+            distance_to_origin: cube.distance_to((0, 0, 0)),
+            cube,
+        }
+    }
+
+    fn key(&self) -> (usize, cmp::Reverse<i64>, cmp::Reverse<i64>) {
+        (
+            self.num_in_range,
+            cmp::Reverse(self.distance_to_origin),
+            cmp::Reverse(self.cube.size),
+        )
+    }
+}
+
+impl PartialEq for SearchCube {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for SearchCube {}
+
+impl Ord for SearchCube {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // the binary heap is a max-heap; the cubes in range of more nanobots come first, and then
+        // the cubes closer to the origin
+        self.key().cmp(&other.key())
+    }
+}
+
+impl PartialOrd for SearchCube {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct BestPosition {
+    position: WideCoordinate,
+    num_in_range: usize,
+}
+
+impl BestPosition {
+    fn distance_to_origin(&self) -> i64 {
+        let (x, y, z) = self.position;
+        x.abs() + y.abs() + z.abs()
+    }
+}
+
+fn describe(info: &easy::Info<char, &str>) -> String {
+    let description = match info {
+        easy::Info::Token(token) => format!("{:?}", token.to_string()),
+        easy::Info::Range(range) => format!("{:?}", range),
+        easy::Info::Owned(message) => message.clone(),
+        easy::Info::Static(message) => message.to_string(),
+    };
+
+    if description == "end of input" {
+        return "end of line".to_string();
+    }
+
+    description
+}
+
+// the position of the errors is relative to the start of the scanned input
+fn to_parse_error(
+    mut scanner: Scanner,
+    errors: easy::Errors<char, &str, SourcePosition>,
+) -> ParseError {
+    let skipped: String = scanner
+        .rest()
+        .chars()
+        .take(errors.position.column as usize - 1)
+        .collect();
+    scanner.eat(&skipped);
+
+    let mut expected: Vec<String> = vec![];
+    let mut found = None;
+
+    for error in errors.errors.iter() {
+        match error {
+            easy::Error::Expected(info) | easy::Error::Message(info) => {
+                let description = describe(info);
+                // optional whitespace is not worth mentioning
+                if !description.starts_with("whitespace") && !expected.contains(&description) {
+                    expected.push(description);
+                }
+            }
+            easy::Error::Unexpected(info) => found = Some(describe(info)),
+            easy::Error::Other(error) => expected.push(error.to_string()),
+        }
+    }
+
+    let mut error = scanner.error(expected.join(" or "));
+    if let Some(found) = found {
+        error.found = found;
+    }
+
+    error
+}
+
+fn parse_nanobot(scanner: Scanner) -> Result<NanoBot, ParseError> {
+    let integer = many1(digit()).and_then(|string: String| {
+        string
+            .parse::<i32>()
+            .map_err(|_| easy::Error::Expected(easy::Info::Static("an integer that fits in i32")))
+    });
+
+    let negative_integer =
+        (char('-'), integer.clone()).map(|(_, parsed_int): (_, i32)| -> i32 { -parsed_int });
+
+    let parse_integer = || choice((negative_integer.clone(), integer.clone()));
+
+    let separator = || spaces().skip(char(','));
+
+    let position_start = many1(letter()).and_then(|word: String| {
+        if word == "pos" {
+            Ok(word)
+        } else {
+            Err(easy::Error::Expected(easy::Info::Static("\"pos\"")))
+        }
+    });
+
+    let coord_list = between(
+        token('<'),
+        token('>'),
+        (
+            parse_integer(),
+            separator(),
+            parse_integer(),
+            separator(),
+            parse_integer(),
+        ),
+    );
+
+    let parse_position = (position_start, char('='), coord_list)
+        .map(|(_, _, (x, _, y, _, z)): (_, _, (i32, _, i32, _, i32))| -> Coordinate { (x, y, z) });
+
+    let parse_radius = (char('r'), char('='), integer).map(|(_, _, radius): (_, _, Radius)| radius);
+
+    let mut parse_nanobot = (
+        parse_position,
+        char(',').skip(spaces()),
+        parse_radius,
+        spaces(),
+        eof(),
+    )
+        .map(
+            |(position, _, radius, _, _): (Coordinate, _, Radius, _, _)| -> NanoBot {
+                NanoBot::new(position, radius)
+            },
+        );
+
+    match parse_nanobot.easy_parse(position::Stream::new(scanner.rest())) {
+        Ok((nanobot, _remaining_input)) => Ok(nanobot),
+        Err(errors) => Err(to_parse_error(scanner, errors)),
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<NanoBot>, ParseError> {
+    scan_lines(input_string).map(parse_nanobot).collect()
+}
+
+fn part_1(nanobots: &[NanoBot]) -> usize {
+    let strongest_nanobot: NanoBot = nanobots.iter().max_by_key(|b| b.radius).unwrap().clone();
+
+    let num_in_range: Vec<NanoBot> = nanobots
+        .iter()
+        .filter(|bot| strongest_nanobot.is_in_range(bot.position))
+        .cloned()
+        .collect();
+
+    num_in_range.len()
+}
+
+// The position in range of the most nanobots, and the closest one to the origin of those.
+//
+// Space is split into cubes, starting from a cube around every nanobot's range. A cube can be
+// in range of no more nanobots than there are nanobots in range of any of its positions, so
+// the cubes are searched in the order of that bound, and then by their distance to the origin.
+// The first cube of a single position that is taken off the queue has no better position
+// left in any other cube.
+fn find_best_position(nanobots: &[NanoBot]) -> Option<BestPosition> {
+    let mut queue: BinaryHeap<SearchCube> = BinaryHeap::new();
+    queue.push(SearchCube::new(Cube::bounding(nanobots)?, nanobots));
+
+    while let Some(search_cube) = queue.pop() {
+        if search_cube.cube.size == 1 {
+            return Some(BestPosition {
+                position: search_cube.cube.min,
+                num_in_range: search_cube.num_in_range,
+            });
+        }
+
+        for cube in search_cube.cube.split() {
+            queue.push(SearchCube::new(cube, nanobots));
+        }
+    }
+
+    None
+}
+
+fn part_2(nanobots: &[NanoBot]) -> Option<i64> {
+    Some(find_best_position(nanobots)?.distance_to_origin())
+}
+
+// https://old.reddit.com/r/adventofcode/comments/a8s17l/2018_day_23_solutions/ecdqzdg/
+//
+// This only considers the distances of the nanobots' ranges to the origin, and not whether
+// the ranges actually overlap; so it can be wrong.
+
+// visualization
+//       <===A===> <==B==>
+// <==C==>             <==D==>
+//     <======E======>
+// 1111223222222221222122211110 :: Bot count per x-coordinate
+//       ^                      :: Point of maximum intersection
+//
+// https://old.reddit.com/r/adventofcode/comments/a8s17l/2018_day_23_solutions/ecez07o/
+fn part_2_sweep(nanobots: &[NanoBot]) -> i32 {
+    let queue: Vec<(i32, i32)> = nanobots
+        .iter()
+        .map(|bot| {
+            let distance = get_manhattan_distance(bot.position, (0, 0, 0));
+
+            let segments = vec![
+                (cmp::max(0, distance - bot.radius), 1),
+                (distance + bot.radius + 1, -1),
+            ];
+
+            segments
+        })
+        .flat_map(|s| s.into_iter())
+        .collect();
+
+    let mut btree = BTreeMap::new();
+    btree.extend(queue);
+
+    let mut count = 0;
+    let mut max_count = 0;
+    let mut result = 0;
+
+    for (distance, e) in btree {
+        count += e;
+
+        if count > max_count {
+            result = distance;
+            max_count = count;
+        }
+    }
+
+    result
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(&parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(&parse_input(input_string).ok()?)?.to_string())
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![Variant {
+            name: "sweep",
+            part: Part::Two,
+            solve: |input_string| Some(part_2_sweep(&parse_input(input_string).ok()?).to_string()),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_part_1() {
+        let input = r###"
+pos=<0,0,0>, r=4
+pos=<1,0,0>, r=1
+pos=<4,0,0>, r=3
+pos=<0,2,0>, r=1
+pos=<0,5,0>, r=3
+pos=<0,0,3>, r=1
+pos=<1,1,1>, r=1
+pos=<1,1,2>, r=1
+pos=<1,3,1>, r=1
+        "###;
+
+        assert_eq!(part_1(&parse_input(input).unwrap()), 7);
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_1(&parse_input(input_string).unwrap()), 737);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = r###"
+pos=<10,12,12>, r=2
+pos=<12,14,12>, r=2
+pos=<16,12,12>, r=4
+pos=<14,14,14>, r=6
+pos=<50,50,50>, r=200
+pos=<10,10,10>, r=5
+        "###;
+
+        assert_eq!(
+            find_best_position(&parse_input(input).unwrap()),
+            Some(BestPosition {
+                position: (12, 12, 12),
+                num_in_range: 5
+            })
+        );
+
+        // the ranges of the first two nanobots are as far from the origin, but do not overlap
+        let input = r###"
+pos=<100,0,0>, r=2
+pos=<-100,0,0>, r=1
+pos=<0,0,150>, r=1
+pos=<0,0,152>, r=1
+        "###;
+
+        let nanobots = parse_input(input).unwrap();
+        assert_eq!(part_2_sweep(&nanobots), 99);
+        assert_eq!(part_2(&nanobots), Some(151));
+
+        assert_eq!(find_best_position(&[]), None);
+
+        let input_string = include_str!("input.txt");
+        let nanobots = parse_input(input_string).unwrap();
+
+        let best_position = find_best_position(&nanobots).unwrap();
+        assert_eq!(best_position.distance_to_origin(), 123356173);
+        assert_eq!(
+            part_2_sweep(&nanobots) as i64,
+            best_position.distance_to_origin()
+        );
+
+        let (x, y, z) = best_position.position;
+        let position = (x as i32, y as i32, z as i32);
+        let num_in_range = nanobots
+            .iter()
+            .filter(|bot| bot.is_in_range(position))
+            .count();
+        assert_eq!(best_position.num_in_range, num_in_range);
+    }
+
+    #[test]
+    fn test_best_position_against_every_position() {
+        let mut rng = StdRng::seed_from_u64(23);
+
+        for _case in 0..200 {
+            let nanobots: Vec<NanoBot> = (0..rng.gen_range(1..10))
+                .map(|_| {
+                    let position = (
+                        rng.gen_range(-8..=8),
+                        rng.gen_range(-8..=8),
+                        rng.gen_range(-8..=8),
+                    );
+                    NanoBot::new(position, rng.gen_range(0..=6))
+                })
+                .collect();
+
+            // every position in range of a nanobot is within 14 of the origin on every axis
+            let mut expected: Option<(usize, Distance)> = None;
+            for x in -14..=14 {
+                for y in -14..=14 {
+                    for z in -14..=14 {
+                        let position = (x, y, z);
+                        let num_in_range = nanobots
+                            .iter()
+                            .filter(|bot| bot.is_in_range(position))
+                            .count();
+                        let distance = get_manhattan_distance(position, (0, 0, 0));
+
+                        let candidate = (num_in_range, distance);
+                        expected = match expected {
+                            Some(best)
+                                if (best.0, cmp::Reverse(best.1))
+                                    >= (candidate.0, cmp::Reverse(candidate.1)) =>
+                            {
+                                Some(best)
+                            }
+                            _ => Some(candidate),
+                        };
+                    }
+                }
+            }
+
+            let best_position = find_best_position(&nanobots).unwrap();
+            let (x, y, z) = best_position.position;
+            let position = (x as i32, y as i32, z as i32);
+
+            assert_eq!(
+                (
+                    best_position.num_in_range,
+                    best_position.distance_to_origin() as Distance
+                ),
+                expected.unwrap()
+            );
+            assert_eq!(
+                nanobots
+                    .iter()
+                    .filter(|bot| bot.is_in_range(position))
+                    .count(),
+                best_position.num_in_range
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("pos=<0,0,0>, r=4\n  pos=<1,x,0>, r=1").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 10: expected \"-\" or digit, found \"x\""
+        );
+
+        let error = parse_input("pos=<0,0>, r=4").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+        assert_eq!(error.expected, "\",\"");
+
+        let error = parse_input("pos=<0,0,0>, r=99999999999").unwrap_err();
+        assert_eq!(error.expected, "an integer that fits in i32");
+        assert_eq!(error.found, "\"99999999999\"");
+
+        let error = parse_input("pos=<0,0,0>").unwrap_err();
+        assert_eq!(error.found, "end of line");
+    }
+}
@@ -0,0 +1,587 @@
+// https://adventofcode.com/2018/day/24
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use core::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+// code
+
+// a run of letters; e.g. a type of damage
+fn parse_word(scanner: &mut Scanner) -> Result<String, ParseError> {
+    let is_letter = |c: char| c.is_ascii_alphabetic();
+    let mut word = scanner.char("a letter", is_letter)?.to_string();
+
+    while scanner.rest().starts_with(is_letter) {
+        word.push(scanner.char("a letter", is_letter)?);
+    }
+
+    Ok(word)
+}
+
+// e.g. (immune to fire; weak to bludgeoning, slashing)
+fn parse_traits(scanner: &mut Scanner) -> Result<Vec<Trait>, ParseError> {
+    let mut traits = vec![];
+
+    scanner.literal("(")?;
+
+    loop {
+        let is_immunity = if scanner.eat("immune to ") {
+            true
+        } else if scanner.eat("weak to ") {
+            false
+        } else {
+            return Err(scanner.error("\"immune to \" or \"weak to \""));
+        };
+
+        let mut words = HashSet::new();
+        words.insert(parse_word(scanner)?);
+        while scanner.eat(", ") {
+            words.insert(parse_word(scanner)?);
+        }
+
+        traits.push(if is_immunity {
+            Trait::Immunities(words)
+        } else {
+            Trait::Weaknesses(words)
+        });
+
+        if !scanner.eat("; ") {
+            break;
+        }
+    }
+
+    scanner.literal(")")?;
+
+    Ok(traits)
+}
+
+// e.g. 17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that
+// does 4507 fire damage at initiative 2
+fn parse_group(mut scanner: Scanner, race: Race) -> Result<Group, ParseError> {
+    let num_of_units: i32 = scanner.integer()?;
+    scanner.literal(" units each with ")?;
+    let hit_points: i32 = scanner.integer()?;
+    scanner.literal(" hit points ")?;
+
+    let traits = if scanner.rest().starts_with('(') {
+        let traits = parse_traits(&mut scanner)?;
+        scanner.literal(" ")?;
+        traits
+    } else {
+        vec![]
+    };
+
+    scanner.literal("with an attack that does ")?;
+    let attack_damage: Damage = scanner.integer()?;
+    scanner.literal(" ")?;
+    let attack_type = parse_word(&mut scanner)?;
+    scanner.literal(" damage at initiative ")?;
+    let initiative: i32 = scanner.integer()?;
+    scanner.end()?;
+
+    let mut immunities = HashSet::new();
+    let mut weaknesses = HashSet::new();
+
+    for item in traits {
+        match item {
+            Trait::Immunities(words) => immunities.extend(words),
+            Trait::Weaknesses(words) => weaknesses.extend(words),
+        }
+    }
+
+    Ok(Group {
+        id: 0,
+
+        race,
+
+        num_of_units,
+        hit_points,
+
+        attack_damage,
+        attack_type,
+        initiative,
+
+        immunities,
+        weaknesses,
+    })
+}
+
+// the groups of the immune system, followed by the groups of the infection
+fn parse_input(input_string: &str) -> Result<Battle, ParseError> {
+    let mut groups = BinaryHeap::new();
+    let mut race: Option<Race> = None;
+    // of the current race
+    let mut num_of_groups = 0;
+    let mut last_line = Scanner::new(0, "");
+
+    for mut scanner in scan_lines(input_string) {
+        last_line = scanner;
+
+        match race {
+            None => {
+                scanner.literal("Immune System:")?;
+                scanner.end()?;
+                race = Some(Race::Immunity);
+            }
+            Some(Race::Immunity) if num_of_groups > 0 && scanner.eat("Infection:") => {
+                scanner.end()?;
+                race = Some(Race::Infection);
+                num_of_groups = 0;
+            }
+            Some(ref race) => {
+                groups.push(parse_group(scanner, race.clone())?);
+                num_of_groups += 1;
+            }
+        }
+    }
+
+    // each army has at least one group
+    let expected = match race {
+        None => "\"Immune System:\"",
+        Some(Race::Immunity) if num_of_groups > 0 => "\"Infection:\"",
+        _ if num_of_groups == 0 => "a group",
+        _ => return Ok(Battle::new(groups)),
+    };
+
+    Err(Scanner::new(last_line.line_number() + 1, "").error(expected))
+}
+
+fn target_order(first_group: &Group, second_group: &Group) -> Ordering {
+    // group with higher effective power is first
+    if first_group.effective_power() != second_group.effective_power() {
+        return first_group
+            .effective_power()
+            .cmp(&second_group.effective_power());
+    }
+
+    // in a tie, the group with the higher initiative is first.
+    first_group.initiative.cmp(&second_group.initiative)
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+enum Race {
+    Immunity,
+    Infection,
+}
+
+#[derive(Debug, Clone)]
+enum Trait {
+    Weaknesses(HashSet<String>),
+    Immunities(HashSet<String>),
+}
+
+type GroupID = i32;
+type Damage = i32;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Group {
+    id: GroupID,
+
+    race: Race,
+
+    num_of_units: i32,
+    hit_points: i32,
+
+    attack_damage: Damage,
+    attack_type: String,
+    initiative: i32,
+
+    immunities: HashSet<String>,
+    weaknesses: HashSet<String>,
+}
+
+impl Group {
+    #[allow(dead_code)]
+    fn to_str(&self) -> String {
+        format!(
+            "Group {} ({:?}) with {} units (HP: {})",
+            self.id, self.race, self.num_of_units, self.hit_points
+        )
+    }
+
+    fn effective_power(&self) -> i32 {
+        self.attack_damage * self.num_of_units
+    }
+
+    fn immune_to(&self, attack_type: &String) -> bool {
+        self.immunities.contains(attack_type)
+    }
+
+    fn weak_to(&self, attack_type: &String) -> bool {
+        self.weaknesses.contains(attack_type)
+    }
+
+    fn calculate_damage_to_group(&self, other_group: &Self) -> Damage {
+        if other_group.immune_to(&self.attack_type) {
+            return 0;
+        }
+
+        if other_group.weak_to(&self.attack_type) {
+            return 2 * self.effective_power();
+        }
+
+        self.effective_power()
+    }
+
+    fn take_damage(&mut self, other_group: &Self) -> bool {
+        let damage_taken = other_group.calculate_damage_to_group(self);
+
+        let mut num_of_units_dead: i32 = damage_taken / self.hit_points;
+
+        assert!(num_of_units_dead >= 0);
+
+        num_of_units_dead = if num_of_units_dead >= self.num_of_units {
+            self.num_of_units
+        } else {
+            num_of_units_dead
+        };
+
+        self.num_of_units -= num_of_units_dead;
+
+        // println!(
+        //     "Group {} ({:?}) attacking Group {} ({:?}): {} units died",
+        //     other_group.id, other_group.race, self.id, self.race, num_of_units_dead
+        // );
+
+        num_of_units_dead > 0
+    }
+
+    fn is_alive(&self) -> bool {
+        self.num_of_units > 0
+    }
+}
+
+impl Ord for Group {
+    fn cmp(&self, other: &Self) -> Ordering {
+        target_order(self, other)
+    }
+}
+
+impl PartialOrd for Group {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum WarStatus {
+    Over,
+    NotOver,
+    StaleMate,
+}
+
+#[derive(Debug, Clone)]
+struct Battle {
+    groups: BinaryHeap<Group>,
+}
+
+impl Battle {
+    fn new(groups: BinaryHeap<Group>) -> Self {
+        let mut new_group = BinaryHeap::new();
+
+        for (current_id, mut group) in groups.into_iter().enumerate() {
+            group.id = current_id as i32;
+            new_group.push(group);
+        }
+
+        Battle { groups: new_group }
+    }
+
+    fn has_immunity(&self) -> bool {
+        self.groups
+            .iter()
+            .filter(|group| group.race == Race::Immunity)
+            .count()
+            > 0
+    }
+
+    fn has_infection(&self) -> bool {
+        self.groups
+            .iter()
+            .filter(|group| group.race == Race::Infection)
+            .count()
+            > 0
+    }
+
+    fn boost(&mut self, boost: Damage) {
+        self.groups = self
+            .groups
+            .iter()
+            .map(|group| {
+                let mut group = group.clone();
+                if group.race == Race::Immunity {
+                    group.attack_damage += boost;
+                }
+                group
+            })
+            .collect();
+    }
+
+    fn has_targets(&self, current_group: &Group) -> bool {
+        self.groups
+            .iter()
+            .filter(|group| {
+                // cannot attack its own race
+                current_group.race != group.race
+            })
+            .count()
+            > 0
+    }
+
+    fn execute_fight_round(&mut self) -> WarStatus {
+        // target selection phase
+
+        let targets = self.groups.clone();
+        let mut queue = self.groups.clone();
+
+        let mut unavailable_targets: HashSet<i32> = HashSet::new();
+
+        let mut groups_lookup: HashMap<GroupID, Group> = HashMap::new();
+        let mut target_selection: Vec<(GroupID, GroupID)> = vec![];
+
+        while let Some(current_group) = queue.pop() {
+            groups_lookup.insert(current_group.id, current_group.clone());
+
+            // TODO: remove
+            // println!(
+            //     "{} effective_power -- {} initiative",
+            //     current_group.effective_power(),
+            //     current_group.initiative
+            // );
+
+            if !self.has_targets(&current_group) {
+                return WarStatus::Over;
+            }
+
+            let mut potential_targets: Vec<(&Group, i32)> = targets
+                .iter()
+                .filter(|target| {
+                    // cannot attack itself
+                    current_group.id != target.id
+                })
+                .filter(|target| {
+                    // cannot attack its own race
+                    current_group.race != target.race
+                })
+                .map(|target| {
+                    let potential_damage = current_group.calculate_damage_to_group(target);
+                    (target, potential_damage)
+                })
+                .filter(|(_target, potential_damage)| {
+                    // only consider targets for which damage can be dealt
+                    potential_damage > &0
+                })
+                .filter(|(target, _potential_damage)| {
+                    // only consider targets for which are not chosen
+                    !unavailable_targets.contains(&target.id)
+                })
+                .collect();
+
+            potential_targets.sort_by(|this, other| {
+                let (this_target, this_potential_damage) = this;
+                let (other_target, other_potential_damage) = other;
+
+                if this_potential_damage != other_potential_damage {
+                    // The attacking group chooses to target the group in the enemy army to which it would deal the most damage
+                    // (after accounting for weaknesses and immunities, but not accounting for whether the defending group
+                    // has enough units to actually receive all of that damage).
+                    return other_potential_damage.cmp(this_potential_damage);
+                }
+
+                // If an attacking group is considering two defending groups to which it would deal equal damage,
+                // it chooses to target the defending group with the largest effective power;
+                // if there is still a tie, it chooses the defending group with the highest initiative.
+                target_order(other_target, this_target)
+            });
+
+            if !potential_targets.is_empty() {
+                let (target, _damage) = potential_targets.first().unwrap();
+
+                assert!(!unavailable_targets.contains(&target.id));
+
+                unavailable_targets.insert(target.id);
+                target_selection.push((current_group.id, target.id));
+            }
+        }
+
+        // attack phase
+
+        target_selection.sort_by(|(this, _), (other, _)| {
+            let this_initiative = groups_lookup.get(this).unwrap().initiative;
+            let other_initiative = groups_lookup.get(other).unwrap().initiative;
+
+            other_initiative.cmp(&this_initiative)
+        });
+
+        let mut units_died_in_this_round = false;
+
+        for (attacking_group_id, defending_group_id) in target_selection.into_iter() {
+            let attacking_group = groups_lookup.get(&attacking_group_id).unwrap().clone();
+
+            if !attacking_group.is_alive() {
+                continue;
+            }
+
+            match groups_lookup.get_mut(&defending_group_id) {
+                None => {
+                    unreachable!();
+                }
+                Some(defending_group) => {
+                    if !defending_group.is_alive() {
+                        continue;
+                    }
+                    if defending_group.take_damage(&attacking_group) {
+                        units_died_in_this_round = true;
+                    }
+                }
+            }
+        }
+
+        let mut new_groups: BinaryHeap<Group> = BinaryHeap::new();
+
+        let remaining_groups = groups_lookup.values().filter(|&g| g.is_alive()).cloned();
+
+        new_groups.extend(remaining_groups);
+
+        self.groups = new_groups;
+
+        if !units_died_in_this_round {
+            return WarStatus::StaleMate;
+        }
+
+        if !self.groups.is_empty() {
+            return WarStatus::NotOver;
+        }
+
+        WarStatus::Over
+    }
+}
+
+fn part_1(input_string: &str) -> Option<i32> {
+    let mut battle = parse_input(input_string).ok()?;
+
+    loop {
+        let status = battle.execute_fight_round();
+        // println!("--------");
+
+        if status == WarStatus::Over {
+            break;
+        }
+    }
+
+    let remaining_units = battle
+        .groups
+        .iter()
+        .fold(0, |acc, group| acc + group.num_of_units);
+
+    Some(remaining_units)
+}
+
+fn part_2(input_string: &str) -> Option<i32> {
+    let battle = parse_input(input_string).ok()?;
+
+    for boost in 0.. {
+        let mut battle = battle.clone();
+        // println!("Boost: {}", boost);
+        battle.boost(boost);
+
+        loop {
+            let status = battle.execute_fight_round();
+
+            if status == WarStatus::Over || status == WarStatus::StaleMate {
+                break;
+            }
+        }
+
+        if battle.has_immunity() && !battle.has_infection() {
+            let remaining_units = battle.groups.iter().fold(0, |acc, group| {
+                assert!(group.race == Race::Immunity);
+                acc + group.num_of_units
+            });
+
+            return Some(remaining_units);
+        }
+    }
+
+    None
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        24
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = r###"
+Immune System:
+17 units each with 5390 hit points (weak to radiation, bludgeoning) with an attack that does 4507 fire damage at initiative 2
+989 units each with 1274 hit points (immune to fire; weak to bludgeoning, slashing) with an attack that does 25 slashing damage at initiative 3
+
+Infection:
+801 units each with 4706 hit points (weak to radiation) with an attack that does 116 bludgeoning damage at initiative 1
+4485 units each with 2961 hit points (immune to radiation; weak to fire, cold) with an attack that does 12 slashing damage at initiative 4
+        "###;
+
+        assert_eq!(part_1(input), Some(5216));
+
+        let input_string = include_str!("input.txt");
+        assert_eq!(part_1(input_string), Some(14799));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = r###"
+Immune System:
+17 units each with 5390 hit points (weak to radiation; bludgeoning) with an attack that does 4507 fire damage at initiative 2
+"###;
+
+        let error = parse_input(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 56: expected \"immune to \" or \"weak to \", found \"bludgeoning\""
+        );
+
+        let input = r###"
+Immune System:
+17 units each with 5390 hit points with an attack that does 4507 fire damage at initiative 2
+"###;
+
+        let error = parse_input(input).err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected \"Infection:\", found end of line"
+        );
+
+        let error = parse_input("Infection:").err().unwrap();
+        assert_eq!(error.expected, "\"Immune System:\"");
+    }
+}
@@ -0,0 +1,238 @@
+// https://adventofcode.com/2018/day/25
+
+// imports
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::collections::HashSet;
+
+// code
+
+// adapted from day 6
+// https://math.stackexchange.com/a/139604/10247
+type Distance = i32;
+fn get_manhattan_distance(start: Coordinate, end: Coordinate) -> Distance {
+    let (a, b, e, g) = start;
+    let (c, d, f, h) = end;
+
+    (a - c).abs() + (b - d).abs() + (e - f).abs() + (g - h).abs()
+}
+
+type Coordinate = (i32, i32, i32, i32);
+
+#[derive(Debug, Clone)]
+struct Constellation {
+    points: HashSet<Coordinate>,
+}
+
+impl Constellation {
+    fn new(point: Coordinate) -> Self {
+        let mut points = HashSet::new();
+        points.insert(point);
+
+        Constellation { points }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        let mut new_points = self.points;
+        new_points.extend(other.points);
+
+        Constellation { points: new_points }
+    }
+
+    fn can_merge(&mut self, other: &Self) -> bool {
+        for current_point in &self.points {
+            if other.can_add(*current_point) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn can_add(&self, new_point: Coordinate) -> bool {
+        for point in &self.points {
+            assert!(new_point != *point);
+            if get_manhattan_distance(*point, new_point) <= 3 {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn add(&mut self, point: Coordinate) {
+        self.points.insert(point);
+    }
+}
+
+fn parse_coordinate(mut scanner: Scanner) -> Result<Coordinate, ParseError> {
+    let mut coords = [0; 4];
+
+    for (index, coord) in coords.iter_mut().enumerate() {
+        if index > 0 {
+            scanner.skip_whitespace();
+            scanner.literal(",")?;
+            scanner.skip_whitespace();
+        }
+        *coord = scanner.integer()?;
+    }
+    scanner.end()?;
+
+    Ok((coords[0], coords[1], coords[2], coords[3]))
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Coordinate>, ParseError> {
+    scan_lines(input_string).map(parse_coordinate).collect()
+}
+
+fn part_1(input_string: &str) -> Option<usize> {
+    let mut constellations: Vec<Constellation> = vec![];
+    let points = parse_input(input_string).ok()?;
+
+    'points_loop: for current_point in points {
+        // find a constellation to join
+        for constellation in constellations.iter_mut() {
+            if constellation.can_add(current_point) {
+                constellation.add(current_point);
+                continue 'points_loop;
+            }
+        }
+
+        // invariant: no constellation found
+        let constellation = Constellation::new(current_point);
+        constellations.push(constellation);
+    }
+
+    // merge constallations
+
+    loop {
+        let num_of_constellations_before = constellations.len();
+
+        constellations = constellations
+            .into_iter()
+            .fold(vec![], |mut acc, mut constellation| {
+                // find a constellation to join
+                for potential_constellation in acc.iter_mut() {
+                    if constellation.can_merge(potential_constellation) {
+                        let new_constellation =
+                            constellation.merge(potential_constellation.clone());
+                        *potential_constellation = new_constellation;
+                        return acc;
+                    }
+                }
+
+                // invariant: no constellation found
+                acc.push(constellation);
+
+                acc
+            });
+
+        let num_of_constellations_after = constellations.len();
+
+        if num_of_constellations_before == num_of_constellations_after {
+            break;
+        }
+    }
+
+    Some(constellations.len())
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn year(&self) -> u16 {
+        2018
+    }
+
+    fn day(&self) -> u8 {
+        25
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(include_str!("input.txt")), Some(352));
+
+        let input_string = r####"
+ 0,0,0,0
+ 3,0,0,0
+ 0,3,0,0
+ 0,0,3,0
+ 0,0,0,3
+ 0,0,0,6
+ 9,0,0,0
+12,0,0,0
+        "####;
+
+        assert_eq!(part_1(input_string), Some(2));
+
+        let input_string = r####"
+-1,2,2,0
+0,0,2,-2
+0,0,0,-2
+-1,2,0,0
+-2,-2,-2,2
+3,0,2,-1
+-1,3,2,2
+-1,0,-1,0
+0,2,1,-2
+3,0,0,0
+        "####;
+
+        assert_eq!(part_1(input_string), Some(4));
+
+        let input_string = r####"
+1,-1,0,1
+2,0,-1,0
+3,2,-1,0
+0,0,3,1
+0,0,-1,-1
+2,3,-2,0
+-2,2,0,0
+2,-2,0,-1
+1,-1,0,-1
+3,2,0,2
+        "####;
+
+        assert_eq!(part_1(input_string), Some(3));
+
+        let input_string = r####"
+1,-1,-1,-2
+-2,-2,0,1
+0,2,1,3
+-2,3,-2,1
+0,2,3,-2
+-1,-1,1,-2
+0,-2,-1,0
+-2,2,3,-1
+1,2,2,0
+-1,-2,0,-2
+        "####;
+
+        assert_eq!(part_1(input_string), Some(8));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("0,0,0,0\n0,0,0\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected \",\", found end of line"
+        );
+
+        let error = parse_input("0,0,0,0,1").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 8));
+    }
+}
@@ -0,0 +1,110 @@
+// https://adventofcode.com/2019/day/1
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+fn fuel_required(mass: i32) -> i32 {
+    (((mass as f64) / 3.0).floor() - 2.0) as i32
+}
+
+// the mass of a module on each line
+fn parse_mass(mut scanner: Scanner) -> Result<i32, ParseError> {
+    let mass = scanner.integer()?;
+    scanner.end()?;
+
+    Ok(mass)
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<i32>, ParseError> {
+    scan_lines(input_string).map(parse_mass).collect()
+}
+
+fn part_1(inputs: Vec<i32>) -> i32 {
+    let total_fuel_requirements: i32 = inputs.into_iter().map(fuel_required).sum();
+
+    total_fuel_requirements
+}
+
+fn fuel_required_part_2(mass: i32) -> i32 {
+    let mut sum = fuel_required(mass);
+    let mut last_fuel_required = sum;
+
+    loop {
+        last_fuel_required = fuel_required(last_fuel_required);
+        if last_fuel_required <= 0 {
+            return sum;
+        }
+
+        sum += last_fuel_required;
+    }
+}
+
+fn part_2(inputs: Vec<i32>) -> i32 {
+    let total_fuel_requirements: i32 = inputs.into_iter().map(fuel_required_part_2).sum();
+
+    total_fuel_requirements
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuel_required() {
+        assert_eq!(fuel_required(12), 2);
+        assert_eq!(fuel_required(14), 2);
+        assert_eq!(fuel_required(1969), 654);
+        assert_eq!(fuel_required(100756), 33583);
+
+        let input_string = include_str!("input.txt");
+        let inputs = parse_input(input_string).unwrap();
+        assert_eq!(part_1(inputs), 3249140);
+    }
+
+    #[test]
+    fn test_fuel_required_part_2() {
+        assert_eq!(fuel_required_part_2(14), 2);
+        assert_eq!(fuel_required_part_2(1969), 966);
+        assert_eq!(fuel_required_part_2(100756), 50346);
+
+        let input_string = include_str!("input.txt");
+        let inputs = parse_input(input_string).unwrap();
+        assert_eq!(part_2(inputs), 4870838);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("12\n14x\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected end of line, found \"x\""
+        );
+
+        let error = parse_input("12\n\n1969 100756").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 6));
+    }
+}
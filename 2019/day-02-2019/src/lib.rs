@@ -0,0 +1,94 @@
+// https://adventofcode.com/2019/day/2
+
+use aoc_core::{ParseError, Solution};
+use intcode::{parse_program, Machine, Value};
+use rayon::prelude::*;
+
+fn run_computer(program: &[Value], noun: Value, verb: Value) -> Value {
+    let mut machine = Machine::new(program.to_vec());
+
+    machine.write(1, noun);
+    machine.write(2, verb);
+
+    machine.run().unwrap();
+
+    machine.read(0)
+}
+
+fn part_2(program: &[Value]) -> Option<Value> {
+    let noun_range: Vec<Value> = (0..=99).collect();
+    let verb_range: Vec<Value> = (0..=99).collect();
+
+    let result: Option<(Value, Value)> = noun_range
+        .into_par_iter()
+        .map(|noun| -> Option<(Value, Value)> {
+            let result = verb_range
+                .par_iter()
+                .map(|verb| -> Option<(Value, Value)> {
+                    let result = run_computer(program, noun, *verb);
+
+                    if result == 19690720 {
+                        return Some((noun, *verb));
+                    }
+
+                    None
+                })
+                .find_first(|result: &Option<(Value, Value)>| result.is_some());
+
+            if let Some(result) = result {
+                return result;
+            }
+
+            None
+        })
+        .find_first(|result: &Option<(Value, Value)>| result.is_some())
+        .unwrap();
+
+    // Unable to find the noun and verb combination otherwise.
+    result.map(|(noun, verb)| 100 * noun + verb)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_program(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        // restore the gravity assist program (your puzzle input) to the "1202 program alarm" state
+        // it had just before the last computer caught fire. To do this, before running the program,
+        // replace position 1 with the value 12 and replace position 2 with the value 2.
+        let program = parse_program(input_string).ok()?;
+        Some(run_computer(&program, 12, 2).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(&parse_program(input_string).ok()?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_computer() {
+        let run =
+            |program: &str, noun, verb| run_computer(&parse_program(program).unwrap(), noun, verb);
+
+        assert_eq!(run("1,9,10,3,2,3,11,0,99,30,40,50", 9, 10), 3500);
+        assert_eq!(run("1,0,0,0,99", 0, 0), 2);
+        assert_eq!(run("2,4,4,5,99,0", 4, 4), 2);
+        assert_eq!(run("1,1,1,4,99,5,6,0,99", 1, 1), 30);
+    }
+}
@@ -0,0 +1,236 @@
+// https://adventofcode.com/2019/day/4
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use rayon::prelude::*;
+
+fn is_valid_password(password: String) -> bool {
+    if password.len() != 6 {
+        return false;
+    }
+
+    let chars: Vec<u8> = password
+        .chars()
+        .map(|digit| -> u8 { digit.to_digit(10).unwrap() as u8 })
+        .collect();
+
+    let mut equal_adjacent_digits = false;
+    let mut prev_digit: Option<u8> = None;
+
+    for digit in chars {
+        match prev_digit {
+            None => {
+                prev_digit = Some(digit);
+                continue;
+            }
+            Some(last_digit) => {
+                // Two adjacent digits are the same
+                if !equal_adjacent_digits && digit == last_digit {
+                    equal_adjacent_digits = true;
+                }
+
+                // println!("{} <= {} {}", last_digit, digit, digit >= last_digit);
+
+                if digit >= last_digit {
+                    prev_digit = Some(digit);
+                    continue;
+                }
+
+                return false;
+            }
+        }
+    }
+
+    if !equal_adjacent_digits {
+        return false;
+    }
+
+    true
+}
+
+fn is_valid_password_part_2(password: String) -> bool {
+    if password.len() != 6 {
+        return false;
+    }
+
+    let chars: Vec<u8> = password
+        .chars()
+        .map(|digit| -> u8 { digit.to_digit(10).unwrap() as u8 })
+        .collect();
+
+    // the two adjacent matching digits are not part of a larger group of matching digits.
+    let mut digit_counter: HashMap<u8, u32> = HashMap::new();
+    let mut prev_digit: Option<u8> = None;
+
+    for digit in chars {
+        match prev_digit {
+            None => {
+                let entry = digit_counter.entry(digit).or_insert(0);
+                *entry += 1;
+
+                prev_digit = Some(digit);
+                continue;
+            }
+            Some(last_digit) => {
+                // if !digit_counter.contains_key(&digit) {
+                //     digit_counter.insert(digit, 1);
+                // } else {
+                //     let count = digit_counter.get(&digit).unwrap();
+                //     digit_counter.insert(digit, count + 1);
+                // }
+
+                let entry = digit_counter.entry(digit).or_insert(0);
+                *entry += 1;
+
+                if digit >= last_digit {
+                    prev_digit = Some(digit);
+                    continue;
+                }
+
+                return false;
+            }
+        }
+    }
+
+    for count in digit_counter.values() {
+        if *count == 2 {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn parse_bound(scanner: &mut Scanner, lower: i32) -> Result<i32, ParseError> {
+    let start = *scanner;
+    let bound: i32 = scanner.integer()?;
+
+    if bound < lower {
+        return Err(start.error(format!("a bound of at least {}", lower)));
+    }
+
+    Ok(bound)
+}
+
+fn parse_input(input_string: &str) -> Result<RangeInclusive<i32>, ParseError> {
+    let mut scanner = scan_lines(input_string)
+        .next()
+        .unwrap_or_else(|| Scanner::new(1, ""));
+
+    let start = parse_bound(&mut scanner, 0)?;
+    scanner.literal("-")?;
+    let end = parse_bound(&mut scanner, start)?;
+    scanner.end()?;
+
+    Ok(start..=end)
+}
+
+fn part_1(input_string: &str) -> Option<u32> {
+    let count = parse_input(input_string)
+        .ok()?
+        .into_par_iter()
+        .map(|current_pass: i32| -> u32 {
+            if is_valid_password(format!("{}", current_pass)) {
+                return 1;
+            }
+            0
+        })
+        .sum();
+
+    Some(count)
+}
+
+fn part_2(input_string: &str) -> Option<u32> {
+    let count = parse_input(input_string)
+        .ok()?
+        .into_par_iter()
+        .map(|current_pass: i32| -> u32 {
+            if is_valid_password_part_2(format!("{}", current_pass)) {
+                return 1;
+            }
+            0
+        })
+        .sum();
+
+    Some(count)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_password() {
+        assert!(is_valid_password("111111".to_string()));
+        assert!(is_valid_password("122345".to_string()));
+        assert!(is_valid_password("111123".to_string()));
+        assert!(!is_valid_password("135679".to_string()));
+
+        assert!(!is_valid_password("223450".to_string()));
+        assert!(!is_valid_password("123789".to_string()));
+    }
+
+    #[test]
+    fn test_is_valid_password_part_2() {
+        assert!(!is_valid_password_part_2("111111".to_string()));
+        assert!(is_valid_password_part_2("122345".to_string()));
+        assert!(!is_valid_password_part_2("111123".to_string()));
+        assert!(!is_valid_password_part_2("135679".to_string()));
+
+        assert!(!is_valid_password_part_2("223450".to_string()));
+        assert!(!is_valid_password_part_2("123789".to_string()));
+        assert!(is_valid_password_part_2("112233".to_string()));
+        assert!(!is_valid_password_part_2("123444".to_string()));
+        assert!(is_valid_password_part_2("111122".to_string()));
+        assert!(is_valid_password_part_2("112345".to_string()));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("\n111110-111112\n"), Ok(111110..=111112));
+        assert_eq!(
+            parse_input("111110").unwrap_err().to_string(),
+            "line 1, column 7: expected \"-\", found end of line"
+        );
+        assert_eq!(
+            parse_input("-5-10").unwrap_err().expected,
+            "a bound of at least 0"
+        );
+        assert_eq!(
+            parse_input("20-10").unwrap_err().expected,
+            "a bound of at least 20"
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1("111110-111112"), Some(2));
+    }
+}
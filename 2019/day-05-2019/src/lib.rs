@@ -0,0 +1,102 @@
+// https://adventofcode.com/2019/day/5
+
+use aoc_core::{ParseError, Solution};
+use intcode::{parse_program, Machine, Value};
+
+// Run the diagnostic program with the ID of the system to test, and return the diagnostic code.
+// Every output before the diagnostic code is the result of a test, which is 0 if it passed.
+fn run_diagnostic(program: &[Value], system_id: Value) -> Option<Value> {
+    let mut machine = Machine::new(program.to_vec());
+    machine.push_input(system_id);
+    machine.run().unwrap();
+
+    let output = machine.take_output();
+    let (diagnostic_code, test_results) = output.split_last()?;
+
+    assert!(test_results.iter().all(|result| *result == 0));
+
+    Some(*diagnostic_code)
+}
+
+fn part_1(program: &[Value]) -> Option<Value> {
+    // the ID of the ship's air conditioner unit
+    run_diagnostic(program, 1)
+}
+
+fn part_2(program: &[Value]) -> Option<Value> {
+    // the ID of the ship's thermal radiator controller
+    run_diagnostic(program, 5)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn year(&self) -> u16 {
+        2019
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_program(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(&parse_program(input_string).ok()?)?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(&parse_program(input_string).ok()?)?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_program(program: &str, input: Value) -> Vec<Value> {
+        let mut machine = Machine::parse(program).unwrap();
+        machine.push_input(input);
+        machine.run().unwrap();
+        machine.take_output()
+    }
+
+    #[test]
+    fn test_examples() {
+        // outputs whatever it gets as input
+        assert_eq!(run_program("3,0,4,0,99", 42), vec![42]);
+
+        // multiplies 33 by 3 in immediate mode, and stores it at address 4
+        let mut machine = Machine::parse("1002,4,3,4,33").unwrap();
+        machine.run().unwrap();
+        assert_eq!(machine.read(4), 99);
+
+        // negative numbers
+        let mut machine = Machine::parse("1101,100,-1,4,0").unwrap();
+        machine.run().unwrap();
+        assert_eq!(machine.read(4), 99);
+
+        // outputs 999 if the input is below 8, 1000 if it is equal to 8, or 1001 if it is greater
+        let program = r###"
+3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,
+1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,
+999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99
+"###
+        .replace(",\n", ",");
+
+        assert_eq!(run_program(&program, 7), vec![999]);
+        assert_eq!(run_program(&program, 8), vec![1000]);
+        assert_eq!(run_program(&program, 9), vec![1001]);
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let program = parse_program(include_str!("input.txt")).unwrap();
+
+        assert_eq!(part_1(&program), Some(5182797));
+        assert_eq!(part_2(&program), Some(12077198));
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+fn part_1(entries: Vec<i32>) -> Option<i32> {
+    find_candidates_sum(entries, 2020)
+}
+
+fn part_2(entries: Vec<i32>) -> Option<i32> {
+    for (index, entry) in entries.iter().enumerate() {
+        let mut rest = entries.clone();
+        rest.remove(index);
+
+        let expected_sum = 2020 - entry;
+
+        if expected_sum < 0 {
+            continue;
+        }
+
+        if let Some(result) = find_candidates_sum(rest, expected_sum) {
+            return Some(result * entry);
+        }
+    }
+
+    None
+}
+
+// find two candidates that add up to to sum, and multiply them
+fn find_candidates_sum(entries: Vec<i32>, sum: i32) -> Option<i32> {
+    for (index, entry) in entries.iter().enumerate() {
+        let start_index = index + 1;
+
+        if start_index >= entries.len() {
+            return None;
+        }
+
+        let rest = &entries[start_index..];
+        for other_entry in rest {
+            if entry + other_entry == sum {
+                return Some(entry * other_entry);
+            }
+        }
+    }
+
+    None
+}
+
+fn parse_entry(mut scanner: Scanner) -> Result<i32, ParseError> {
+    let entry = scanner.integer()?;
+    scanner.end()?;
+
+    Ok(entry)
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<i32>, ParseError> {
+    scan_lines(input_string).map(parse_entry).collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(parse_input(input_string).ok()?).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(parse_input(input_string).ok()?).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_1() {
+        let input_string = include_str!("input.txt");
+
+        let inputs: Vec<&str> = input_string.split_whitespace().collect();
+
+        let entries: Vec<i32> = inputs
+            .into_iter()
+            .map(|entry| -> i32 { entry.parse().unwrap() })
+            .collect();
+
+        assert_eq!(part_1(entries.clone()), Some(864864));
+        assert_eq!(part_2(entries), Some(281473080));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("1721\n979 366\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected end of line, found \"366\""
+        );
+
+        assert_eq!(part_1(parse_input("1721\n979\n").unwrap()), None);
+    }
+}
@@ -0,0 +1,147 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+#[derive(Clone)]
+struct PasswordPolicy {
+    min: u32,
+    max: u32,
+    character: char,
+    password: String,
+}
+
+impl PasswordPolicy {
+    fn is_valid_old_policy(&self) -> bool {
+        let counts = self.password.matches(self.character).count() as u32;
+        self.min <= counts && counts <= self.max
+    }
+
+    fn is_valid_new_policy(&self) -> bool {
+        // positions past the end of the password never hold the character
+        let first_position_char = self.password.chars().nth(self.min as usize - 1);
+        let second_position_char = self.password.chars().nth(self.max as usize - 1);
+
+        (first_position_char == Some(self.character))
+            != (second_position_char == Some(self.character))
+    }
+}
+
+fn parse_policy(mut scanner: Scanner) -> Result<PasswordPolicy, ParseError> {
+    let start = scanner;
+    let min: u32 = scanner.integer()?;
+    if min == 0 {
+        return Err(start.error("a position of at least 1"));
+    }
+    scanner.literal("-")?;
+
+    let start = scanner;
+    let max: u32 = scanner.integer()?;
+    if max < min {
+        return Err(start.error(format!("a position of at least {}", min)));
+    }
+    scanner.skip_whitespace();
+
+    let character = scanner.char("a letter", |c| c.is_ascii_alphabetic())?;
+    scanner.literal(":")?;
+    scanner.skip_whitespace();
+
+    let password = scanner.word("a password")?.to_string();
+    scanner.end()?;
+
+    Ok(PasswordPolicy {
+        min,
+        max,
+        character,
+        password,
+    })
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<PasswordPolicy>, ParseError> {
+    scan_lines(input_string).map(parse_policy).collect()
+}
+
+fn part_1(entries: Vec<PasswordPolicy>) -> i32 {
+    let mut num_of_valid_passwords = 0;
+
+    for entry in entries {
+        if entry.is_valid_old_policy() {
+            num_of_valid_passwords += 1;
+        }
+    }
+
+    num_of_valid_passwords
+}
+
+fn part_2(entries: Vec<PasswordPolicy>) -> i32 {
+    let mut num_of_valid_passwords = 0;
+
+    for entry in entries {
+        if entry.is_valid_new_policy() {
+            num_of_valid_passwords += 1;
+        }
+    }
+
+    num_of_valid_passwords
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_2() {
+        let input_string = include_str!("input.txt");
+
+        let puzzle_inputs = parse_input(input_string).unwrap();
+
+        assert_eq!(part_1(puzzle_inputs.clone()), 625);
+        assert_eq!(part_2(puzzle_inputs), 391);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("1-3 a: abcde\n1-3 b cdefg\n").err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected \":\", found \" \""
+        );
+
+        let error = parse_input("0-3 a: abcde").err().unwrap();
+        assert_eq!(error.expected, "a position of at least 1");
+
+        let error = parse_input("3-1 a: abcde").err().unwrap();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (3, "a position of at least 3")
+        );
+    }
+
+    #[test]
+    fn test_new_policy_past_the_end() {
+        let policies = parse_input("2-9 c: ccccccccc\n2-10 c: ccccccccc").unwrap();
+        assert_eq!(part_2(policies), 1);
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+// whether there is a tree at each square of a row of the map
+fn parse_row(mut scanner: Scanner) -> Result<Vec<bool>, ParseError> {
+    let mut row = vec![];
+
+    while !scanner.is_at_end() {
+        let square = scanner.char("an open square (.) or a tree (#)", |c| c == '.' || c == '#')?;
+        row.push(square == '#');
+    }
+
+    Ok(row)
+}
+
+// the rows of the map, which all have the same width
+fn parse_input(input_string: &str) -> Result<Vec<Vec<bool>>, ParseError> {
+    let mut rows: Vec<Vec<bool>> = vec![];
+
+    for scanner in scan_lines(input_string) {
+        let row = parse_row(scanner)?;
+
+        if let Some(first_row) = rows.first() {
+            if row.len() != first_row.len() {
+                return Err(scanner.error(format!("a row of {} squares", first_row.len())));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn year(&self) -> u16 {
+        2020
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let rows = parse_input("..##\n#...\n").unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                vec![false, false, true, true],
+                vec![true, false, false, false]
+            ]
+        );
+
+        assert!(parse_input(include_str!("input.txt")).is_ok());
+
+        let error = parse_input("..##\n#.x.\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected an open square (.) or a tree (#), found \"x\""
+        );
+
+        let error = parse_input("..##\n#..\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -1,4 +1,5 @@
+use day_03_2020::Day03;
+
 fn main() {
-    let input_string = include_str!("input.txt");
-    println!("{}", input_string);
+    aoc_core::run(&Day03);
 }
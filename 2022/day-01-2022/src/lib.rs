@@ -0,0 +1,124 @@
+// https://adventofcode.com/2022/day/1
+
+use aoc_core::{ParseError, Scanner, Solution};
+
+type Calories = i32;
+
+#[derive(Debug, Clone)]
+struct Elf {
+    food: Vec<Calories>,
+}
+
+impl Elf {
+    fn get_total_calories(&self) -> i32 {
+        self.food.iter().sum()
+    }
+}
+
+// elves sorted by the total calories they carry, in descending order
+fn parse_input(input_string: &str) -> Result<Vec<Elf>, ParseError> {
+    let mut elves: Vec<Elf> = vec![];
+
+    let mut current_elf = Elf { food: vec![] };
+
+    // blank lines separate the elves, so the lines are scanned as they are
+    for (index, line) in input_string.lines().enumerate() {
+        let mut scanner = Scanner::new(index + 1, line);
+        scanner.skip_whitespace();
+
+        if scanner.is_at_end() {
+            if !current_elf.food.is_empty() {
+                elves.push(current_elf);
+                current_elf = Elf { food: vec![] };
+            }
+            continue;
+        }
+
+        let calories = scanner.integer()?;
+        scanner.end()?;
+        current_elf.food.push(calories);
+    }
+
+    if !current_elf.food.is_empty() {
+        elves.push(current_elf);
+    }
+
+    elves.sort_by_key(|elf| -> i32 { elf.get_total_calories() });
+    elves.reverse();
+
+    Ok(elves)
+}
+
+fn part_1(input_string: &str) -> Option<i32> {
+    let elves = parse_input(input_string).ok()?;
+
+    // Find the Elf carrying the most Calories. How many total Calories is that Elf carrying?
+    let elve_carrying_most_calories = elves.first()?;
+
+    Some(elve_carrying_most_calories.get_total_calories())
+}
+
+fn part_2(input_string: &str) -> Option<i32> {
+    let elves = parse_input(input_string).ok()?;
+
+    if elves.len() < 3 {
+        return None;
+    }
+
+    Some(
+        elves
+            .iter()
+            .take(3)
+            .map(|elf| elf.get_total_calories())
+            .sum(),
+    )
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input_string = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+        assert_eq!(part_1(input_string), Some(24000));
+        assert_eq!(part_2(input_string), Some(45000));
+        assert_eq!(part_1("1000\n\n30000"), Some(30000));
+        assert_eq!(part_2("1000\n\n2000"), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("1000\n\n20OO\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: expected end of line, found \"OO\""
+        );
+    }
+}
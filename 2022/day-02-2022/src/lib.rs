@@ -0,0 +1,231 @@
+// https://adventofcode.com/2022/day/2
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::cmp::Ordering;
+use std::vec;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    fn get_moves() -> Vec<Move> {
+        vec![Move::Rock, Move::Paper, Move::Scissors]
+    }
+
+    fn find_move(&self, outcome: Ordering) -> Move {
+        // every move beats exactly one move and loses to exactly one move
+        Move::get_moves()
+            .into_iter()
+            .find(|possible_move| possible_move.cmp(self) == outcome)
+            .unwrap_or_else(|| self.clone())
+    }
+}
+
+// the second column of the strategy guide
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Column {
+    X,
+    Y,
+    Z,
+}
+
+type Round = (Move, Column);
+
+impl Ord for Move {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Move::Rock, Move::Rock) => Ordering::Equal,
+            (Move::Rock, Move::Paper) => Ordering::Less,
+            (Move::Rock, Move::Scissors) => Ordering::Greater,
+
+            (Move::Paper, Move::Rock) => Ordering::Greater,
+            (Move::Paper, Move::Paper) => Ordering::Equal,
+            (Move::Paper, Move::Scissors) => Ordering::Less,
+
+            (Move::Scissors, Move::Rock) => Ordering::Less,
+            (Move::Scissors, Move::Paper) => Ordering::Greater,
+            (Move::Scissors, Move::Scissors) => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for Move {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct Game {
+    opponent_move: Move,
+    my_move: Move,
+}
+
+impl Game {
+    fn get_my_move_score(&self) -> i32 {
+        // 1 for Rock, 2 for Paper, and 3 for Scissors
+        match self.my_move {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scissors => 3,
+        }
+    }
+
+    fn get_score_part_1(&self) -> i32 {
+        let my_move_score = self.get_my_move_score();
+
+        // 0 if you lost, 3 if the round was a draw, and 6 if you won
+        let outcome = match self.my_move.cmp(&self.opponent_move) {
+            Ordering::Equal => 3,
+            Ordering::Less => 0,
+            Ordering::Greater => 6,
+        };
+
+        my_move_score + outcome
+    }
+}
+
+struct Tournament {
+    rounds: Vec<Game>,
+}
+
+impl Tournament {
+    fn get_total_scores(&self) -> i32 {
+        self.rounds
+            .iter()
+            .map(|round| -> i32 { round.get_score_part_1() })
+            .sum()
+    }
+}
+
+impl Tournament {
+    fn add_round_part_1(&mut self, opponent_move: Move, column: Column) {
+        // X for Rock, Y for Paper, and Z for Scissors
+        let my_move = match column {
+            Column::X => Move::Rock,
+            Column::Y => Move::Paper,
+            Column::Z => Move::Scissors,
+        };
+
+        let round = Game {
+            opponent_move,
+            my_move,
+        };
+
+        self.rounds.push(round);
+    }
+
+    fn add_round_part_2(&mut self, opponent_move: Move, column: Column) {
+        // X means you need to lose,
+        // Y means you need to end the round in a draw,
+        // and Z means you need to win
+        let my_move = match column {
+            Column::X => opponent_move.find_move(Ordering::Less),
+            Column::Y => opponent_move.clone(),
+            Column::Z => opponent_move.find_move(Ordering::Greater),
+        };
+
+        let round = Game {
+            opponent_move,
+            my_move,
+        };
+
+        self.rounds.push(round);
+    }
+}
+
+fn parse_round(mut scanner: Scanner) -> Result<Round, ParseError> {
+    // A for Rock, B for Paper, and C for Scissors
+    let opponent_move = match scanner.char("one of A, B or C", |c| matches!(c, 'A'..='C'))? {
+        'A' => Move::Rock,
+        'B' => Move::Paper,
+        _ => Move::Scissors,
+    };
+    scanner.skip_whitespace();
+
+    let column = match scanner.char("one of X, Y or Z", |c| matches!(c, 'X'..='Z'))? {
+        'X' => Column::X,
+        'Y' => Column::Y,
+        _ => Column::Z,
+    };
+    scanner.end()?;
+
+    Ok((opponent_move, column))
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Round>, ParseError> {
+    scan_lines(input_string).map(parse_round).collect()
+}
+
+fn part_1(input_string: &str) -> Option<i32> {
+    let mut tournament = Tournament { rounds: vec![] };
+
+    for (opponent_move, column) in parse_input(input_string).ok()? {
+        tournament.add_round_part_1(opponent_move, column);
+    }
+
+    Some(tournament.get_total_scores())
+}
+
+fn part_2(input_string: &str) -> Option<i32> {
+    let mut tournament = Tournament { rounds: vec![] };
+
+    for (opponent_move, column) in parse_input(input_string).ok()? {
+        tournament.add_round_part_2(opponent_move, column);
+    }
+
+    Some(tournament.get_total_scores())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input_string = "A Y\nB X\nC Z\n";
+
+        assert_eq!(part_1(input_string), Some(15));
+        assert_eq!(part_2(input_string), Some(12));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("A Y\nB W\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected one of X, Y or Z, found \"W\""
+        );
+
+        let error = parse_input("D Y").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
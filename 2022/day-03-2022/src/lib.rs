@@ -0,0 +1,189 @@
+// https://adventofcode.com/2022/day/3
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::collections::HashSet;
+
+struct Rucksack {
+    all: Vec<char>,
+    first_compartment: Vec<char>,
+    second_compartment: Vec<char>,
+}
+
+impl Rucksack {
+    fn new(input: String) -> Self {
+        assert!(input.len().is_multiple_of(2));
+        let input_len = input.len();
+        let chars: Vec<char> = input.chars().collect();
+
+        let first_compartment: Vec<char> = chars.iter().take(input_len / 2).copied().collect();
+
+        let second_compartment: Vec<char> = chars.iter().skip(input_len / 2).copied().collect();
+
+        {
+            let first_string: String = first_compartment.iter().collect();
+            let second_string: String = second_compartment.iter().collect();
+            assert!(input == format!("{}{}", first_string, second_string));
+        };
+
+        Rucksack {
+            all: chars,
+            first_compartment,
+            second_compartment,
+        }
+    }
+
+    fn get_common_item(&self) -> Option<char> {
+        let first: HashSet<&char> = HashSet::from_iter(self.first_compartment.iter());
+        let second: HashSet<&char> = HashSet::from_iter(self.second_compartment.iter());
+        let common: HashSet<&char> = first.intersection(&second).copied().collect();
+
+        get_only_item(common)
+    }
+}
+
+// the item shared by both sides, if there is exactly one
+fn get_only_item(common: HashSet<&char>) -> Option<char> {
+    if common.len() != 1 {
+        return None;
+    }
+
+    common.into_iter().next().copied()
+}
+
+fn get_priority(x: char) -> u64 {
+    if x.is_ascii_lowercase() {
+        return (x as u64) - 96;
+    };
+
+    (x as u64) - 38
+}
+
+fn get_sum_of_priorities_part_1(inputs: Vec<String>) -> Option<u64> {
+    inputs
+        .iter()
+        .map(|input| -> Rucksack { Rucksack::new(input.trim().to_string()) })
+        .map(|x: Rucksack| -> Option<u64> {
+            let common_item: char = x.get_common_item()?;
+
+            Some(get_priority(common_item))
+        })
+        .sum()
+}
+
+fn get_sum_of_priorities_part_2(inputs: Vec<String>) -> Option<u64> {
+    let rucksacks: Vec<Rucksack> = inputs
+        .iter()
+        .map(|input| -> Rucksack { Rucksack::new(input.trim().to_string()) })
+        .collect();
+
+    if !rucksacks.len().is_multiple_of(3) {
+        return None;
+    }
+
+    let mut sum_of_priorities = 0;
+    for group in rucksacks.chunks(3) {
+        let first: HashSet<&char> = HashSet::from_iter(group[0].all.iter());
+        let second: HashSet<&char> = HashSet::from_iter(group[1].all.iter());
+        let third: HashSet<&char> = HashSet::from_iter(group[2].all.iter());
+
+        let first_and_second: HashSet<&char> = first.intersection(&second).copied().collect();
+
+        let common_item: HashSet<&char> = first_and_second.intersection(&third).copied().collect();
+
+        let common_item: char = get_only_item(common_item)?;
+
+        sum_of_priorities += get_priority(common_item);
+    }
+
+    Some(sum_of_priorities)
+}
+
+fn parse_rucksack(mut scanner: Scanner) -> Result<String, ParseError> {
+    let start = scanner;
+    let items = scanner.word("a list of items")?;
+
+    if let Some(offset) = items.find(|c: char| !c.is_ascii_alphabetic()) {
+        let mut scanner = start;
+        scanner.eat(&items[..offset]);
+        return Err(scanner.error("an item from a to z or A to Z"));
+    }
+
+    if !items.len().is_multiple_of(2) {
+        return Err(start.error("an even number of items"));
+    }
+
+    scanner.end()?;
+
+    Ok(items.to_string())
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<String>, ParseError> {
+    scan_lines(input_string).map(parse_rucksack).collect()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        get_sum_of_priorities_part_1(parse_input(input_string).ok()?)
+            .map(|sum_of_priorities| sum_of_priorities.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        get_sum_of_priorities_part_2(parse_input(input_string).ok()?)
+            .map(|sum_of_priorities| sum_of_priorities.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input_string = r###"
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+    "###
+        .trim();
+
+        let inputs: Vec<String> = input_string
+            .split_whitespace()
+            .map(|x| -> String { x.to_string() })
+            .collect();
+
+        let sum_of_priorities = get_sum_of_priorities_part_1(inputs.clone());
+
+        assert_eq!(sum_of_priorities, Some(157));
+
+        assert_eq!(get_sum_of_priorities_part_2(inputs), Some(70));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("vJrwpWtwJgWrhcsFMMfFFhFp\nabc\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected an even number of items, found \"abc\""
+        );
+
+        let error = parse_input("ab1d").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+}
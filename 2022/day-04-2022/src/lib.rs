@@ -0,0 +1,150 @@
+// https://adventofcode.com/2022/day/4
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+type Assignments = (i32, i32);
+
+// e.g. 2-4
+fn parse_assignment(scanner: &mut Scanner) -> Result<Assignments, ParseError> {
+    let start = scanner.integer()?;
+    scanner.literal("-")?;
+
+    let end_scanner = *scanner;
+    let end = scanner.integer()?;
+    if end < start {
+        return Err(end_scanner.error(format!("a section of at least {}", start)));
+    }
+
+    Ok((start, end))
+}
+
+#[derive(Debug)]
+struct Pairs {
+    assignments: (Assignments, Assignments),
+}
+
+impl Pairs {
+    // e.g. 2-4,6-8
+    fn parse(mut scanner: Scanner) -> Result<Self, ParseError> {
+        let first = parse_assignment(&mut scanner)?;
+        scanner.literal(",")?;
+        let second = parse_assignment(&mut scanner)?;
+        scanner.end()?;
+
+        Ok(Pairs {
+            assignments: (first, second),
+        })
+    }
+
+    fn part_1(&self) -> bool {
+        let (first, second) = self.assignments;
+        let (start_1, end_1) = first;
+        let (start_2, end_2) = second;
+
+        if start_2 <= start_1 && end_1 <= end_2 {
+            return true;
+        }
+
+        if start_1 <= start_2 && end_2 <= end_1 {
+            return true;
+        }
+
+        false
+    }
+
+    fn part_2(&self) -> bool {
+        let (first, second) = self.assignments;
+        let (start_1, end_1) = first;
+        let (start_2, end_2) = second;
+
+        if end_2 < start_1 {
+            return false;
+        }
+
+        if end_1 < start_2 {
+            return false;
+        }
+
+        true
+    }
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Pairs>, ParseError> {
+    scan_lines(input_string).map(Pairs::parse).collect()
+}
+
+fn part1(input_string: &str) -> Option<usize> {
+    let pairs = parse_input(input_string).ok()?;
+    Some(pairs.iter().filter(|pairs| pairs.part_1()).count())
+}
+
+fn part2(input_string: &str) -> Option<usize> {
+    let pairs = parse_input(input_string).ok()?;
+    Some(pairs.iter().filter(|pairs| pairs.part_2()).count())
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part2(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input_string = r###"
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+    "###
+        .trim();
+
+        assert_eq!(part1(input_string), Some(2));
+        assert_eq!(part2(input_string), Some(4));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("2-4,6-8\n2-x,4-5\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected an integer, found \"x\""
+        );
+
+        let error = parse_input("5-3,7-9").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected a section of at least 5, found \"3\""
+        );
+
+        let error = parse_input("2-4;6-8").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+
+        assert_eq!(part1("2-4,6-8,1-2"), None);
+    }
+}
@@ -0,0 +1,326 @@
+// https://adventofcode.com/2022/day/5
+
+use aoc_core::{ParseError, Scanner, Solution};
+
+struct Move {
+    amount: u32,
+    from_stack: u32,
+    to_stack: u32,
+}
+
+impl Move {
+    // e.g. move 1 from 2 to 1
+    fn parse(mut scanner: Scanner, num_of_stacks: usize) -> Result<Self, ParseError> {
+        let parse_stack = |scanner: &mut Scanner| -> Result<u32, ParseError> {
+            let start = *scanner;
+            let stack: u32 = scanner.integer()?;
+            if stack == 0 || stack as usize > num_of_stacks {
+                return Err(start.error(format!("a stack from 1 to {}", num_of_stacks)));
+            }
+            Ok(stack - 1)
+        };
+
+        scanner.literal("move ")?;
+        let amount: u32 = scanner.integer()?;
+        scanner.literal(" from ")?;
+        let from_stack = parse_stack(&mut scanner)?;
+        scanner.literal(" to ")?;
+
+        let start = scanner;
+        let to_stack = parse_stack(&mut scanner)?;
+        if to_stack == from_stack {
+            return Err(start.error(format!("a stack other than {}", from_stack + 1)));
+        }
+        scanner.end()?;
+
+        Ok(Move {
+            amount,
+            from_stack,
+            to_stack,
+        })
+    }
+}
+
+// the crates of a row of the stack diagram from left to right, e.g. "    [D]", where None is a
+// stack without a crate at this height; along with a scanner at each of them
+fn parse_crates(
+    mut scanner: Scanner,
+    num_of_stacks: usize,
+) -> Result<Vec<(Option<char>, Scanner)>, ParseError> {
+    let mut crates = vec![];
+
+    loop {
+        let start = scanner;
+
+        if crates.len() == num_of_stacks {
+            return Err(start.error("end of line"));
+        }
+
+        let label = if scanner.eat("[") {
+            let label = scanner.char("a crate", |c| c.is_ascii_uppercase())?;
+            scanner.literal("]")?;
+            Some(label)
+        } else if scanner.eat("   ") {
+            None
+        } else {
+            return Err(scanner.error("a crate, e.g. [A], or an empty space"));
+        };
+
+        crates.push((label, start));
+
+        if scanner.is_at_end() {
+            return Ok(crates);
+        }
+
+        scanner.literal(" ")?;
+    }
+}
+
+// the numbers of the stacks below the stack diagram, e.g. " 1   2   3"
+fn parse_num_of_stacks(mut scanner: Scanner) -> Result<usize, ParseError> {
+    let mut num_of_stacks = 0;
+
+    loop {
+        scanner.skip_whitespace();
+        if scanner.is_at_end() && num_of_stacks > 0 {
+            return Ok(num_of_stacks);
+        }
+
+        let start = scanner;
+        let stack: usize = scanner.integer()?;
+        if stack != num_of_stacks + 1 {
+            return Err(start.error(format!("stack {}", num_of_stacks + 1)));
+        }
+
+        num_of_stacks += 1;
+    }
+}
+
+struct Crane {
+    stacks: Vec<Vec<char>>,
+    instructions: Vec<Move>,
+}
+
+impl Crane {
+    // the stack diagram, and the moves after it
+    fn parse(input_string: &str) -> Result<Self, ParseError> {
+        // the leading whitespace of the stack diagram is significant
+        let mut lines = input_string
+            .lines()
+            .enumerate()
+            .map(|(index, line)| Scanner::new(index + 1, line.trim_end()))
+            .skip_while(|scanner| scanner.is_at_end());
+
+        let mut rows: Vec<Scanner> = vec![];
+
+        let num_of_stacks = loop {
+            let scanner = match lines.next() {
+                None => {
+                    let line_number = rows.last().map_or(0, |row| row.line_number());
+                    return Err(Scanner::new(line_number + 1, "")
+                        .error("a line numbering the stacks, e.g. 1   2   3"));
+                }
+                Some(scanner) => scanner,
+            };
+
+            if scanner
+                .rest()
+                .trim_start()
+                .starts_with(|c: char| c.is_ascii_digit())
+            {
+                break parse_num_of_stacks(scanner)?;
+            }
+
+            rows.push(scanner);
+        };
+
+        // stack the crates from the bottom row upwards
+        let mut stacks: Vec<Vec<char>> = vec![vec![]; num_of_stacks];
+        let mut heights: Vec<usize> = vec![0; num_of_stacks];
+
+        for (row_index, row) in rows.into_iter().rev().enumerate() {
+            for (stack, (label, scanner)) in
+                parse_crates(row, num_of_stacks)?.into_iter().enumerate()
+            {
+                if let Some(label) = label {
+                    if heights[stack] < row_index {
+                        return Err(scanner.error("a crate on top of another crate"));
+                    }
+
+                    stacks[stack].push(label);
+                    heights[stack] = row_index + 1;
+                }
+            }
+        }
+
+        let mut instructions = vec![];
+
+        for mut scanner in lines {
+            scanner.skip_whitespace();
+            if scanner.is_at_end() {
+                continue;
+            }
+
+            instructions.push(Move::parse(scanner, num_of_stacks)?);
+        }
+
+        Ok(Crane {
+            stacks,
+            instructions,
+        })
+    }
+
+    // None if a move takes more crates than there are on a stack,
+    // or if a stack is empty at the end
+    fn part_1(&self) -> Option<String> {
+        let mut stacks = self.stacks.clone();
+        for instruction in &self.instructions {
+            let mut from_stack = stacks[instruction.from_stack as usize].clone();
+            let mut to_stack = stacks[instruction.to_stack as usize].clone();
+            for _ in 1..=instruction.amount {
+                let item = from_stack.pop()?;
+                to_stack.push(item);
+            }
+            stacks[instruction.from_stack as usize] = from_stack;
+            stacks[instruction.to_stack as usize] = to_stack;
+        }
+
+        // message
+        stacks.iter().map(|stack| stack.last()).collect()
+    }
+
+    // None if a move takes more crates than there are on a stack,
+    // or if a stack is empty at the end
+    fn part_2(&self) -> Option<String> {
+        let mut stacks = self.stacks.clone();
+        for instruction in &self.instructions {
+            let mut from_stack = stacks[instruction.from_stack as usize].clone();
+            let mut to_stack = stacks[instruction.to_stack as usize].clone();
+
+            if instruction.amount as usize > from_stack.len() {
+                return None;
+            }
+
+            let mut items: Vec<char> = from_stack
+                .iter()
+                .rev()
+                .take(instruction.amount as usize)
+                .rev()
+                .copied()
+                .collect();
+
+            from_stack = from_stack
+                .iter()
+                .rev()
+                .skip(instruction.amount as usize)
+                .rev()
+                .copied()
+                .collect();
+
+            to_stack.append(&mut items);
+
+            stacks[instruction.from_stack as usize] = from_stack;
+            stacks[instruction.to_stack as usize] = to_stack;
+        }
+
+        // message
+        stacks.iter().map(|stack| stack.last()).collect()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(Crane::parse(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Crane::parse(input_string).ok()?.part_1()
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Crane::parse(input_string).ok()?.part_2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        // whitespace is important here
+        let input_string = r###"
+    [D]    
+[N] [C]    
+[Z] [M] [P]
+ 1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+"###
+        .to_string();
+
+        let crane = Crane::parse(&input_string).unwrap();
+        assert_eq!(crane.part_1(), Some("CMZ".to_string()));
+        assert_eq!(crane.part_2(), Some("MCD".to_string()));
+
+        // more crates are moved than there are on stack 1
+        let crane = Crane::parse(&input_string.replace("move 1 from 1 to 2", "move 9 from 1 to 2"))
+            .unwrap();
+        assert_eq!(crane.part_1(), None);
+        assert_eq!(crane.part_2(), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse_error = |input_string: &str| Crane::parse(input_string).err().unwrap();
+
+        let error = parse_error("[A] [b]\n 1   2\n\nmove 1 from 1 to 2");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 6: expected a crate, found \"b\""
+        );
+
+        let error = parse_error("[A] [B]\n 1   2\n\nmove 1 from 1 to 3");
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 18: expected a stack from 1 to 2, found \"3\""
+        );
+
+        let error = parse_error("[A] [B]\n 1   2\n\nmove 1 from 2 to 2");
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 18: expected a stack other than 2, found \"2\""
+        );
+
+        // a crate in the air
+        let error = parse_error("    [C]\n[A]    \n 1   2\n");
+        assert_eq!((error.line, error.column), (1, 5));
+
+        // more crates than stacks
+        let error = parse_error("[A] [B] [C]\n 1   2\n");
+        assert_eq!((error.line, error.column), (1, 9));
+
+        let error = parse_error(" 1   3\n");
+        assert_eq!((error.line, error.column), (1, 6));
+
+        let error = parse_error("[A] [B]\n");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected a line numbering the stacks, e.g. 1   2   3, found end of line"
+        );
+    }
+}
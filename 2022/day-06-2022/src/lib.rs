@@ -0,0 +1,135 @@
+// https://adventofcode.com/2022/day/6
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+fn marker_finder(input_string: String, marker_size: usize) -> Option<usize> {
+    let mut buffer: Vec<char> = vec![];
+    for (index, char) in input_string.chars().enumerate() {
+        // invariant: buffer contains up to 3 unique characters
+
+        if let Some(index) = buffer.iter().position(|&c| c == char) {
+            buffer = buffer.iter().skip(index + 1).copied().collect();
+        }
+
+        buffer.push(char);
+
+        if buffer.len() == marker_size {
+            return Some(index + 1);
+        }
+    }
+
+    None
+}
+
+fn part_1(input_string: String) -> Option<usize> {
+    marker_finder(input_string, 4)
+}
+
+fn part_2(input_string: String) -> Option<usize> {
+    marker_finder(input_string, 14)
+}
+
+// the datastream buffer, which is the only line of the puzzle input
+fn parse_input(input_string: &str) -> Result<&str, ParseError> {
+    let mut lines = scan_lines(input_string);
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+    let datastream = scanner.rest();
+
+    while !scanner.is_at_end() {
+        scanner.char("a lowercase letter", |c| c.is_ascii_lowercase())?;
+    }
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok(datastream)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?.to_string())?.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?.to_string())?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            part_1("mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string()),
+            Some(7)
+        );
+
+        assert_eq!(part_1("bvwbjplbgvbhsrlpgdmjqwftvncz".to_string()), Some(5));
+
+        assert_eq!(part_1("nppdvjthqldpwncqszvftbrmjlhg".to_string()), Some(6));
+
+        assert_eq!(
+            part_1("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string()),
+            Some(10)
+        );
+
+        assert_eq!(
+            part_1("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string()),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            part_2("mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string()),
+            Some(19)
+        );
+
+        assert_eq!(part_2("bvwbjplbgvbhsrlpgdmjqwftvncz".to_string()), Some(23));
+
+        assert_eq!(part_2("nppdvjthqldpwncqszvftbrmjlhg".to_string()), Some(23));
+
+        assert_eq!(
+            part_2("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string()),
+            Some(29)
+        );
+
+        assert_eq!(
+            part_2("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string()),
+            Some(26)
+        );
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("  bvwbjplbgvbhsr\n"), Ok("bvwbjplbgvbhsr"));
+
+        let error = parse_input("bvwb jplb").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected a lowercase letter, found \" \""
+        );
+
+        let error = parse_input("bvwb\njplb").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
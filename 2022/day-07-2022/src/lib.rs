@@ -0,0 +1,387 @@
+// https://adventofcode.com/2022/day/7
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::sync::RwLock;
+use std::sync::{Arc, Weak};
+
+#[derive(Debug, Clone)]
+enum Command<'a> {
+    List,
+    // the scanner points at the name of the directory, for reporting directories that do not exist
+    ChangeDirectory(&'a str, Scanner<'a>),
+}
+
+impl<'a> Command<'a> {
+    // e.g. $ cd a
+    fn parse(mut scanner: Scanner<'a>) -> Result<Self, ParseError> {
+        scanner.literal("$")?;
+        scanner.skip_whitespace();
+
+        let command = if scanner.eat("ls") {
+            Command::List
+        } else if scanner.eat("cd") {
+            scanner.skip_whitespace();
+            let argument = scanner;
+            let directory_name = scanner.word("a directory name")?;
+            Command::ChangeDirectory(directory_name, argument)
+        } else {
+            return Err(scanner.error("\"ls\" or \"cd\""));
+        };
+
+        scanner.end()?;
+
+        Ok(command)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Content {
+    File(File),
+    Directory(Arc<RwLock<Directory>>),
+}
+
+#[derive(Debug, Clone)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Directory {
+    name: String,
+    contents: Vec<Content>,
+    parent_dir: Option<Weak<RwLock<Directory>>>,
+}
+
+impl Directory {
+    fn create_filesystem() -> Self {
+        Directory {
+            name: "/".to_string(),
+            contents: vec![],
+            parent_dir: None,
+        }
+    }
+
+    fn create(name: String, parent_dir: Weak<RwLock<Directory>>) -> Self {
+        Directory {
+            name,
+            contents: vec![],
+            parent_dir: Some(parent_dir),
+        }
+    }
+
+    fn add_content(&mut self, content: Content) {
+        self.contents.push(content);
+    }
+
+    fn get_total_size(&self) -> usize {
+        self.contents
+            .iter()
+            .map(|content| match content {
+                Content::Directory(dir) => dir.read().unwrap().get_total_size(),
+                Content::File(file) => file.size,
+            })
+            .sum()
+    }
+
+    fn _print_debug(current_directory: Arc<RwLock<Directory>>, level: usize) {
+        let dir = current_directory.read().unwrap();
+        let dir_name = dir.name.clone();
+        let output = format!("{} (dir, size={})", dir_name, dir.get_total_size());
+        let padding = " ".repeat(level * 3);
+        println!("{}- {}", padding, output);
+
+        for content in dir.contents.iter() {
+            match content {
+                Content::Directory(dir) => {
+                    Self::_print_debug(dir.clone(), level + 1);
+                }
+                Content::File(file) => {
+                    let padding = " ".repeat((level + 1) * 3);
+                    let file_name = file.name.clone();
+                    let output = format!("{} (file, size={})", file_name, file.size);
+                    println!("{}- {}", padding, output);
+                }
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    fn print_debug(directory: Arc<RwLock<Directory>>) {
+        Self::_print_debug(directory, 0);
+    }
+}
+
+// directory names are not necessarily alphanumeric; e.g. ..
+fn directory_error(argument: Scanner, directory_name: &str, expected: &str) -> ParseError {
+    let mut error = argument.error(expected);
+    error.found = format!("{:?}", directory_name);
+    error
+}
+
+fn make_root_filesytem(input_string: &str) -> Result<Arc<RwLock<Directory>>, ParseError> {
+    let root_filesytem = Arc::new(RwLock::new(Directory::create_filesystem()));
+
+    let mut current_directory = root_filesytem.clone();
+
+    let mut parse_input_for_command = None;
+
+    for mut scanner in scan_lines(input_string) {
+        if scanner.rest().starts_with('$') {
+            parse_input_for_command = None;
+
+            // parse command
+            let parsed_command = Command::parse(scanner)?;
+
+            match parsed_command {
+                Command::ChangeDirectory(directory_name, argument) => match directory_name {
+                    "/" => {
+                        current_directory = root_filesytem.clone();
+                        continue;
+                    }
+                    ".." => {
+                        let parent_dir = { current_directory.read().unwrap().parent_dir.clone() };
+                        if let Some(parent_dir) = parent_dir {
+                            current_directory = parent_dir.upgrade().unwrap().clone();
+                            continue;
+                        }
+
+                        return Err(directory_error(
+                            argument,
+                            directory_name,
+                            "a directory other than .. at the root",
+                        ));
+                    }
+                    _ => {
+                        let needle = current_directory
+                            .read()
+                            .unwrap()
+                            .contents
+                            .iter()
+                            .find(|x| match x {
+                                Content::Directory(dir) => {
+                                    dir.read().unwrap().name == directory_name
+                                }
+                                _ => false,
+                            })
+                            .cloned();
+
+                        match needle {
+                            Some(Content::Directory(directory)) => {
+                                current_directory = directory;
+                            }
+                            _ => {
+                                return Err(directory_error(
+                                    argument,
+                                    directory_name,
+                                    "a directory that has been listed",
+                                ));
+                            }
+                        }
+                        continue;
+                    }
+                },
+                Command::List => parse_input_for_command = Some(parsed_command.clone()),
+            }
+
+            continue;
+        }
+
+        if !matches!(parse_input_for_command, Some(Command::List)) {
+            return Err(scanner.error("a command"));
+        }
+
+        // e.g. dir a, or 14848514 b.txt
+
+        let content = if scanner.eat("dir") {
+            scanner.skip_whitespace();
+            let name = scanner.word("a directory name")?;
+            let directory = Directory::create(name.to_string(), Arc::downgrade(&current_directory));
+
+            Content::Directory(Arc::new(RwLock::new(directory)))
+        } else {
+            let file_size: usize = scanner.integer()?;
+            scanner.skip_whitespace();
+            let name = scanner.word("a file name")?;
+
+            Content::File(File {
+                name: name.to_string(),
+                size: file_size,
+            })
+        };
+
+        scanner.end()?;
+
+        current_directory.write().unwrap().add_content(content);
+    }
+
+    Ok(root_filesytem)
+}
+
+fn part_1(root_filesytem: Arc<RwLock<Directory>>) -> usize {
+    // Directory::print_debug(root_filesytem.clone());
+
+    let mut to_visit = vec![root_filesytem];
+
+    let mut sum_of_sizes = 0;
+    loop {
+        if to_visit.is_empty() {
+            break;
+        }
+
+        let current_directory = to_visit.pop().unwrap();
+
+        let mut directories: Vec<_> = current_directory
+            .read()
+            .unwrap()
+            .contents
+            .iter()
+            .filter(|x| matches!(x, Content::Directory(_)))
+            .map(|x| match x.clone() {
+                Content::Directory(dir) => dir,
+                _ => unreachable!(),
+            })
+            .collect();
+        to_visit.append(&mut directories);
+
+        let total_size = current_directory.read().unwrap().get_total_size();
+        if total_size <= 100000 {
+            sum_of_sizes += total_size;
+        }
+    }
+
+    sum_of_sizes
+}
+
+fn part_2(root_filesytem: Arc<RwLock<Directory>>) -> usize {
+    let mut to_visit = vec![root_filesytem.clone()];
+
+    let root_dir_size = root_filesytem.read().unwrap().get_total_size();
+    let total_disk_size_available: usize = 70_000_000;
+    let current_unused_space = total_disk_size_available - root_dir_size;
+    let required_unused_space: usize = 30_000_000;
+
+    assert!(root_dir_size <= total_disk_size_available);
+
+    let mut candidate_directory_size = root_filesytem.read().unwrap().get_total_size();
+    loop {
+        if to_visit.is_empty() {
+            break;
+        }
+
+        let current_directory = to_visit.pop().unwrap();
+
+        let mut directories: Vec<_> = current_directory
+            .read()
+            .unwrap()
+            .contents
+            .iter()
+            .filter(|x| matches!(x, Content::Directory(_)))
+            .map(|x| match x.clone() {
+                Content::Directory(dir) => dir,
+                _ => unreachable!(),
+            })
+            .collect();
+        to_visit.append(&mut directories);
+
+        let total_size = current_directory.read().unwrap().get_total_size();
+        if total_size < candidate_directory_size {
+            let candidate_unused_space = current_unused_space + candidate_directory_size;
+            let next_unused_space = current_unused_space + total_size;
+            if next_unused_space < candidate_unused_space
+                && next_unused_space > required_unused_space
+            {
+                candidate_directory_size = total_size;
+            }
+        }
+    }
+
+    assert!((current_unused_space + candidate_directory_size) > required_unused_space);
+    candidate_directory_size
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(make_root_filesytem(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(make_root_filesytem(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(make_root_filesytem(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let input_string = r###"
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+"###;
+
+        assert_eq!(part_1(make_root_filesytem(input_string).unwrap()), 95437);
+        assert_eq!(part_2(make_root_filesytem(input_string).unwrap()), 24933642);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = make_root_filesytem("$ cd /\n$ dir").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected \"ls\" or \"cd\", found \"dir\""
+        );
+
+        let error = make_root_filesytem("$ cd /\n$ ls\nabc b.txt").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.expected, "an integer");
+
+        let error = make_root_filesytem("$ cd /\n$ ls\ndir a\n$ cd b").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 6));
+        assert_eq!(error.expected, "a directory that has been listed");
+        assert_eq!(error.found, "\"b\"");
+
+        let error = make_root_filesytem("$ cd ..").unwrap_err();
+        assert_eq!(error.found, "\"..\"");
+
+        let error = make_root_filesytem("$ cd /\ndir a").unwrap_err();
+        assert_eq!(error.expected, "a command");
+    }
+}
@@ -0,0 +1,200 @@
+// https://adventofcode.com/2022/day/8
+
+use aoc_core::{ParseError, Solution};
+use aoc_grid::{Coordinate, Grid, Transitions};
+use std::collections::HashSet;
+
+type Height = i32;
+#[derive(Debug, Clone)]
+struct Patch {
+    area: Grid<Height>,
+    max_y: i32,
+    max_x: i32,
+}
+
+impl Patch {
+    fn new(area: Grid<Height>) -> Self {
+        Patch {
+            max_y: area.max_y(),
+            max_x: area.max_x(),
+            area,
+        }
+    }
+
+    fn get_viewing_distance<F>(&self, coord: Coordinate, generate_coord: F) -> usize
+    where
+        F: Fn(Coordinate) -> Coordinate,
+    {
+        let mut viewing_distance = 0;
+        let mut current_viewing_coord = generate_coord(coord);
+        let current_height = self.area[coord];
+        loop {
+            let tree = self.area.get(current_viewing_coord);
+            if tree.is_none() {
+                break;
+            }
+            viewing_distance += 1;
+            let tree_height = *tree.unwrap();
+            if tree_height >= current_height {
+                break;
+            }
+
+            current_viewing_coord = generate_coord(current_viewing_coord);
+        }
+
+        viewing_distance
+    }
+}
+
+fn generate_patch(input_string: String) -> Patch {
+    let input_string: Vec<&str> = input_string
+        .trim()
+        .lines()
+        .map(|line| line.trim())
+        .collect();
+    let area = Grid::parse(&input_string.join("\n"), |_position, height| {
+        height.to_digit(10).unwrap() as Height
+    })
+    .unwrap();
+
+    Patch::new(area)
+}
+
+fn part_1(input_string: String) -> usize {
+    let patch = generate_patch(input_string);
+
+    let mut visible_trees: HashSet<Coordinate> = HashSet::new();
+
+    let mut test_visibility = |coord: Coordinate, tallest_tree: &mut Option<Height>| {
+        let height = patch.area[coord];
+        match tallest_tree {
+            None => {
+                visible_trees.insert(coord);
+                *tallest_tree = Some(height);
+            }
+            Some(current_height) => {
+                if height > *current_height {
+                    visible_trees.insert(coord);
+                    *tallest_tree = Some(height);
+                }
+            }
+        };
+    };
+
+    {
+        // scan from left to right
+        let mut tallest_tree: Option<Height>;
+        for y in 0..=patch.max_y {
+            tallest_tree = None;
+            for x in 0..=patch.max_x {
+                let coord: Coordinate = (x, y);
+                test_visibility(coord, &mut tallest_tree);
+            }
+        }
+    }
+
+    {
+        // scan from right to left
+        let mut tallest_tree: Option<Height>;
+        for y in 0..=patch.max_y {
+            tallest_tree = None;
+            for x in (0..=patch.max_x).rev() {
+                let coord: Coordinate = (x, y);
+                test_visibility(coord, &mut tallest_tree);
+            }
+        }
+    }
+
+    {
+        // scan from up to down
+        let mut tallest_tree: Option<Height>;
+        for x in 0..=patch.max_x {
+            tallest_tree = None;
+            for y in 0..=patch.max_y {
+                let coord: Coordinate = (x, y);
+                test_visibility(coord, &mut tallest_tree);
+            }
+        }
+    }
+
+    {
+        // scan from down to up
+        let mut tallest_tree: Option<Height>;
+        for x in 0..=patch.max_x {
+            tallest_tree = None;
+            for y in (0..=patch.max_y).rev() {
+                let coord: Coordinate = (x, y);
+                test_visibility(coord, &mut tallest_tree);
+            }
+        }
+    }
+
+    visible_trees.len()
+}
+
+fn part_2(input_string: String) -> usize {
+    let patch = generate_patch(input_string);
+
+    let mut best_scenic_score = 0;
+
+    for y in 0..=patch.max_y {
+        for x in 0..=patch.max_x {
+            let coord: Coordinate = (x, y);
+            let up = patch.get_viewing_distance(coord, |x| x.up());
+            let left = patch.get_viewing_distance(coord, |x| x.left());
+            let right = patch.get_viewing_distance(coord, |x| x.right());
+            let down = patch.get_viewing_distance(coord, |x| x.down());
+            let scenic_score = up * left * right * down;
+
+            if scenic_score > best_scenic_score {
+                best_scenic_score = scenic_score;
+            }
+        }
+    }
+    best_scenic_score
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(generate_patch(input_string.to_string()));
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(input_string.to_string()).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(input_string.to_string()).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input_string = r###"
+30373
+25512
+65332
+33549
+35390
+"###
+        .to_string();
+
+        assert_eq!(part_1(input_string.to_string()), 21);
+        assert_eq!(part_2(input_string.to_string()), 8);
+    }
+}
@@ -0,0 +1,273 @@
+// https://adventofcode.com/2022/day/9
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use aoc_grid::{Coordinate, Transitions};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone)]
+enum Move {
+    Up(u32),
+    Down(u32),
+    Left(u32),
+    Right(u32),
+}
+
+#[derive(Debug, Clone)]
+struct Rope {
+    knots: Vec<Coordinate>,
+    tail_visits: HashSet<Coordinate>,
+}
+
+impl Rope {
+    fn new(num_of_body_knots: u32) -> Self {
+        let starting_point: Coordinate = (0, 0);
+        let tail_visits = {
+            let mut tail_visits = HashSet::new();
+            tail_visits.insert(starting_point);
+            tail_visits
+        };
+
+        let mut knots: Vec<Coordinate> = vec![(0, 0), (0, 0)];
+
+        for _ in 1..=num_of_body_knots {
+            knots.push(starting_point);
+        }
+
+        Rope { knots, tail_visits }
+    }
+
+    fn parse_move(&mut self, parsed_move_step: Move) {
+        match parsed_move_step {
+            Move::Up(direction_length) => {
+                for _ in 1..=direction_length {
+                    self.knots[0] = self.knots[0].up();
+                    self.update_body_positions();
+                }
+            }
+            Move::Down(direction_length) => {
+                for _ in 1..=direction_length {
+                    self.knots[0] = self.knots[0].down();
+                    self.update_body_positions();
+                }
+            }
+            Move::Left(direction_length) => {
+                for _ in 1..=direction_length {
+                    self.knots[0] = self.knots[0].left();
+                    self.update_body_positions();
+                }
+            }
+            Move::Right(direction_length) => {
+                for _ in 1..=direction_length {
+                    self.knots[0] = self.knots[0].right();
+                    self.update_body_positions();
+                }
+            }
+        }
+    }
+
+    fn update_body_positions(&mut self) {
+        let num_of_knots = self.knots.len();
+        for index in 1..=(num_of_knots - 1) {
+            self.update_knot_position(index);
+        }
+    }
+
+    fn update_knot_position(&mut self, index_of_knot: usize) {
+        assert!(index_of_knot > 0);
+
+        let is_last_knot = index_of_knot == (self.knots.len() - 1);
+
+        let prev_knot = self.knots[index_of_knot - 1];
+        let current_knot = &mut self.knots[index_of_knot];
+
+        let (prev_knot_x, prev_knot_y) = prev_knot;
+        let (current_knot_x, current_knot_y) = *current_knot;
+
+        // Tail must always be within two steps directly up, down, left, or right away from head.
+        let x_distance = (prev_knot_x - current_knot_x).abs();
+        let y_distance = (prev_knot_y - current_knot_y).abs();
+        assert!(x_distance <= 2);
+        assert!(y_distance <= 2);
+        let same_row = prev_knot_y == current_knot_y;
+        let same_col = prev_knot_x == current_knot_x;
+        let head_and_tail_touching = x_distance == 1 && y_distance == 1;
+
+        // If the head is ever two steps directly up, down, left, or right from the tail, the tail must also move one
+        // step in that direction so it remains close enough
+        if x_distance == 2 && same_row {
+            if prev_knot_x > current_knot_x {
+                *current_knot = current_knot.right();
+            } else {
+                *current_knot = current_knot.left();
+            }
+        } else if y_distance == 2 && same_col {
+            if prev_knot_y > current_knot_y {
+                *current_knot = current_knot.down();
+            } else {
+                *current_knot = current_knot.up();
+            }
+
+        // if the head and tail aren't touching and aren't in the same row or column, the tail always moves one step
+        // diagonally to keep up
+        } else if !head_and_tail_touching && !same_row && !same_col {
+            if prev_knot_x > current_knot_x {
+                *current_knot = current_knot.right();
+            } else {
+                *current_knot = current_knot.left();
+            }
+
+            if prev_knot_y > current_knot_y {
+                *current_knot = current_knot.down();
+            } else {
+                *current_knot = current_knot.up();
+            }
+        }
+
+        if is_last_knot {
+            self.tail_visits.insert(*current_knot);
+        }
+    }
+}
+
+// e.g. R 4
+fn parse_move(mut scanner: Scanner) -> Result<Move, ParseError> {
+    let direction = scanner.char("one of U, D, L or R", |c| "UDLR".contains(c))?;
+    scanner.literal(" ")?;
+    scanner.skip_whitespace();
+
+    let start = scanner;
+    let direction_length: u32 = scanner.integer()?;
+    if direction_length == 0 {
+        return Err(start.error("at least 1 step"));
+    }
+    scanner.end()?;
+
+    let parsed_move_step = match direction {
+        'U' => Move::Up(direction_length),
+        'D' => Move::Down(direction_length),
+        'L' => Move::Left(direction_length),
+        _ => Move::Right(direction_length),
+    };
+
+    Ok(parsed_move_step)
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Move>, ParseError> {
+    scan_lines(input_string).map(parse_move).collect()
+}
+
+fn part_1(moves: Vec<Move>) -> usize {
+    let mut rope = Rope::new(0);
+
+    for parsed_move_step in moves {
+        rope.parse_move(parsed_move_step);
+    }
+
+    rope.tail_visits.len()
+}
+
+fn part_2(moves: Vec<Move>) -> usize {
+    let mut rope = Rope::new(8);
+
+    for parsed_move_step in moves {
+        rope.parse_move(parsed_move_step);
+    }
+
+    rope.tail_visits.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn year(&self) -> u16 {
+        2022
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input_string = r###"
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"###
+        .to_string();
+
+        assert_eq!(part_1(parse_input(&input_string).unwrap()), 13);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input_string = r###"
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+"###
+        .to_string();
+
+        assert_eq!(part_2(parse_input(&input_string).unwrap()), 1);
+
+        let input_string = r###"
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+"###
+        .to_string();
+
+        assert_eq!(part_2(parse_input(&input_string).unwrap()), 36);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("R 4\nX 4\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected one of U, D, L or R, found \"X\""
+        );
+
+        let error = parse_input("R 0").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected at least 1 step, found \"0\""
+        );
+
+        let error = parse_input("R 4\nU four").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+}
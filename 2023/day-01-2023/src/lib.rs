@@ -0,0 +1,178 @@
+// https://adventofcode.com/2023/day/1
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+// a line of the calibration document, of lowercase letters and digits
+fn parse_line(mut scanner: Scanner) -> Result<String, ParseError> {
+    let mut line = String::new();
+
+    while !scanner.is_at_end() {
+        line.push(scanner.char("a lowercase letter or a digit", |c| {
+            c.is_ascii_lowercase() || c.is_ascii_digit()
+        })?);
+    }
+
+    Ok(line)
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<String>, ParseError> {
+    scan_lines(input_string).map(parse_line).collect()
+}
+
+fn part_1(inputs: &[String]) -> i32 {
+    let mut numbers: Vec<i32> = vec![];
+
+    for input in inputs {
+        let digits: String = input.chars().filter(|c| c.is_ascii_digit()).collect();
+        let digits: Vec<char> = digits.chars().collect();
+
+        if digits.is_empty() {
+            continue;
+        }
+        let first_calibration_value = digits.first().unwrap().to_digit(10).unwrap() as i32;
+        let second_calibration_value = digits.last().unwrap().to_digit(10).unwrap() as i32;
+        let calibration_value = format!("{}{}", first_calibration_value, second_calibration_value);
+        let number = calibration_value.parse::<i32>().unwrap();
+        numbers.push(number);
+    }
+
+    numbers.into_iter().sum()
+}
+
+fn parse_number_word(chars: &[char]) -> Option<(i32, i32)> {
+    let number_words = vec![
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    for (index, number_word) in number_words.into_iter().enumerate() {
+        let number_word_length = number_word.len();
+        let number_word = number_word.chars().collect::<Vec<char>>();
+        if chars.starts_with(&number_word) {
+            let number = index + 1;
+            return Some((number as i32, number_word_length as i32));
+        }
+    }
+
+    None
+}
+
+fn part_2(inputs: &[String]) -> i32 {
+    let mut numbers: Vec<i32> = vec![];
+
+    for input in inputs {
+        let mut chars: Vec<char> = input.chars().collect();
+
+        let mut digits: Vec<i32> = vec![];
+
+        loop {
+            if chars.is_empty() {
+                break;
+            }
+
+            let first_char = chars.first().unwrap();
+            if first_char.is_ascii_digit() {
+                let number = first_char.to_digit(10).unwrap() as i32;
+                digits.push(number);
+                chars.remove(0);
+                continue;
+            }
+
+            let result = parse_number_word(&chars);
+            if result.is_none() {
+                chars.remove(0);
+                continue;
+            }
+
+            let (number, number_word_length) = result.unwrap();
+            digits.push(number);
+            // chars.drain(0..number_word_length as usize);
+            // Preserve the last character of the number word to cover cases such as eighthree and sevenine.
+            // The instructions doesn't make this clear.
+            chars.drain(0..(number_word_length - 1) as usize);
+        }
+
+        if digits.is_empty() {
+            continue;
+        }
+
+        let first_calibration_value = digits.first().unwrap();
+        let second_calibration_value = digits.last().unwrap();
+        let calibration_value = format!("{}{}", first_calibration_value, second_calibration_value);
+        let number = calibration_value.parse::<i32>().unwrap();
+        numbers.push(number);
+    }
+
+    numbers.into_iter().sum()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(&parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(&parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_1() {
+        let input_string = r###"
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+"###;
+
+        assert_eq!(part_1(&parse_input(input_string).unwrap()), 142);
+
+        let input_string = r###"
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+"###;
+
+        assert_eq!(part_2(&parse_input(input_string).unwrap()), 281);
+
+        let input_string = r###"
+eighthree
+sevenine
+"###;
+
+        assert_eq!(part_2(&parse_input(input_string).unwrap()), 83 + 79);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("1abc2\npqr3 stu8\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected a lowercase letter or a digit, found \" \""
+        );
+
+        let error = parse_input("treb7uCHet").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+    }
+}
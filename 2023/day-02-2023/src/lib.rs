@@ -0,0 +1,197 @@
+// https://adventofcode.com/2023/day/2
+
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+#[derive(Debug)]
+struct Revealed {
+    red_cubes: i32,
+    blue_cubes: i32,
+    green_cubes: i32,
+}
+#[derive(Debug)]
+struct Game {
+    id: i32,
+    reveals: Vec<Revealed>,
+}
+
+impl Game {
+    fn is_valid(&self, max_red_cubes: i32, max_blue_cubes: i32, max_green_cubes: i32) -> bool {
+        for reveal in &self.reveals {
+            if !(reveal.red_cubes <= max_red_cubes
+                && reveal.blue_cubes <= max_blue_cubes
+                && reveal.green_cubes <= max_green_cubes)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn fewest_cubes(&self) -> (i32, i32, i32) {
+        let mut max_red_cubes = 0;
+        let mut max_blue_cubes = 0;
+        let mut max_green_cubes = 0;
+        for reveal in &self.reveals {
+            if reveal.red_cubes > max_red_cubes {
+                max_red_cubes = reveal.red_cubes;
+            }
+            if reveal.blue_cubes > max_blue_cubes {
+                max_blue_cubes = reveal.blue_cubes;
+            }
+            if reveal.green_cubes > max_green_cubes {
+                max_green_cubes = reveal.green_cubes;
+            }
+        }
+
+        (max_red_cubes, max_blue_cubes, max_green_cubes)
+    }
+
+    fn power_of_min_set(&self) -> i32 {
+        let (max_red_cubes, max_blue_cubes, max_green_cubes) = self.fewest_cubes();
+
+        max_red_cubes * max_blue_cubes * max_green_cubes
+    }
+}
+
+fn parse_reveal(scanner: &mut Scanner) -> Result<Revealed, ParseError> {
+    let mut red_cubes = 0;
+    let mut blue_cubes = 0;
+    let mut green_cubes = 0;
+
+    loop {
+        scanner.skip_whitespace();
+        let start = *scanner;
+        let num_of_cubes: i32 = scanner.integer()?;
+        if num_of_cubes < 0 {
+            return Err(start.error("a number of cubes"));
+        }
+        scanner.skip_whitespace();
+
+        if scanner.eat("red") {
+            red_cubes = num_of_cubes;
+        } else if scanner.eat("blue") {
+            blue_cubes = num_of_cubes;
+        } else if scanner.eat("green") {
+            green_cubes = num_of_cubes;
+        } else {
+            return Err(scanner.error("one of red, blue or green"));
+        }
+
+        scanner.skip_whitespace();
+        if !scanner.eat(",") {
+            break;
+        }
+    }
+
+    Ok(Revealed {
+        red_cubes,
+        blue_cubes,
+        green_cubes,
+    })
+}
+
+fn parse_game(mut scanner: Scanner) -> Result<Game, ParseError> {
+    scanner.literal("Game")?;
+    scanner.skip_whitespace();
+    let game_id: i32 = scanner.integer()?;
+    scanner.literal(":")?;
+
+    let mut reveals: Vec<Revealed> = vec![];
+    loop {
+        reveals.push(parse_reveal(&mut scanner)?);
+
+        if !scanner.eat(";") {
+            break;
+        }
+    }
+    scanner.end()?;
+
+    Ok(Game {
+        id: game_id,
+        reveals,
+    })
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Game>, ParseError> {
+    scan_lines(input_string).map(parse_game).collect()
+}
+
+fn part_1(input_string: &str) -> Option<i32> {
+    let games = parse_input(input_string).ok()?;
+
+    let max_red_cubes = 12;
+    let max_blue_cubes = 14;
+    let max_green_cubes = 13;
+
+    Some(
+        games
+            .into_iter()
+            .filter(|x| x.is_valid(max_red_cubes, max_blue_cubes, max_green_cubes))
+            .map(|x| x.id)
+            .sum(),
+    )
+}
+
+fn part_2(input_string: &str) -> Option<i32> {
+    let games = parse_input(input_string).ok()?;
+
+    Some(games.into_iter().map(|x| x.power_of_min_set()).sum())
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_2() {
+        let input_string = r###"
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"###;
+
+        assert_eq!(part_1(input_string), Some(8));
+
+        assert_eq!(part_2(input_string), Some(2286));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("Game 1: 3 blue, 4 red\nGame 2: 1 blue; 2 purple\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 19: expected one of red, blue or green, found \"purple\""
+        );
+
+        let error = parse_input("Game 1: 3 blue 4 red").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 16));
+    }
+}
@@ -0,0 +1,591 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+};
+
+#[derive(Debug, Clone)]
+struct CategoryRange {
+    category: String,
+    range_start: i64,
+    range_end: i64,
+    range_length: i64,
+}
+
+impl CategoryRange {
+    fn is_valid(&self) -> bool {
+        let proper_range = self.range_start <= self.range_end;
+        let expected_length = self.range_end - self.range_start + 1;
+        let proper_length = self.range_length == expected_length && expected_length > 0;
+
+        proper_range && proper_length
+    }
+
+    fn is_in_source_range(&self, category_range: &CategoryRange) -> bool {
+        if self.range_start < category_range.range_start {
+            return false;
+        }
+
+        if self.range_end > category_range.range_end {
+            return false;
+        }
+
+        true
+    }
+}
+
+#[derive(Debug)]
+enum MappingResult {
+    Overlap(CategoryRange),
+    NoOverlap(CategoryRange),
+}
+
+#[derive(Debug)]
+struct Range {
+    destination_range_start: i64,
+    source_range_start: i64,
+    range_length: i64,
+}
+
+impl Range {
+    fn is_in_source_range(&self, source: i64) -> bool {
+        if source < self.source_range_start {
+            return false;
+        }
+        // invariant: source_range_end - self.source_range_start + 1 = self.range_length
+        let source_range_end = self.source_range_start + self.range_length - 1;
+        if source > source_range_end {
+            return false;
+        }
+        true
+    }
+
+    fn get_destination(&self, source: i64) -> i64 {
+        if !self.is_in_source_range(source) {
+            // Any source numbers that aren't mapped correspond to the same destination number.
+            return source;
+        }
+
+        let zero_indexed_source = source - self.source_range_start;
+
+        self.destination_range_start + zero_indexed_source
+    }
+
+    fn get_destination_ranges(
+        &self,
+        destination_category: String,
+        input_range: CategoryRange,
+    ) -> Vec<MappingResult> {
+        assert!(input_range.category != destination_category);
+        let mut mapping_results: Vec<MappingResult> = Vec::new();
+
+        // check range overlap
+        let source_range_end = self.source_range_start + self.range_length - 1;
+        let has_overlap = self.source_range_start <= input_range.range_end
+            && input_range.range_start <= source_range_end;
+
+        if has_overlap {
+            let new_range_start = cmp::min(self.source_range_start, input_range.range_start);
+
+            let new_range_end = cmp::max(source_range_end, input_range.range_end);
+
+            let destination_overlap_range_start =
+                cmp::max(self.source_range_start, input_range.range_start);
+            let destination_overlap_range_end = cmp::min(source_range_end, input_range.range_end);
+
+            let left_non_overlap = CategoryRange {
+                category: destination_category.clone(),
+                range_start: new_range_start,
+                range_end: destination_overlap_range_start - 1,
+                range_length: destination_overlap_range_start - new_range_start,
+            };
+
+            let overlap = CategoryRange {
+                category: destination_category.clone(),
+                // range_start: self.get_destination(destination_overlap_range_start),
+                // range_end: self.get_destination(destination_overlap_range_end),
+                range_start: destination_overlap_range_start,
+                range_end: destination_overlap_range_end,
+                range_length: destination_overlap_range_end - destination_overlap_range_start + 1,
+            };
+
+            let right_non_overlap = CategoryRange {
+                category: destination_category.clone(),
+                range_start: destination_overlap_range_end + 1,
+                range_end: new_range_end,
+                range_length: new_range_end - destination_overlap_range_end,
+            };
+
+            if left_non_overlap.is_valid() && left_non_overlap.is_in_source_range(&input_range) {
+                mapping_results.push(MappingResult::NoOverlap(left_non_overlap));
+            }
+            if overlap.is_valid() {
+                let mut overlap = overlap;
+                assert!(overlap.is_in_source_range(&input_range));
+
+                overlap.range_start = self.get_destination(destination_overlap_range_start);
+                overlap.range_end = self.get_destination(destination_overlap_range_end);
+
+                mapping_results.push(MappingResult::Overlap(overlap));
+            }
+            if right_non_overlap.is_valid() && right_non_overlap.is_in_source_range(&input_range) {
+                mapping_results.push(MappingResult::NoOverlap(right_non_overlap));
+            }
+        } else {
+            // no overlap
+            // let destination_range = CategoryRange {
+            //     category: destination_category.clone(),
+            //     range_start: input_range.range_start,
+            //     range_end: input_range.range_end,
+            //     range_length: input_range.range_length,
+            // };
+
+            // mapping_results.push(MappingResult::NoOverlap(destination_range));
+        }
+
+        mapping_results
+    }
+}
+
+#[derive(Debug)]
+struct Mapping {
+    source_category: String,
+    destination_category: String,
+    ranges: Vec<Range>,
+}
+
+impl Mapping {
+    fn get_destination(&self, source: i64) -> i64 {
+        for range in self.ranges.iter() {
+            if range.is_in_source_range(source) {
+                return range.get_destination(source);
+            }
+        }
+
+        // Any source numbers that aren't mapped correspond to the same destination number.
+        source
+    }
+
+    fn get_destination_ranges(&self, source_ranges: &[CategoryRange]) -> Vec<CategoryRange> {
+        let mut destination_ranges: Vec<CategoryRange> = Vec::new();
+        let mut source_ranges: Vec<CategoryRange> = source_ranges.to_owned();
+
+        'source_ranges_loop: loop {
+            if source_ranges.is_empty() {
+                break;
+            }
+
+            let source_range = source_ranges.remove(0);
+
+            assert!(source_range.category == self.source_category);
+
+            'ranges_for_loop: for range in self.ranges.iter() {
+                let mapping_result = range.get_destination_ranges(
+                    self.destination_category.clone(),
+                    source_range.clone(),
+                );
+
+                if mapping_result.is_empty() {
+                    // no overlap found. try to find an overlap in the next range in self.ranges.
+                    continue 'ranges_for_loop;
+                }
+
+                if mapping_result.len() == 1 {
+                    match &mapping_result[0] {
+                        MappingResult::Overlap(range) => {
+                            destination_ranges.push(range.clone());
+                            continue 'source_ranges_loop;
+                        }
+                        MappingResult::NoOverlap(_range) => {
+                            continue 'ranges_for_loop;
+                        }
+                    }
+                }
+
+                assert!(mapping_result.len() <= 3);
+                let has_overlap = mapping_result.iter().any(|x| match x {
+                    MappingResult::Overlap(_) => true,
+                    MappingResult::NoOverlap(_) => false,
+                });
+                assert!(has_overlap);
+
+                for result in mapping_result.into_iter() {
+                    match result {
+                        MappingResult::Overlap(range) => {
+                            destination_ranges.push(range);
+                        }
+                        MappingResult::NoOverlap(range) => {
+                            let mut range = range;
+                            range.category = self.source_category.clone();
+                            source_ranges.push(range);
+                        }
+                    }
+                }
+                continue 'source_ranges_loop;
+            }
+
+            // Any source numbers that aren't mapped correspond to the same destination number.
+            let range = CategoryRange {
+                category: self.destination_category.clone(),
+                range_start: source_range.range_start,
+                range_end: source_range.range_end,
+                range_length: source_range.range_length,
+            };
+            destination_ranges.push(range);
+        }
+
+        destination_ranges
+    }
+}
+
+struct Almanac {
+    seeds: Vec<i64>,
+    // the maps by their source category
+    maps: HashMap<String, Mapping>,
+}
+
+// the numbers of the almanac fit in an u32
+fn parse_number(scanner: &mut Scanner) -> Result<i64, ParseError> {
+    let number: u32 = scanner.integer()?;
+    Ok(number as i64)
+}
+
+// e.g. seeds: 79 14 55 13
+fn parse_seeds(mut scanner: Scanner) -> Result<Vec<i64>, ParseError> {
+    scanner.literal("seeds:")?;
+
+    let mut seeds = vec![];
+
+    loop {
+        scanner.skip_whitespace();
+        if scanner.is_at_end() && !seeds.is_empty() {
+            return Ok(seeds);
+        }
+
+        seeds.push(parse_number(&mut scanner)?);
+    }
+}
+
+// e.g. fertilizer
+fn parse_category(scanner: &mut Scanner) -> Result<String, ParseError> {
+    let mut category = String::new();
+    category.push(scanner.char("a category", |c| c.is_ascii_lowercase())?);
+
+    while scanner.rest().starts_with(|c: char| c.is_ascii_lowercase()) {
+        category.push(scanner.char("a category", |c| c.is_ascii_lowercase())?);
+    }
+
+    Ok(category)
+}
+
+// e.g. seed-to-soil map:
+fn parse_mapping(mut scanner: Scanner) -> Result<Mapping, ParseError> {
+    let source_category = parse_category(&mut scanner)?;
+    scanner.literal("-to-")?;
+    let destination_category = parse_category(&mut scanner)?;
+    scanner.literal(" map:")?;
+    scanner.end()?;
+
+    Ok(Mapping {
+        source_category,
+        destination_category,
+        ranges: Vec::new(),
+    })
+}
+
+// e.g. 50 98 2
+fn parse_range(mut scanner: Scanner) -> Result<Range, ParseError> {
+    let destination_range_start = parse_number(&mut scanner)?;
+    scanner.literal(" ")?;
+    let source_range_start = parse_number(&mut scanner)?;
+    scanner.literal(" ")?;
+
+    let start = scanner;
+    let range_length = parse_number(&mut scanner)?;
+    if range_length == 0 {
+        return Err(start.error("a range length of at least 1"));
+    }
+    scanner.end()?;
+
+    Ok(Range {
+        destination_range_start,
+        source_range_start,
+        range_length,
+    })
+}
+
+// The seeds on the first line, followed by the maps; which lead from the seed category to the
+// location category.
+fn parse_input(input_string: &str) -> Result<Almanac, ParseError> {
+    let mut lines = scan_lines(input_string);
+
+    let seeds = parse_seeds(lines.next().unwrap_or_else(|| Scanner::new(1, "")))?;
+
+    let mut maps: HashMap<String, Mapping> = HashMap::new();
+    // the scanner at the header of each map
+    let mut headers: HashMap<String, Scanner> = HashMap::new();
+    let mut current_map: Option<Mapping> = None;
+    let mut last_line_number = 1;
+
+    for scanner in lines {
+        last_line_number = scanner.line_number();
+
+        if scanner.rest().starts_with(|c: char| c.is_ascii_digit()) {
+            match current_map.as_mut() {
+                None => return Err(scanner.error("a map, e.g. seed-to-soil map:")),
+                Some(map) => map.ranges.push(parse_range(scanner)?),
+            }
+            continue;
+        }
+
+        let map = parse_mapping(scanner)?;
+        if headers.contains_key(&map.source_category) {
+            return Err(scanner.error(format!(
+                "a map from a category other than {}",
+                map.source_category
+            )));
+        }
+        headers.insert(map.source_category.clone(), scanner);
+
+        if let Some(map) = current_map.replace(map) {
+            maps.insert(map.source_category.clone(), map);
+        }
+    }
+
+    if let Some(map) = current_map {
+        maps.insert(map.source_category.clone(), map);
+    }
+
+    // follow the maps from the seed category to the location category
+    let mut category = "seed".to_string();
+    let mut visited: HashSet<String> = HashSet::new();
+
+    while category != "location" {
+        let map = match maps.get(&category) {
+            None => {
+                return Err(Scanner::new(last_line_number + 1, "")
+                    .error(format!("a map from the {} category", category)))
+            }
+            Some(map) => map,
+        };
+
+        visited.insert(category.clone());
+
+        if visited.contains(&map.destination_category) {
+            return Err(headers[&category].error("maps that lead from seed to location"));
+        }
+
+        category = map.destination_category.clone();
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+fn find_location_for_seed(maps: &HashMap<String, Mapping>, seed: i64) -> Option<i64> {
+    let mut source = seed;
+    let mut destination;
+    let mut current_map = maps.get("seed")?;
+
+    loop {
+        destination = current_map.get_destination(source);
+        if current_map.destination_category == "location" {
+            break;
+        }
+
+        source = destination;
+        current_map = maps.get(&current_map.destination_category)?;
+    }
+
+    Some(destination)
+}
+
+fn part_1(almanac: &Almanac) -> Option<i64> {
+    let mut lowest_location_number: Option<i64> = None;
+
+    for seed in almanac.seeds.iter() {
+        let location_number = find_location_for_seed(&almanac.maps, *seed)?;
+        if lowest_location_number.is_none() {
+            lowest_location_number = Some(location_number);
+            continue;
+        }
+        lowest_location_number = cmp::min(lowest_location_number, Some(location_number));
+    }
+
+    lowest_location_number
+}
+
+fn find_lowest_location_for_seed_range(
+    maps: &HashMap<String, Mapping>,
+    seed_range: &CategoryRange,
+) -> Option<i64> {
+    let mut source_ranges: Vec<CategoryRange> = vec![seed_range.clone()];
+
+    let mut current_map = maps.get("seed")?;
+
+    let mut destination_ranges: Vec<CategoryRange>;
+
+    loop {
+        destination_ranges = current_map.get_destination_ranges(&source_ranges);
+
+        for destination_range in destination_ranges.iter() {
+            assert!(destination_range.category == current_map.destination_category);
+        }
+
+        if current_map.destination_category == "location" {
+            break;
+        }
+
+        source_ranges = destination_ranges;
+        current_map = maps.get(&current_map.destination_category)?;
+    }
+
+    destination_ranges.iter().map(|r| r.range_start).min()
+}
+
+// None if the seeds are not in pairs of a start and a length, or every length is 0
+fn part_2(almanac: &Almanac) -> Option<i64> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return None;
+    }
+
+    let seed_ranges: Vec<CategoryRange> = almanac
+        .seeds
+        .chunks(2)
+        .filter(|pair| pair[1] > 0)
+        .map(|pair| {
+            let (range_start, range_length) = (pair[0], pair[1]);
+
+            CategoryRange {
+                category: "seed".to_string(),
+                range_start,
+                range_end: range_start + range_length - 1,
+                range_length,
+            }
+        })
+        .collect();
+
+    let mut lowest_location_number: Option<i64> = None;
+
+    for seed_range in seed_ranges.iter() {
+        let location_number = find_lowest_location_for_seed_range(&almanac.maps, seed_range)?;
+
+        if lowest_location_number.is_none() {
+            lowest_location_number = Some(location_number);
+            continue;
+        }
+        lowest_location_number = cmp::min(lowest_location_number, Some(location_number));
+    }
+
+    lowest_location_number
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(&parse_input(input_string).ok()?).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(&parse_input(input_string).ok()?).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle() {
+        let input_string = r###"
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"###;
+
+        let almanac = parse_input(input_string).unwrap();
+        assert_eq!(part_1(&almanac), Some(35));
+        assert_eq!(part_2(&almanac), Some(46));
+
+        let almanac = parse_input(&input_string.replace("79 14 55 13", "79 14 55")).unwrap();
+        assert_eq!(part_2(&almanac), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let parse_error = |input_string: &str| parse_input(input_string).err().unwrap();
+
+        let error = parse_error("seeds: 79 x\n\nseed-to-location map:\n50 98 2\n");
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 11: expected an integer, found \"x\""
+        );
+
+        let error = parse_error("seeds: 79\n\nseed-to-location map:\n50 98\n");
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 6: expected \" \", found end of line"
+        );
+
+        let error = parse_error("seeds: 79\n50 98 2\n");
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = parse_error("seeds: 79\n\nseed-to-location map:\n50 98 0\n");
+        assert_eq!((error.line, error.column), (4, 7));
+
+        let error = parse_error("seeds: 79\n\nseed-to-soil map:\n50 98 2\n");
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 1: expected a map from the soil category, found end of line"
+        );
+
+        let error = parse_error("seeds: 79\nseed-to-soil map:\nsoil-to-seed map:\n");
+        assert_eq!((error.line, error.column), (3, 1));
+
+        let error = parse_error("seeds: 79\nseed-to-soil map:\nseed-to-location map:\n");
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
@@ -0,0 +1,212 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+// None if there are no real solutions
+fn quadratic_formula(a: f64, b: f64, c: f64) -> Option<(f64, f64)> {
+    // ax^2 + bx + c = 0
+    let discriminant = b.powi(2) - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let sqrt_discriminant = discriminant.sqrt();
+    let x1 = (-b + sqrt_discriminant) / (2.0 * a);
+    let x2 = (-b - sqrt_discriminant) / (2.0 * a);
+    Some((x1, x2))
+}
+
+#[derive(Debug)]
+struct Race {
+    // For each race, how far can the toy boat travel within time_allowed?
+    // milliseconds
+    time_allowed: i64,
+    // millimeters
+    best_distance: i64,
+}
+
+impl Race {
+    fn ways_to_win(&self) -> i64 {
+        // Your toy boat has a starting speed of zero millimeters per millisecond. For each whole millisecond you
+        // spend at the beginning of the race holding down the button, the boat's speed increases by
+        // one millimeter per millisecond.
+
+        //  Scratch work:
+        // let charge_speed = 1;
+        // let charge_time = charge_speed;
+        // let remaining_time = self.time_allowed - charge_time;
+        // let distance_travelled = charge_speed * remaining_time;
+
+        // Assume charge_speed is a positive integer.
+        // charge_time = charge_speed
+        // remaining_time = self.time_allowed - charge_time;
+        //
+        // distance_travelled > self.best_distance
+        // charge_speed * remaining_time > self.best_distance
+        // charge_speed * (self.time_allowed - charge_time) > self.best_distance
+        // charge_time * (self.time_allowed - charge_time) > self.best_distance
+        // charge_time * self.time_allowed - charge_time^2 > self.best_distance
+        // c * t - c^2 > d
+        // Get solutions for: charge_time * self.time_allowed - charge_time^2 - self.best_distance = 0
+        // There should be two zeros, and we want the number of integers between these two zeros.
+        // The number of integers between two zeros is the number of ways to win.
+
+        // Assume ax^2 + bx + c = 0
+        // -c^2 + t * c - d = 0
+        // -charge_time^2 + self.time_allowed * charge_time  - self.best_distance = 0
+        //
+        // There are no ways to win if the best distance cannot be beaten.
+        let (left, right) =
+            match quadratic_formula(-1.0, self.time_allowed as f64, -self.best_distance as f64) {
+                None => return 0,
+                Some(solutions) => solutions,
+            };
+        let charge_time_minimum = left.floor() as i64 + 1;
+        let charge_time_maximum = right.ceil() as i64 - 1;
+        if charge_time_minimum > charge_time_maximum {
+            return 0;
+        }
+        // the best distance is not negative
+        assert!(charge_time_minimum > 0);
+
+        charge_time_maximum - charge_time_minimum + 1
+    }
+}
+
+// the numbers of a line after its label, e.g. Time:      7  15   30
+fn parse_numbers(scanner: &mut Scanner, label: &str) -> Result<Vec<i64>, ParseError> {
+    scanner.literal(label)?;
+
+    let mut numbers = vec![];
+
+    loop {
+        scanner.skip_whitespace();
+        if scanner.is_at_end() && !numbers.is_empty() {
+            return Ok(numbers);
+        }
+
+        let number: u32 = scanner.integer()?;
+        numbers.push(number as i64);
+    }
+}
+
+// the time allowed for each race on the first line, and the best distance of each race on the
+// second line
+fn parse_input(input_string: &str) -> Result<Vec<Race>, ParseError> {
+    let mut lines = scan_lines(input_string);
+
+    let mut scanner = lines.next().unwrap_or_else(|| Scanner::new(1, ""));
+    let time_allowed_vec = parse_numbers(&mut scanner, "Time:")?;
+
+    let line_number = scanner.line_number();
+    let mut scanner = lines
+        .next()
+        .unwrap_or_else(|| Scanner::new(line_number + 1, ""));
+    scanner.literal("Distance:")?;
+
+    let mut races = vec![];
+    for time_allowed in time_allowed_vec {
+        scanner.skip_whitespace();
+        let best_distance: u32 = scanner.integer()?;
+
+        races.push(Race {
+            time_allowed,
+            best_distance: best_distance as i64,
+        });
+    }
+    scanner.end()?;
+
+    if let Some(line) = lines.next() {
+        return Err(line.error("end of input"));
+    }
+
+    Ok(races)
+}
+
+fn part_1(races: &[Race]) -> i64 {
+    races.iter().map(|x| x.ways_to_win()).product::<i64>()
+}
+
+// the numbers of the races are one number; None if it overflows
+fn part_2(races: &[Race]) -> Option<i64> {
+    let time_allowed: String = races
+        .iter()
+        .map(|x| x.time_allowed.to_string())
+        .collect::<Vec<String>>()
+        .join("");
+    let best_distance: String = races
+        .iter()
+        .map(|x| x.best_distance.to_string())
+        .collect::<Vec<String>>()
+        .join("");
+
+    let race = Race {
+        time_allowed: time_allowed.parse::<i64>().ok()?,
+        best_distance: best_distance.parse::<i64>().ok()?,
+    };
+    Some(race.ways_to_win())
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(&parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(&parse_input(input_string).ok()?).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle() {
+        let input_string = r###"
+Time:      7  15   30
+Distance:  9  40  200
+"###;
+
+        let races = parse_input(input_string).unwrap();
+        assert_eq!(part_1(&races), 288);
+        assert_eq!(part_2(&races), Some(71503));
+
+        // races that cannot be won
+        let races = parse_input("Time: 7 3\nDistance: 9 2").unwrap();
+        assert_eq!(part_1(&races), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("Time: 7 15 30\nDistance: 9 40").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 15: expected an integer, found end of line"
+        );
+
+        let error = parse_input("Time: 7 15\nDistance: 9 40 200").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 16: expected end of line, found \"200\""
+        );
+
+        let error = parse_input("Time: 7 -15\nDistance: 9 40").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+
+        let error = parse_input("Time: 7\nDistance: 9\nTime: 7").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
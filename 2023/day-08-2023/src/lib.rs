@@ -0,0 +1,290 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use num::integer::lcm;
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+enum Instruction {
+    Right,
+    Left,
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    label: String,
+
+    // vertices connecting to this node
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn new(label: String, left: String, right: String) -> Self {
+        Self { label, left, right }
+    }
+}
+
+type Nodes = HashMap<String, Node>;
+
+fn parse_instructions(mut scanner: Scanner) -> Result<Vec<Instruction>, ParseError> {
+    let mut instructions: Vec<Instruction> = vec![];
+
+    loop {
+        match scanner.char("one of R or L", |c| c == 'R' || c == 'L')? {
+            'R' => instructions.push(Instruction::Right),
+            _ => instructions.push(Instruction::Left),
+        }
+
+        if scanner.is_at_end() {
+            break;
+        }
+    }
+
+    Ok(instructions)
+}
+
+fn parse_label(scanner: &mut Scanner) -> Result<String, ParseError> {
+    let is_label = |c: char| c.is_ascii_alphanumeric();
+
+    let mut label = String::new();
+    label.push(scanner.char("a node label", is_label)?);
+    while scanner.rest().starts_with(is_label) {
+        label.push(scanner.char("a node label", is_label)?);
+    }
+
+    Ok(label)
+}
+
+fn parse_input(input_string: &str) -> Result<(Vec<Instruction>, Nodes), ParseError> {
+    let mut scanners = scan_lines(input_string);
+
+    let instructions = parse_instructions(scanners.next().unwrap_or_else(|| Scanner::new(1, "")))?;
+
+    // parse nodes, and remember where each of them points to
+    let mut nodes: Nodes = HashMap::new();
+    let mut references: Vec<(String, Scanner)> = vec![];
+
+    for mut scanner in scanners {
+        let start = scanner;
+        let label = parse_label(&mut scanner)?;
+        if nodes.contains_key(&label) {
+            return Err(start.error("a node that was not seen before"));
+        }
+
+        scanner.skip_whitespace();
+        scanner.literal("=")?;
+        scanner.skip_whitespace();
+        scanner.literal("(")?;
+
+        references.push((label.clone(), scanner));
+        let left = parse_label(&mut scanner)?;
+        scanner.literal(",")?;
+        scanner.skip_whitespace();
+
+        references.push((label.clone(), scanner));
+        let right = parse_label(&mut scanner)?;
+        scanner.literal(")")?;
+        scanner.end()?;
+
+        nodes.insert(label.clone(), Node::new(label, left, right));
+    }
+
+    for (label, mut scanner) in references {
+        let start = scanner;
+        if !nodes.contains_key(&parse_label(&mut scanner)?) {
+            return Err(start.error(format!("a node connected to {}", label)));
+        }
+    }
+
+    Ok((instructions, nodes))
+}
+
+fn part_1(input_string: &str) -> Option<i64> {
+    let (instructions, nodes) = parse_input(input_string).ok()?;
+
+    let mut current_node = nodes.get("AAA")?;
+    let mut num_of_steps = 0;
+    let mut instruction_step = 0;
+
+    loop {
+        if current_node.label == "ZZZ" {
+            break;
+        }
+
+        num_of_steps += 1;
+
+        let current_instruction = &instructions[instruction_step];
+
+        match current_instruction {
+            Instruction::Right => {
+                current_node = nodes.get(&current_node.right)?;
+            }
+            Instruction::Left => {
+                current_node = nodes.get(&current_node.left)?;
+            }
+        }
+
+        instruction_step += 1;
+        instruction_step %= instructions.len();
+    }
+
+    Some(num_of_steps)
+}
+
+fn part_2(input_string: &str) -> Option<i64> {
+    let (instructions, nodes) = parse_input(input_string).ok()?;
+
+    // start at every node that ends with A
+    let mut current_nodes: Vec<Node> = nodes
+        .values()
+        .filter(|x| x.label.ends_with('A'))
+        .cloned()
+        .collect();
+
+    let mut num_of_steps = 0;
+    let mut instruction_step = 0;
+    let current_nodes_len = current_nodes.len();
+    let mut cycle_lengths = vec![0; current_nodes_len];
+
+    // After examining the input, we will know that each node that ends with A will cycle.
+    //
+    // For example:
+    // xxA -> xxB --> ... -> xxZ --> xxB --> ...
+    // We measure the cycle length (or number of steps) from xxB to xxZ.
+    //
+    // Knowing this, we take the LCM (least common multiple) of all cycle lengths to get the number of steps for all
+    // nodes to reach a node that ends with Z.
+
+    // find cycle lengths
+    loop {
+        if cycle_lengths.par_iter().all(|x| *x != 0) {
+            break;
+        }
+
+        {
+            for (index, current_node) in current_nodes.iter().enumerate() {
+                if current_node.label.ends_with('Z') && cycle_lengths[index] == 0 {
+                    cycle_lengths[index] = num_of_steps;
+                }
+            }
+        }
+
+        // check if every node in current_nodes ends with Z
+        // if current_nodes.par_iter().all(|x| x.label.ends_with("Z")) {
+        //     break;
+        // }
+
+        num_of_steps += 1;
+        let current_instruction = &instructions[instruction_step];
+
+        current_nodes = current_nodes
+            .into_par_iter()
+            .map(|current_node| {
+                match current_instruction {
+                    Instruction::Right => {
+                        // next node
+                        nodes.get(&current_node.right).cloned()
+                    }
+                    Instruction::Left => nodes.get(&current_node.left).cloned(),
+                }
+            })
+            .collect::<Option<Vec<Node>>>()?;
+
+        assert!(current_nodes.len() == current_nodes_len);
+
+        instruction_step += 1;
+        instruction_step %= instructions.len();
+    }
+
+    cycle_lengths.into_iter().reduce(lcm)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        part_1(input_string).map(|answer| answer.to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        part_2(input_string).map(|answer| answer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle() {
+        let input_string = r###"
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+"###;
+
+        assert_eq!(part_1(input_string), Some(2));
+
+        let input_string = r###"
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+"###;
+
+        assert_eq!(part_1(input_string), Some(6));
+
+        let input_string = r###"
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+"###;
+
+        assert_eq!(part_2(input_string), Some(6));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("RLX\n\nAAA = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected one of R or L, found \"X\""
+        );
+
+        let error = parse_input("RL\n\nAAA = (ZZZ, BBB)\nZZZ = (ZZZ, ZZZ)\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 13: expected a node connected to AAA, found \"BBB\""
+        );
+
+        let error = parse_input("RL\n\nAAA = (AAA AAA)\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 11));
+    }
+}
@@ -0,0 +1,138 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+
+#[derive(Debug)]
+struct History {
+    sequence: Vec<i64>,
+    differences: Box<Option<History>>,
+}
+
+impl History {
+    fn new(sequence: Vec<i64>) -> Self {
+        let differences: Vec<i64> = sequence.windows(2).map(|x| x[1] - x[0]).collect();
+
+        let mut differences_clone = differences.clone();
+        // check if differences are all the same
+        differences_clone.dedup();
+
+        // a single value is extrapolated as a constant
+        let history_difference = if differences_clone.is_empty() {
+            None
+        } else if differences_clone.len() == 1 {
+            if differences_clone[0] == 0 {
+                None
+            } else {
+                Some(Self::new(differences))
+            }
+        } else {
+            Some(Self::new(differences))
+        };
+
+        Self {
+            sequence,
+            differences: Box::new(history_difference),
+        }
+    }
+
+    fn find_next_value(&self) -> i64 {
+        let last_value = *self.sequence.last().unwrap();
+
+        if let Some(history_difference) = &*self.differences {
+            last_value + history_difference.find_next_value()
+        } else {
+            last_value
+        }
+    }
+
+    fn find_previous_value(&self) -> i64 {
+        let first_value = *self.sequence.first().unwrap();
+
+        if let Some(history_difference) = &*self.differences {
+            first_value - history_difference.find_previous_value()
+        } else {
+            first_value
+        }
+    }
+}
+
+// the values of a history, e.g. 0 3 6 9 12 15
+fn parse_history(mut scanner: Scanner) -> Result<History, ParseError> {
+    let mut sequence = vec![];
+
+    while !scanner.is_at_end() {
+        let value: i32 = scanner.integer()?;
+        sequence.push(value as i64);
+        scanner.skip_whitespace();
+    }
+
+    Ok(History::new(sequence))
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<History>, ParseError> {
+    scan_lines(input_string).map(parse_history).collect()
+}
+
+fn part_1(histories: Vec<History>) -> i64 {
+    histories.into_iter().map(|x| x.find_next_value()).sum()
+}
+
+fn part_2(histories: Vec<History>) -> i64 {
+    histories.into_iter().map(|x| x.find_previous_value()).sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle() {
+        let input_string = r###"
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"###;
+
+        assert_eq!(part_1(parse_input(input_string).unwrap()), 114);
+        assert_eq!(part_2(parse_input(input_string).unwrap()), 2);
+
+        // histories that are too short to reach differences of zero
+        assert_eq!(part_1(parse_input("7\n1 3").unwrap()), 7 + 5);
+        assert_eq!(part_2(parse_input("7\n1 3").unwrap()), 7 - 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("0 3 6\n1 3 x 10\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected an integer, found \"x\""
+        );
+
+        let error = parse_input("0 3,6").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+}
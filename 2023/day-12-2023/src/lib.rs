@@ -0,0 +1,294 @@
+use aoc_core::{scan_lines, ParseError, Scanner, Solution};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Spring {
+    Damaged,
+    Operational,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Hash)]
+struct Row {
+    springs: Vec<Spring>,
+    damage_report: Vec<usize>,
+}
+
+impl Row {
+    #[allow(dead_code)]
+    fn to_str(&self) -> String {
+        let mut string = String::new();
+
+        for spring in self.springs.iter() {
+            match spring {
+                Spring::Damaged => {
+                    string.push('#');
+                }
+                Spring::Operational => {
+                    string.push('.');
+                }
+                Spring::Unknown => {
+                    string.push('?');
+                }
+            }
+        }
+
+        format!("{} {:?}", string, self.damage_report)
+    }
+}
+
+// a row of springs followed by the sizes of the damaged groups, e.g. ???.### 1,1,3
+fn parse_row(mut scanner: Scanner) -> Result<Row, ParseError> {
+    let mut springs = vec![];
+
+    loop {
+        let spring = scanner.char("a spring (#, . or ?)", |c| "#.?".contains(c))?;
+        springs.push(match spring {
+            '#' => Spring::Damaged,
+            '.' => Spring::Operational,
+            _ => Spring::Unknown,
+        });
+
+        if scanner.rest().starts_with(' ') {
+            break;
+        }
+    }
+
+    scanner.literal(" ")?;
+
+    let mut damage_report = vec![];
+
+    loop {
+        let start = scanner;
+        let count: usize = scanner.integer()?;
+        if count == 0 {
+            return Err(start.error("a group of at least 1 damaged spring"));
+        }
+        damage_report.push(count);
+
+        if !scanner.eat(",") {
+            break;
+        }
+    }
+
+    scanner.end()?;
+
+    Ok(Row {
+        springs,
+        damage_report,
+    })
+}
+
+fn parse_input(input_string: &str) -> Result<Vec<Row>, ParseError> {
+    scan_lines(input_string).map(parse_row).collect()
+}
+
+fn part_1(rows: Vec<Row>) -> usize {
+    rows.into_par_iter()
+        .map(|row| -> usize { count_possible_arangements(row) })
+        .sum()
+}
+
+fn count_possible_arangements(row: Row) -> usize {
+    let springs = row.springs;
+    let counts = row.damage_report;
+    let mut cache = HashMap::new();
+
+    count_possible_arangements_inner(&springs, &counts, &mut cache)
+}
+
+fn count_possible_arangements_inner(
+    springs: &[Spring],
+    counts: &[usize],
+    cache: &mut HashMap<(Vec<usize>, Vec<Spring>), usize>,
+) -> usize {
+    if springs.is_empty() {
+        if counts.is_empty() {
+            return 1;
+        } else {
+            // Exhaused all springs, but still have counts to satisfy
+            return 0;
+        }
+    }
+
+    if counts.is_empty() {
+        if springs.contains(&Spring::Damaged) {
+            // Too many previous unknowns were counted as damaged
+            return 0;
+        } else {
+            // All remaining unknowns are operational
+            return 1;
+        }
+    }
+
+    match springs[0] {
+        Spring::Operational => {
+            // We can skip this spring, and continue processing the rest
+            count_possible_arangements_inner(&springs[1..], counts, cache)
+        }
+        Spring::Damaged => process_damaged_group(springs, counts, cache),
+        Spring::Unknown => {
+            let mut possible_arrrangements = 0;
+
+            // We assume current spring is operational, and skip it to process the rest.
+            possible_arrrangements +=
+                count_possible_arangements_inner(&springs[1..], counts, cache);
+
+            // We assume current spring is damaged, and process the rest.
+            possible_arrrangements += process_damaged_group(springs, counts, cache);
+
+            possible_arrrangements
+        }
+    }
+}
+
+fn process_damaged_group(
+    springs: &[Spring],
+    counts: &[usize],
+    cache: &mut HashMap<(Vec<usize>, Vec<Spring>), usize>,
+) -> usize {
+    // invariant: assume springs[0] is damaged
+
+    if counts.is_empty() {
+        return 0;
+    }
+
+    if springs.is_empty() {
+        return 0;
+    }
+
+    if let Some(&result) = cache.get(&(counts.to_vec(), springs.to_vec())) {
+        return result;
+    }
+
+    let current_group_size = counts[0];
+    if springs.len() < current_group_size {
+        return 0;
+    }
+
+    if springs[..current_group_size].contains(&Spring::Operational) {
+        return 0;
+    }
+
+    // assume springs[..current_group_size] are all damaged
+
+    if springs.len() == current_group_size {
+        if counts.len() == 1 {
+            return 1;
+        }
+        // springs are not long enough to satisfy the next set of counts
+        return 0;
+    }
+
+    if springs[current_group_size] == Spring::Damaged {
+        // This group is one damaged spring too long.
+        return 0;
+    }
+
+    // invariant: springs[current_group_size] is Operational or Unknown. If it is Unknown, then we still assume it is
+    // Operational, since otherwise it would be part of this group.
+
+    let result =
+        count_possible_arangements_inner(&springs[(current_group_size + 1)..], &counts[1..], cache);
+
+    cache.insert((counts.to_vec(), springs.to_vec()), result);
+
+    result
+}
+
+fn part_2(rows: Vec<Row>) -> usize {
+    rows.into_par_iter()
+        .map(|row| -> usize {
+            let springs: Vec<Spring> = row
+                .springs
+                .iter()
+                .copied()
+                .chain([Spring::Unknown])
+                .cycle()
+                .take(row.springs.len() * 5 + 4)
+                .collect();
+
+            let damage_report: Vec<usize> = row
+                .damage_report
+                .iter()
+                .copied()
+                .cycle()
+                .take(row.damage_report.len() * 5)
+                .collect();
+
+            let row = Row {
+                springs,
+                damage_report,
+            };
+
+            count_possible_arangements(row)
+        })
+        .sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+        std::hint::black_box(parse_input(input_string)?);
+        Ok(true)
+    }
+
+    fn part_1(&self, input_string: &str) -> Option<String> {
+        Some(part_1(parse_input(input_string).ok()?).to_string())
+    }
+
+    fn part_2(&self, input_string: &str) -> Option<String> {
+        Some(part_2(parse_input(input_string).ok()?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_puzzle() {
+        let input_string = r###"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+"###;
+
+        assert_eq!(part_1(parse_input(input_string).unwrap()), 21);
+        assert_eq!(part_2(parse_input(input_string).unwrap()), 525152);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("???.### 1,1,3\n.??x.??...?##. 1,1,3\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a spring (#, . or ?), found \"x\""
+        );
+
+        let error = parse_input(" 1,1,3").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 2));
+
+        let error = parse_input("???.### 1,0,3").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+
+        let error = parse_input("???.### 1,1,").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 13));
+
+        let error = parse_input("???.### 1,1,3 2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 15));
+    }
+}
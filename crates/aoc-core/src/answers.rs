@@ -0,0 +1,159 @@
+// imports
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::input::workspace_root;
+use crate::solution::Part;
+
+// code
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct DayAnswers {
+    part_1: Option<String>,
+    part_2: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Unreadable { path: PathBuf, error: io::Error },
+    Invalid { path: PathBuf, message: String },
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Unreadable { path, error } => {
+                write!(f, "unable to read {}: {}", path.display(), error)
+            }
+            AnswersError::Invalid { path, message } => {
+                write!(f, "invalid answers file {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+// The recorded answers of a year, i.e. <root>/<year>/answers.toml:
+//
+// [day-01]
+// part-1 = "513"
+// part-2 = "287"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+impl Answers {
+    pub fn parse(path: &Path, input_string: &str) -> Result<Self, AnswersError> {
+        let invalid = |message: String| AnswersError::Invalid {
+            path: path.to_path_buf(),
+            message,
+        };
+
+        let tables: HashMap<String, DayAnswers> =
+            toml::from_str(input_string).map_err(|error| invalid(error.to_string()))?;
+
+        let mut days = BTreeMap::new();
+
+        for (key, day_answers) in tables {
+            let day: u8 = key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| invalid(format!("expected a table like [day-01], got [{}]", key)))?;
+
+            days.insert(day, day_answers);
+        }
+
+        Ok(Answers { days })
+    }
+
+    // a year without an answers file has no recorded answers
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        let path = answers_path(year);
+
+        match fs::read_to_string(&path) {
+            Ok(input_string) => Answers::parse(&path, &input_string),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(error) => Err(AnswersError::Unreadable { path, error }),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.days
+            .get(&day)
+            .and_then(|day_answers| day_answers.get(part))
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days.keys().copied()
+    }
+}
+
+pub fn answers_path(year: u16) -> PathBuf {
+    workspace_root().join(year.to_string()).join("answers.toml")
+}
+
+// answers are compared without any surrounding whitespace (e.g. the stars drawn by 2018 day 10)
+pub fn matches_answer(expected: &str, actual: &str) -> bool {
+    expected.trim() == actual.trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let input_string = r###"
+[day-01]
+part-1 = "513"
+part-2 = "287"
+
+[day-10]
+part-1 = '''
+#..#
+.##.
+'''
+"###;
+
+        let answers = Answers::parse(Path::new("answers.toml"), input_string).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some("513"));
+        assert_eq!(answers.get(1, Part::Two), Some("287"));
+        assert_eq!(answers.get(10, Part::Two), None);
+        assert_eq!(answers.get(2, Part::One), None);
+        assert!(matches_answer(
+            answers.get(10, Part::One).unwrap(),
+            "\n#..#\n.##."
+        ));
+        assert_eq!(answers.days().collect::<Vec<u8>>(), vec![1, 10]);
+
+        assert!(Answers::parse(Path::new("answers.toml"), "[one]\npart-1 = \"1\"").is_err());
+        assert!(Answers::parse(Path::new("answers.toml"), "[day-01]\npart-3 = \"1\"").is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let answers = Answers::load(2018).unwrap();
+        assert_eq!(answers.get(19, Part::One), Some("930"));
+        assert_eq!(answers.get(19, Part::Two), Some("10628484"));
+
+        assert_eq!(Answers::load(1999).unwrap(), Answers::default());
+    }
+}
@@ -0,0 +1,225 @@
+// imports
+
+use std::collections::{BTreeMap, BTreeSet};
+
+// code
+
+// A way for the constraints to fail, in terms of the variable that they fail on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unsolvable<V, C> {
+    // no value is left for the variable
+    Contradiction { variable: V },
+    // the variable can take either value, and the constraints still hold
+    Ambiguous { variable: V, values: [C; 2] },
+}
+
+// Variables that each take a different value, out of their own set of candidates.
+//
+// Solving alternates between unit propagation (a variable with a single candidate left takes it,
+// which rules it out for every other variable), and backtracking on the variable with the fewest
+// candidates once propagation stalls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraints<V: Ord, C: Ord> {
+    candidates: BTreeMap<V, BTreeSet<C>>,
+}
+
+impl<V: Ord + Copy, C: Ord + Copy> Default for Constraints<V, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Ord + Copy, C: Ord + Copy> Constraints<V, C> {
+    pub fn new() -> Self {
+        Constraints {
+            candidates: BTreeMap::new(),
+        }
+    }
+
+    // The variable can only take one of these values. Any earlier restriction of the variable
+    // still holds, and an Err is returned if no value satisfies both.
+    pub fn restrict<I>(&mut self, variable: V, values: I) -> Result<(), Unsolvable<V, C>>
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let values: BTreeSet<C> = values.into_iter().collect();
+
+        let candidates = self
+            .candidates
+            .entry(variable)
+            .and_modify(|candidates| candidates.retain(|value| values.contains(value)))
+            .or_insert(values);
+
+        if candidates.is_empty() {
+            return Err(Unsolvable::Contradiction { variable });
+        }
+
+        Ok(())
+    }
+
+    // the values that the variable may still take, before solving
+    pub fn candidates(&self, variable: V) -> Option<&BTreeSet<C>> {
+        self.candidates.get(&variable)
+    }
+
+    // The only value of every variable that satisfies the constraints.
+    pub fn solve(&self) -> Result<BTreeMap<V, C>, Unsolvable<V, C>> {
+        let mut solutions = vec![];
+        let mut contradiction = None;
+
+        search(self.candidates.clone(), &mut solutions, &mut contradiction);
+
+        match solutions.as_slice() {
+            [] => {
+                let variable = contradiction.expect("a search without solutions fails somewhere");
+                Err(Unsolvable::Contradiction { variable })
+            }
+            [solution] => Ok(solution.clone()),
+            [first, second, ..] => {
+                let (variable, value) = first
+                    .iter()
+                    .find(|(variable, value)| second[*variable] != **value)
+                    .expect("different solutions differ somewhere");
+
+                Err(Unsolvable::Ambiguous {
+                    variable: *variable,
+                    values: [*value, second[variable]],
+                })
+            }
+        }
+    }
+}
+
+// Rule out the value of every variable with a single candidate for every other variable, until
+// nothing changes. Err with the variable that is left without any candidate, if any.
+fn propagate<V: Ord + Copy, C: Ord + Copy>(
+    candidates: &mut BTreeMap<V, BTreeSet<C>>,
+) -> Result<(), V> {
+    let mut assigned: BTreeSet<V> = BTreeSet::new();
+
+    loop {
+        let unit = candidates
+            .iter()
+            .find(|(variable, values)| values.len() == 1 && !assigned.contains(*variable))
+            .map(|(variable, values)| (*variable, *values.iter().next().unwrap()));
+
+        let (variable, value) = match unit {
+            Some(unit) => unit,
+            None => return Ok(()),
+        };
+        assigned.insert(variable);
+
+        for (other, values) in candidates.iter_mut() {
+            if *other != variable && values.remove(&value) && values.is_empty() {
+                return Err(*other);
+            }
+        }
+    }
+}
+
+// Find up to two solutions, which is enough to tell whether the solution is unique.
+fn search<V: Ord + Copy, C: Ord + Copy>(
+    mut candidates: BTreeMap<V, BTreeSet<C>>,
+    solutions: &mut Vec<BTreeMap<V, C>>,
+    contradiction: &mut Option<V>,
+) {
+    if let Err(variable) = propagate(&mut candidates) {
+        contradiction.get_or_insert(variable);
+        return;
+    }
+
+    // the variable with the fewest candidates has the fewest branches to try
+    let undecided = candidates
+        .iter()
+        .filter(|(_, values)| values.len() > 1)
+        .min_by_key(|(_, values)| values.len())
+        .map(|(variable, values)| (*variable, values.clone()));
+
+    let (variable, values) = match undecided {
+        Some(undecided) => undecided,
+        None => {
+            let solution = candidates
+                .into_iter()
+                .map(|(variable, values)| (variable, *values.iter().next().unwrap()))
+                .collect();
+            solutions.push(solution);
+            return;
+        }
+    };
+
+    for value in values {
+        if solutions.len() >= 2 {
+            return;
+        }
+
+        let mut guess = candidates.clone();
+        guess.insert(variable, BTreeSet::from([value]));
+        search(guess, solutions, contradiction);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_propagation() {
+        let mut constraints = Constraints::new();
+        constraints.restrict('a', [1, 2, 3]).unwrap();
+        constraints.restrict('b', [1, 2]).unwrap();
+        constraints.restrict('c', [2]).unwrap();
+        constraints.restrict('a', [1, 3]).unwrap();
+
+        assert_eq!(constraints.candidates('a'), Some(&BTreeSet::from([1, 3])));
+        assert_eq!(
+            constraints.solve(),
+            Ok(BTreeMap::from([('a', 3), ('b', 1), ('c', 2)]))
+        );
+    }
+
+    #[test]
+    fn test_backtracking() {
+        // propagation alone makes no progress, but no choice for a leaves two values for the
+        // other three variables
+        let mut constraints = Constraints::new();
+        constraints.restrict('a', [1, 2]).unwrap();
+        constraints.restrict('b', [1, 2]).unwrap();
+        constraints.restrict('c', [1, 2]).unwrap();
+        assert!(matches!(
+            constraints.solve(),
+            Err(Unsolvable::Contradiction { .. })
+        ));
+
+        // whereas with a third value for c, a and b can take 1 and 2 either way around
+        let mut constraints = Constraints::new();
+        constraints.restrict('a', [1, 2]).unwrap();
+        constraints.restrict('b', [1, 2]).unwrap();
+        constraints.restrict('c', [1, 2, 3]).unwrap();
+        assert_eq!(
+            constraints.solve(),
+            Err(Unsolvable::Ambiguous {
+                variable: 'a',
+                values: [1, 2]
+            })
+        );
+    }
+
+    #[test]
+    fn test_unsolvable() {
+        let mut constraints = Constraints::new();
+        constraints.restrict('a', [1, 2]).unwrap();
+        assert_eq!(
+            constraints.restrict('a', [3]),
+            Err(Unsolvable::Contradiction { variable: 'a' })
+        );
+
+        // two variables can not both take 1
+        let mut constraints = Constraints::new();
+        constraints.restrict('a', [1]).unwrap();
+        constraints.restrict('b', [1]).unwrap();
+        assert_eq!(
+            constraints.solve(),
+            Err(Unsolvable::Contradiction { variable: 'b' })
+        );
+    }
+}
@@ -0,0 +1,183 @@
+// imports
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::solution::Solution;
+
+// code
+
+// environment variable that points to a directory of puzzle inputs
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // the input.txt file that is committed alongside the solution
+    Default,
+    File(PathBuf),
+    Stdin,
+    // a directory laid out as <dir>/<year>/day-<day>.txt;
+    // days without a file in the directory fall back to their default input
+    Directory(PathBuf),
+}
+
+impl InputSource {
+    // "-" is read from stdin; anything else is a path to a file
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    // the inputs directory from the environment, if one is configured
+    pub fn from_env() -> Self {
+        match env::var_os(INPUTS_DIR_ENV) {
+            Some(dir) if !dir.is_empty() => InputSource::Directory(PathBuf::from(dir)),
+            _ => InputSource::Default,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { path: PathBuf },
+    Unreadable { path: PathBuf, error: io::Error },
+    Stdin(io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(
+                f,
+                "puzzle input not found at {}; pass a path, use - for stdin or set {}",
+                path.display(),
+                INPUTS_DIR_ENV
+            ),
+            InputError::Unreadable { path, error } => {
+                write!(f, "unable to read {}: {}", path.display(), error)
+            }
+            InputError::Stdin(error) => write!(f, "unable to read stdin: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub(crate) fn workspace_root() -> &'static Path {
+    // this crate lives at <root>/crates/aoc-core
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .and_then(Path::parent)
+        .unwrap()
+}
+
+// i.e. <root>/2018/day-15-2018/src/input.txt
+pub fn default_input_path(year: u16, day: u8) -> PathBuf {
+    workspace_root()
+        .join(year.to_string())
+        .join(format!("day-{:02}-{}", day, year))
+        .join("src")
+        .join("input.txt")
+}
+
+pub fn directory_input_path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string())
+        .join(format!("day-{:02}.txt", day))
+}
+
+fn read_file(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|error| {
+        if error.kind() == io::ErrorKind::NotFound {
+            InputError::Missing {
+                path: path.to_path_buf(),
+            }
+        } else {
+            InputError::Unreadable {
+                path: path.to_path_buf(),
+                error,
+            }
+        }
+    })
+}
+
+pub fn load_input(solution: &dyn Solution, source: &InputSource) -> Result<String, InputError> {
+    let default_path = default_input_path(solution.year(), solution.day());
+
+    match source {
+        InputSource::Default => read_file(&default_path),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input_string = String::new();
+            io::stdin()
+                .read_to_string(&mut input_string)
+                .map_err(InputError::Stdin)?;
+            Ok(input_string)
+        }
+        InputSource::Directory(dir) => {
+            let path = directory_input_path(dir, solution.year(), solution.day());
+            if path.is_file() {
+                read_file(&path)
+            } else {
+                read_file(&default_path)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        fn year(&self) -> u16 {
+            2018
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+    }
+
+    #[test]
+    fn test_paths() {
+        let path = default_input_path(2018, 1);
+        assert!(path.ends_with("2018/day-01-2018/src/input.txt"));
+        assert!(path.is_file());
+
+        assert_eq!(
+            directory_input_path(Path::new("inputs"), 2023, 4),
+            PathBuf::from("inputs/2023/day-04.txt")
+        );
+    }
+
+    #[test]
+    fn test_sources() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("input.txt"),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+
+        let default_input = load_input(&Example, &InputSource::Default).unwrap();
+
+        // days that are missing from the inputs directory use the default input
+        let directory = InputSource::Directory(PathBuf::from("does-not-exist"));
+        assert_eq!(load_input(&Example, &directory).unwrap(), default_input);
+
+        let missing = InputSource::File(PathBuf::from("does-not-exist.txt"));
+        match load_input(&Example, &missing) {
+            Err(InputError::Missing { path }) => {
+                assert_eq!(path, PathBuf::from("does-not-exist.txt"))
+            }
+            result => panic!("expected a missing input, got {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+mod answers;
+mod constraints;
+mod input;
+mod parse;
+mod solution;
+
+pub use answers::{answers_path, matches_answer, Answers, AnswersError, DayAnswers};
+pub use constraints::{Constraints, Unsolvable};
+pub use input::{
+    default_input_path, directory_input_path, load_input, InputError, InputSource, INPUTS_DIR_ENV,
+};
+pub use parse::{scan_lines, ParseError, Scanner};
+pub use solution::{print_answers, read_input, run, Part, Solution, Variant};
@@ -0,0 +1,275 @@
+// imports
+
+use std::any::type_name;
+use std::fmt;
+use std::str::FromStr;
+
+// code
+
+// A malformed puzzle input, pointing at the offending line and column (both start at 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    // what was found instead; e.g. "x" or end of line
+    pub found: String,
+    // the offending line of the puzzle input
+    pub source_line: String,
+}
+
+impl ParseError {
+    // The offending line with a caret pointing at the column, e.g.
+    //
+    //   3 | pos=<1,x,3>, r=1
+    //     |        ^
+    pub fn snippet(&self) -> String {
+        let line_number = self.line.to_string();
+        let padding = " ".repeat(line_number.len());
+
+        format!(
+            "{} | {}\n{} | {}^",
+            line_number,
+            self.source_line,
+            padding,
+            " ".repeat(self.column - 1)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// A cursor over a single line of the puzzle input.
+// Every method that fails returns a ParseError that points at the current position.
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    line_number: usize,
+    line: &'a str,
+    // byte offset of the cursor within the line
+    offset: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(line_number: usize, line: &'a str) -> Self {
+        Scanner {
+            line_number,
+            line,
+            offset: 0,
+        }
+    }
+
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    pub fn column(&self) -> usize {
+        self.line[..self.offset].chars().count() + 1
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.line[self.offset..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn error<S: Into<String>>(&self, expected: S) -> ParseError {
+        // a whole word or number, otherwise a single character
+        let found: String = match self.rest().chars().next() {
+            None => "end of line".to_string(),
+            Some(c) if c.is_alphanumeric() => {
+                let token: String = self
+                    .rest()
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric())
+                    .collect();
+                format!("{:?}", token)
+            }
+            Some(c) => format!("{:?}", c.to_string()),
+        };
+
+        ParseError {
+            line: self.line_number,
+            column: self.column(),
+            expected: expected.into(),
+            found,
+            source_line: self.line.to_string(),
+        }
+    }
+
+    fn advance(&mut self, num_of_bytes: usize) -> &'a str {
+        let consumed = &self.rest()[..num_of_bytes];
+        self.offset += num_of_bytes;
+        consumed
+    }
+
+    fn take_while<F>(&mut self, predicate: F) -> &'a str
+    where
+        F: Fn(char) -> bool,
+    {
+        let num_of_bytes = self
+            .rest()
+            .find(|c: char| !predicate(c))
+            .unwrap_or(self.rest().len());
+        self.advance(num_of_bytes)
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    // consume the literal if the line continues with it
+    pub fn eat(&mut self, literal: &str) -> bool {
+        if self.rest().starts_with(literal) {
+            self.advance(literal.len());
+            return true;
+        }
+
+        false
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.eat(literal) {
+            return Ok(());
+        }
+
+        Err(self.error(format!("{:?}", literal)))
+    }
+
+    pub fn char<F>(&mut self, expected: &str, predicate: F) -> Result<char, ParseError>
+    where
+        F: Fn(char) -> bool,
+    {
+        match self.rest().chars().next() {
+            Some(c) if predicate(c) => {
+                self.advance(c.len_utf8());
+                Ok(c)
+            }
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    // an optionally signed integer
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        let start = *self;
+
+        let sign_length = match self.rest().chars().next() {
+            Some('-') | Some('+') => 1,
+            _ => 0,
+        };
+        let num_of_digits = self.rest()[sign_length..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest().len() - sign_length);
+
+        if num_of_digits == 0 {
+            return Err(start.error("an integer"));
+        }
+
+        let digits = self.advance(sign_length + num_of_digits);
+        digits
+            .parse()
+            .map_err(|_| start.error(format!("an integer that fits in {}", type_name::<T>())))
+    }
+
+    // a run of characters up to the next whitespace
+    pub fn word(&mut self, expected: &str) -> Result<&'a str, ParseError> {
+        let word = self.take_while(|c| !c.is_whitespace());
+
+        if word.is_empty() {
+            return Err(self.error(expected));
+        }
+
+        Ok(word)
+    }
+
+    // anything but trailing whitespace is unexpected
+    pub fn end(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+
+        if self.is_at_end() {
+            return Ok(());
+        }
+
+        Err(self.error("end of line"))
+    }
+}
+
+// A scanner for each line of the puzzle input that is not blank, with its leading whitespace
+// skipped. Lines are numbered as in the puzzle input, blank lines included.
+pub fn scan_lines(input_string: &str) -> impl Iterator<Item = Scanner<'_>> {
+    input_string
+        .lines()
+        .enumerate()
+        .filter(|(_index, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let mut scanner = Scanner::new(index + 1, line.trim_end());
+            scanner.skip_whitespace();
+            scanner
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner() {
+        let mut scanner = Scanner::new(1, "pos=<-1,20,3>, r=4");
+        scanner.literal("pos=<").unwrap();
+        assert_eq!(scanner.integer::<i32>(), Ok(-1));
+        scanner.literal(",").unwrap();
+        assert_eq!(scanner.integer::<i32>(), Ok(20));
+        assert_eq!(scanner.column(), 11);
+
+        let error = scanner.literal(">").unwrap_err();
+        assert_eq!(error.column, 11);
+        assert_eq!(error.expected, "\">\"");
+        assert_eq!(error.found, "\",\"");
+
+        assert!(scanner.eat(",3>,"));
+        scanner.skip_whitespace();
+        assert_eq!(scanner.word("a radius"), Ok("r=4"));
+        assert!(scanner.end().is_ok());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut scanner = Scanner::new(3, "pos=<1,x,3>, r=1");
+        scanner.literal("pos=<1,").unwrap();
+
+        let error = scanner.integer::<i32>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 8: expected an integer, found \"x\""
+        );
+        assert_eq!(error.snippet(), "3 | pos=<1,x,3>, r=1\n  |        ^");
+
+        let error = Scanner::new(1, "300").integer::<u8>().unwrap_err();
+        assert_eq!(error.expected, "an integer that fits in u8");
+
+        let mut scanner = Scanner::new(1, "ls");
+        scanner.literal("ls").unwrap();
+        assert_eq!(scanner.word("a name").unwrap_err().found, "end of line");
+    }
+
+    #[test]
+    fn test_scan_lines() {
+        let input_string = "\n  a\n\nb  \n";
+        let lines: Vec<(usize, &str)> = scan_lines(input_string)
+            .map(|scanner| (scanner.line_number(), scanner.rest()))
+            .collect();
+
+        assert_eq!(lines, vec![(2, "a"), (4, "b")]);
+    }
+}
@@ -0,0 +1,171 @@
+// imports
+
+use std::env;
+use std::fmt;
+use std::process;
+
+use crate::input::{load_input, InputSource};
+use crate::parse::ParseError;
+
+// code
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+// An alternative implementation of a part (e.g. a naive version that is kept next to an
+// optimised one), so that both can be benchmarked against each other.
+#[derive(Clone, Copy)]
+pub struct Variant {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&str) -> Option<String>,
+}
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Variant({} {})", self.part, self.name)
+    }
+}
+
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    // Parse the puzzle input without solving anything, so that parsing can be timed on its own,
+    // and so that a malformed input is reported before any part is solved.
+    // Days without a separate parsing step return Ok(false).
+    fn parse(&self, _input_string: &str) -> Result<bool, ParseError> {
+        Ok(false)
+    }
+
+    // Parts that are not solved yet, or that do not exist (e.g. day 25), return None.
+    fn part_1(&self, _input_string: &str) -> Option<String> {
+        None
+    }
+
+    fn part_2(&self, _input_string: &str) -> Option<String> {
+        None
+    }
+
+    fn solve(&self, part: Part, input_string: &str) -> Option<String> {
+        match part {
+            Part::One => self.part_1(input_string),
+            Part::Two => self.part_2(input_string),
+        }
+    }
+
+    fn variants(&self) -> Vec<Variant> {
+        vec![]
+    }
+}
+
+impl fmt::Debug for dyn Solution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Solution({} day {})", self.year(), self.day())
+    }
+}
+
+// Read the puzzle input for the binary of each day, and exit with an error if it cannot be read
+// or parsed. The puzzle input may be given as a path argument, or as - to read it from stdin.
+pub fn read_input(solution: &dyn Solution) -> String {
+    let source = match env::args().nth(1) {
+        Some(arg) => InputSource::from_arg(&arg),
+        None => InputSource::from_env(),
+    };
+
+    let input_string = match load_input(solution, &source) {
+        Ok(input_string) => input_string,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    };
+
+    if let Err(error) = solution.parse(&input_string) {
+        eprintln!("error: {}", error);
+        eprintln!("{}", error.snippet());
+        process::exit(1);
+    }
+
+    input_string
+}
+
+pub fn print_answers(solution: &dyn Solution, input_string: &str) {
+    for part in Part::ALL {
+        if let Some(answer) = solution.solve(part, input_string) {
+            println!("{}: {}", part, answer);
+        }
+    }
+}
+
+// entry point for the binary of each day
+pub fn run(solution: &dyn Solution) {
+    let input_string = read_input(solution);
+    print_answers(solution, &input_string);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        fn year(&self) -> u16 {
+            2015
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, input_string: &str) -> Option<String> {
+            Some(input_string.len().to_string())
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let solution: &dyn Solution = &Example;
+
+        assert_eq!(solution.solve(Part::One, "(())"), Some("4".to_string()));
+        assert_eq!(solution.solve(Part::Two, "(())"), None);
+        assert_eq!(format!("{:?}", solution), "Solution(2015 day 1)");
+    }
+
+    #[test]
+    fn test_parts() {
+        assert_eq!(Part::from_number(1), Some(Part::One));
+        assert_eq!(Part::from_number(3), None);
+        assert_eq!(Part::Two.to_string(), "Part 2");
+    }
+}
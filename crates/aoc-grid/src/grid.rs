@@ -0,0 +1,381 @@
+// imports
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use crate::point::{Point, Transitions};
+
+// code
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridParseError {
+    Empty,
+    // every row of the grid must have the same width
+    RaggedRow {
+        line: usize,
+        expected_width: usize,
+        actual_width: usize,
+    },
+}
+
+impl fmt::Display for GridParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridParseError::Empty => write!(f, "grid is empty"),
+            GridParseError::RaggedRow {
+                line,
+                expected_width,
+                actual_width,
+            } => write!(
+                f,
+                "line {}: expected a row of width {}, but got {}",
+                line, expected_width, actual_width
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridParseError {}
+
+// A dense grid stored in row-major order.
+// The top-left cell is at (0, 0).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height);
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Parse the puzzle format; i.e. one row per line.
+    // Leading and trailing blank lines are skipped, but whitespace within a line is kept since
+    // it may be significant (e.g. the mine cart tracks).
+    pub fn parse<F>(input_string: &str, mut parse_cell: F) -> Result<Self, GridParseError>
+    where
+        F: FnMut(Point, char) -> T,
+    {
+        let lines: Vec<&str> = input_string
+            .trim_matches(|c| c == '\n' || c == '\r')
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .collect();
+
+        if lines.is_empty() || lines[0].is_empty() {
+            return Err(GridParseError::Empty);
+        }
+
+        let width = lines[0].chars().count();
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for (y, line) in lines.iter().enumerate() {
+            let actual_width = line.chars().count();
+            if actual_width != width {
+                return Err(GridParseError::RaggedRow {
+                    line: y + 1,
+                    expected_width: width,
+                    actual_width,
+                });
+            }
+
+            for (x, cell) in line.chars().enumerate() {
+                cells.push(parse_cell(Point::new(x as i32, y as i32), cell));
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn max_x(&self) -> i32 {
+        self.width as i32 - 1
+    }
+
+    pub fn max_y(&self) -> i32 {
+        self.height as i32 - 1
+    }
+
+    pub fn contains<P: Into<Point>>(&self, position: P) -> bool {
+        let position = position.into();
+        0 <= position.x
+            && position.x < self.width as i32
+            && 0 <= position.y
+            && position.y < self.height as i32
+    }
+
+    fn to_index(&self, position: Point) -> Option<usize> {
+        if !self.contains(position) {
+            return None;
+        }
+
+        Some(position.y as usize * self.width + position.x as usize)
+    }
+
+    fn to_point(&self, index: usize) -> Point {
+        Point::new((index % self.width) as i32, (index / self.width) as i32)
+    }
+
+    pub fn get<P: Into<Point>>(&self, position: P) -> Option<&T> {
+        self.to_index(position.into())
+            .map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut<P: Into<Point>>(&mut self, position: P) -> Option<&mut T> {
+        self.to_index(position.into())
+            .map(move |index| &mut self.cells[index])
+    }
+
+    // returns the previous value
+    pub fn set<P: Into<Point>>(&mut self, position: P, value: T) -> Option<T> {
+        self.get_mut(position)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    // all positions of the grid in reading order
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(move |index| self.to_point(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| (self.to_point(index), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> + '_ {
+        let width = self.width;
+        self.cells.iter_mut().enumerate().map(move |(index, cell)| {
+            let point = Point::new((index % width) as i32, (index / width) as i32);
+            (point, cell)
+        })
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    // a grid without columns still has its rows, which are empty
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    // empty if the column is outside the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        let height = if x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self.cells[y * self.width + x])
+    }
+
+    // orthogonally adjacent positions that are within the grid, in reading order
+    pub fn neighbours_4<P: Into<Point>>(&self, position: P) -> impl Iterator<Item = Point> + '_ {
+        position
+            .into()
+            .neighbours_4()
+            .filter(move |neighbour| self.contains(*neighbour))
+    }
+
+    // surrounding positions (including diagonals) that are within the grid, in reading order
+    pub fn neighbours_8<P: Into<Point>>(&self, position: P) -> impl Iterator<Item = Point> + '_ {
+        position
+            .into()
+            .neighbours_8()
+            .filter(move |neighbour| self.contains(*neighbour))
+    }
+
+    pub fn position<F>(&self, predicate: F) -> Option<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| self.to_point(index))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T, P: Into<Point>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &T {
+        let position = position.into();
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {} is out of bounds of grid with size {}x{}",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T, P: Into<Point>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut T {
+        let position = position.into();
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!(
+                "position {} is out of bounds of grid with size {}x{}",
+                position, width, height
+            ),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridParseError;
+
+    fn from_str(input_string: &str) -> Result<Self, Self::Err> {
+        Grid::parse(input_string, |_position, cell| cell)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let input_string = r###"
+#.#
+.##
+...
+"###;
+
+        let grid: Grid<char> = input_string.parse().unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid[(1, 0)], '.');
+        assert_eq!(grid[Point::new(2, 1)], '#');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, -1)), None);
+        assert_eq!(grid.to_string(), input_string.trim());
+
+        let column: String = grid.column(1).collect();
+        assert_eq!(column, ".#.");
+
+        let walls = Grid::parse(input_string, |_position, cell| cell == '#').unwrap();
+        assert_eq!(walls.values().filter(|wall| **wall).count(), 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<Grid<char>>(), Err(GridParseError::Empty));
+        assert_eq!(
+            "##\n#\n".parse::<Grid<char>>(),
+            Err(GridParseError::RaggedRow {
+                line: 2,
+                expected_width: 2,
+                actual_width: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_zero_width() {
+        let grid = Grid::new(0, 3, '.');
+        assert_eq!(grid.rows().count(), 3);
+        assert!(grid.rows().all(|row| row.is_empty()));
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.to_string(), "\n\n");
+
+        let grid: Grid<char> = Grid::from_vec(0, 0, vec![]);
+        assert_eq!(grid.to_string(), "");
+
+        // a column past the right edge does not wrap around into the next row
+        let grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert_eq!(grid.column(2).count(), 0);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b'], &['c', 'd']]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        let neighbours: Vec<Point> = grid.neighbours_4((0, 0)).collect();
+        assert_eq!(neighbours, vec![Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours_8((1, 0)).count(), 5);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_mutation() {
+        let mut grid = Grid::new(2, 2, '.');
+        assert_eq!(grid.set((1, 1), '#'), Some('.'));
+        grid[(0, 1)] = '#';
+        assert_eq!(grid.position(|cell| *cell == '#'), Some(Point::new(0, 1)));
+        assert_eq!(grid.to_string(), "..\n##");
+        assert_eq!(
+            grid.map(|cell| *cell == '#')
+                .values()
+                .filter(|x| **x)
+                .count(),
+            2
+        );
+    }
+}
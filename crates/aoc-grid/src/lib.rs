@@ -0,0 +1,7 @@
+// Grid primitives shared by the puzzles that walk around a 2D map.
+
+mod grid;
+mod point;
+
+pub use grid::{Grid, GridParseError};
+pub use point::{reading_order, BoundsCheck, Coordinate, Neighbours, Point, Transitions, Vector};
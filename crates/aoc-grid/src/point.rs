@@ -0,0 +1,353 @@
+// imports
+
+use core::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// code
+
+// Screen coordinates: x grows to the right (east), y grows downwards (south).
+// This matches the order in which the puzzle inputs are read line by line.
+pub type Coordinate = (i32, i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> i32 {
+        (*other - *self).manhattan_length()
+    }
+
+    pub fn to_coordinate(self) -> Coordinate {
+        (self.x, self.y)
+    }
+}
+
+// points are ordered according to their reading order; i.e. top-to-bottom, then left-to-right
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        reading_order(&self.to_coordinate(), &other.to_coordinate())
+    }
+}
+
+impl From<Coordinate> for Point {
+    fn from((x, y): Coordinate) -> Self {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for Coordinate {
+    fn from(point: Point) -> Self {
+        point.to_coordinate()
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// sort coordinates according to their reading order
+pub fn reading_order(first_coord: &Coordinate, second_coord: &Coordinate) -> Ordering {
+    let (x1, y1) = first_coord;
+    let (x2, y2) = second_coord;
+
+    if y1 != y2 {
+        return y1.cmp(y2);
+    }
+
+    x1.cmp(x2)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector {
+    pub dx: i32,
+    pub dy: i32,
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { dx: 0, dy: 0 };
+    pub const UP: Vector = Vector { dx: 0, dy: -1 };
+    pub const DOWN: Vector = Vector { dx: 0, dy: 1 };
+    pub const LEFT: Vector = Vector { dx: -1, dy: 0 };
+    pub const RIGHT: Vector = Vector { dx: 1, dy: 0 };
+
+    pub const NORTH: Vector = Vector::UP;
+    pub const SOUTH: Vector = Vector::DOWN;
+    pub const WEST: Vector = Vector::LEFT;
+    pub const EAST: Vector = Vector::RIGHT;
+
+    // listed in reading order
+    pub const ORTHOGONAL: [Vector; 4] = [Vector::UP, Vector::LEFT, Vector::RIGHT, Vector::DOWN];
+
+    // listed in reading order
+    pub const ALL: [Vector; 8] = [
+        Vector::new(-1, -1),
+        Vector::UP,
+        Vector::new(1, -1),
+        Vector::LEFT,
+        Vector::RIGHT,
+        Vector::new(-1, 1),
+        Vector::DOWN,
+        Vector::new(1, 1),
+    ];
+
+    pub const fn new(dx: i32, dy: i32) -> Self {
+        Vector { dx, dy }
+    }
+
+    pub fn manhattan_length(&self) -> i32 {
+        self.dx.abs() + self.dy.abs()
+    }
+
+    // rotate by 90 degrees counter-clockwise (as seen on screen)
+    pub fn turn_left(&self) -> Self {
+        Vector::new(self.dy, -self.dx)
+    }
+
+    // rotate by 90 degrees clockwise (as seen on screen)
+    pub fn turn_right(&self) -> Self {
+        Vector::new(-self.dy, self.dx)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.x + vector.dx, self.y + vector.dy)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + (-vector)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector;
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add<Vector> for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.dx + other.dx, self.dy + other.dy)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.dx, -self.dy)
+    }
+}
+
+impl Mul<i32> for Vector {
+    type Output = Vector;
+
+    fn mul(self, scalar: i32) -> Vector {
+        Vector::new(self.dx * scalar, self.dy * scalar)
+    }
+}
+
+pub trait Transitions: Sized + Copy {
+    fn translate(&self, vector: Vector) -> Self;
+
+    fn up(&self) -> Self {
+        self.translate(Vector::UP)
+    }
+
+    fn down(&self) -> Self {
+        self.translate(Vector::DOWN)
+    }
+
+    fn left(&self) -> Self {
+        self.translate(Vector::LEFT)
+    }
+
+    fn right(&self) -> Self {
+        self.translate(Vector::RIGHT)
+    }
+
+    fn north(&self) -> Self {
+        self.up()
+    }
+
+    fn south(&self) -> Self {
+        self.down()
+    }
+
+    fn west(&self) -> Self {
+        self.left()
+    }
+
+    fn east(&self) -> Self {
+        self.right()
+    }
+
+    // up, left, right, down; i.e. the orthogonally adjacent positions in reading order
+    fn neighbours_4(&self) -> Neighbours<Self> {
+        Neighbours::new(*self, &Vector::ORTHOGONAL)
+    }
+
+    // all eight surrounding positions in reading order
+    fn neighbours_8(&self) -> Neighbours<Self> {
+        Neighbours::new(*self, &Vector::ALL)
+    }
+}
+
+impl Transitions for Point {
+    fn translate(&self, vector: Vector) -> Self {
+        *self + vector
+    }
+}
+
+impl Transitions for Coordinate {
+    fn translate(&self, vector: Vector) -> Self {
+        let (x, y) = self;
+        (x + vector.dx, y + vector.dy)
+    }
+}
+
+pub trait BoundsCheck {
+    // bounds are inclusive
+    fn within_bounds(&self, max_x: i32, max_y: i32) -> bool;
+}
+
+impl BoundsCheck for Coordinate {
+    fn within_bounds(&self, max_x: i32, max_y: i32) -> bool {
+        let (x, y) = self;
+        let x_bounds = 0 <= *x && *x <= max_x;
+        let y_bounds = 0 <= *y && *y <= max_y;
+
+        x_bounds && y_bounds
+    }
+}
+
+impl BoundsCheck for Point {
+    fn within_bounds(&self, max_x: i32, max_y: i32) -> bool {
+        self.to_coordinate().within_bounds(max_x, max_y)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Neighbours<P> {
+    origin: P,
+    offsets: std::slice::Iter<'static, Vector>,
+}
+
+impl<P> Neighbours<P> {
+    fn new(origin: P, offsets: &'static [Vector]) -> Self {
+        Neighbours {
+            origin,
+            offsets: offsets.iter(),
+        }
+    }
+}
+
+impl<P: Transitions> Iterator for Neighbours<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        self.offsets
+            .next()
+            .map(|offset| self.origin.translate(*offset))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<P: Transitions> ExactSizeIterator for Neighbours<P> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transitions() {
+        let coord: Coordinate = (3, 3);
+        assert_eq!(coord.up(), (3, 2));
+        assert_eq!(coord.down(), (3, 4));
+        assert_eq!(coord.left(), (2, 3));
+        assert_eq!(coord.right(), (4, 3));
+        assert_eq!(coord.north().west(), (2, 2));
+
+        let point = Point::new(3, 3);
+        assert_eq!(point.south().east(), Point::new(4, 4));
+        assert_eq!(Point::from(coord.up()), point.up());
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let neighbours: Vec<Coordinate> = (1, 1).neighbours_4().collect();
+        assert_eq!(neighbours, vec![(1, 0), (0, 1), (2, 1), (1, 2)]);
+
+        let mut neighbours: Vec<Point> = Point::new(1, 1).neighbours_8().collect();
+        assert_eq!(neighbours.len(), 8);
+        let sorted = {
+            let mut sorted = neighbours.clone();
+            sorted.sort();
+            sorted
+        };
+        assert_eq!(neighbours, sorted);
+        neighbours.dedup();
+        assert_eq!(neighbours.len(), 8);
+    }
+
+    #[test]
+    fn test_vectors() {
+        assert_eq!(Vector::UP.turn_right(), Vector::RIGHT);
+        assert_eq!(Vector::UP.turn_left(), Vector::LEFT);
+        assert_eq!(Vector::RIGHT.turn_right(), Vector::DOWN);
+        assert_eq!(Point::new(1, 2) + Vector::RIGHT * 3, Point::new(4, 2));
+        assert_eq!(Point::new(4, 6) - Point::new(1, 2), Vector::new(3, 4));
+        assert_eq!(Point::new(4, 6).manhattan_distance(&Point::new(1, 2)), 7);
+    }
+
+    #[test]
+    fn test_bounds_check() {
+        assert!((0, 0).within_bounds(2, 2));
+        assert!((2, 2).within_bounds(2, 2));
+        assert!(!(3, 2).within_bounds(2, 2));
+        assert!(!Point::new(-1, 0).within_bounds(2, 2));
+    }
+}
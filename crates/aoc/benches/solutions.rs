@@ -0,0 +1,225 @@
+// imports
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use aoc::registry;
+use aoc_core::{load_input, Answers, InputSource, Part, Solution};
+use criterion::{Criterion, SamplingMode};
+use serde::Deserialize;
+
+// code
+
+// a change of the mean that is certainly beyond this fraction of the baseline is flagged
+const NOISE_THRESHOLD: f64 = 0.05;
+
+#[derive(Deserialize)]
+struct ConfidenceInterval {
+    lower_bound: f64,
+    upper_bound: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    confidence_interval: ConfidenceInterval,
+    point_estimate: f64,
+}
+
+// the subset of criterion's estimates.json (both new/ and change/) that the summary uses
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+}
+
+struct Row {
+    name: String,
+    // nanoseconds
+    mean: f64,
+    // relative to the previous run, or to the baseline given with --baseline
+    change: Option<Estimate>,
+}
+
+// The same directory that criterion picks by default, so that baselines saved by earlier runs are
+// found regardless of which directory cargo bench is run from.
+fn output_directory() -> PathBuf {
+    if let Some(dir) = env::var_os("CRITERION_HOME") {
+        return PathBuf::from(dir);
+    }
+
+    let target_dir = match env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        // this crate lives at <root>/crates/aoc
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../../target"),
+    };
+
+    target_dir.join("criterion")
+}
+
+fn group_name(solution: &dyn Solution) -> String {
+    format!("{}-day-{:02}", solution.year(), solution.day())
+}
+
+fn part_name(part: Part) -> String {
+    format!("part-{}", part.number())
+}
+
+// Estimates written by an earlier run (or skipped by a filter) are ignored.
+fn read_estimates(path: &Path, since: SystemTime) -> Option<Estimates> {
+    let modified = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()?;
+    if modified < since {
+        return None;
+    }
+
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+fn format_nanoseconds(nanoseconds: f64) -> String {
+    if nanoseconds >= 1e9 {
+        format!("{:.2}s", nanoseconds / 1e9)
+    } else if nanoseconds >= 1e6 {
+        format!("{:.2}ms", nanoseconds / 1e6)
+    } else if nanoseconds >= 1e3 {
+        format!("{:.2}µs", nanoseconds / 1e3)
+    } else {
+        format!("{:.0}ns", nanoseconds)
+    }
+}
+
+fn format_change(change: &Estimate) -> String {
+    let verdict = if change.confidence_interval.lower_bound > NOISE_THRESHOLD {
+        "  slower"
+    } else if change.confidence_interval.upper_bound < -NOISE_THRESHOLD {
+        "  faster"
+    } else {
+        ""
+    };
+
+    format!("{:+.2}%{}", change.point_estimate * 100.0, verdict)
+}
+
+fn print_summary(benchmarks: &[String], output_directory: &Path, since: SystemTime) {
+    let mut rows: Vec<Row> = benchmarks
+        .iter()
+        .filter_map(|name| {
+            let dir = output_directory.join(name);
+            let estimates = read_estimates(&dir.join("new/estimates.json"), since)?;
+            let change = read_estimates(&dir.join("change/estimates.json"), since);
+
+            Some(Row {
+                name: name.clone(),
+                mean: estimates.mean.point_estimate,
+                change: change.map(|change| change.mean),
+            })
+        })
+        .collect();
+
+    // e.g. when only listing the benchmarks, or when running them as tests
+    if rows.is_empty() {
+        return;
+    }
+
+    rows.sort_by(|a, b| b.mean.total_cmp(&a.mean));
+
+    let name_width = rows.iter().map(|row| row.name.len()).max().unwrap();
+
+    println!();
+    println!("{:<name_width$}  {:>10}  change", "benchmark", "mean");
+    for row in rows {
+        let line = format!(
+            "{:<name_width$}  {:>10}  {}",
+            row.name,
+            format_nanoseconds(row.mean),
+            row.change.as_ref().map_or(String::new(), format_change)
+        );
+        println!("{}", line.trim_end());
+    }
+}
+
+// Benchmark the parsing, both parts and the alternative implementations of every registered day.
+// Only parts with a recorded answer are benchmarked, so unsolved parts are skipped.
+fn bench_solutions(c: &mut Criterion) -> Vec<String> {
+    let source = InputSource::from_env();
+    let mut answers: BTreeMap<u16, Answers> = BTreeMap::new();
+    let mut benchmarks = vec![];
+
+    for solution in registry::SOLUTIONS.iter().copied() {
+        let year_answers = answers
+            .entry(solution.year())
+            .or_insert_with(|| Answers::load(solution.year()).unwrap());
+        let is_solved = |part: Part| year_answers.get(solution.day(), part).is_some();
+
+        let input_string = match load_input(solution, &source) {
+            Ok(input_string) => input_string,
+            Err(error) => {
+                eprintln!("skipping {}: {}", group_name(solution), error);
+                continue;
+            }
+        };
+        let input_string = input_string.as_str();
+
+        let has_parsing_step = match solution.parse(input_string) {
+            Ok(has_parsing_step) => has_parsing_step,
+            Err(error) => {
+                eprintln!("skipping {}: {}", group_name(solution), error);
+                continue;
+            }
+        };
+
+        let group_id = group_name(solution);
+        let mut group = c.benchmark_group(&group_id);
+        // every sample runs the same number of iterations, which keeps the slow parts bounded
+        group.sampling_mode(SamplingMode::Flat);
+
+        if has_parsing_step {
+            group.bench_function("parse", |b| {
+                b.iter(|| solution.parse(black_box(input_string)))
+            });
+            benchmarks.push(format!("{}/parse", group_id));
+        }
+
+        let variants = solution.variants();
+
+        for part in Part::ALL.into_iter().filter(|part| is_solved(*part)) {
+            group.bench_function(part_name(part), |b| {
+                b.iter(|| solution.solve(part, black_box(input_string)))
+            });
+            benchmarks.push(format!("{}/{}", group_id, part_name(part)));
+
+            for variant in variants.iter().filter(|variant| variant.part == part) {
+                let name = format!("{}-{}", part_name(part), variant.name);
+                group.bench_function(&name, |b| {
+                    b.iter(|| (variant.solve)(black_box(input_string)))
+                });
+                benchmarks.push(format!("{}/{}", group_id, name));
+            }
+        }
+
+        group.finish();
+    }
+
+    benchmarks
+}
+
+fn main() {
+    let output_directory = output_directory();
+    let since = SystemTime::now();
+
+    // Some parts take seconds to solve, so fewer samples are taken than criterion's default.
+    // Both can still be overridden from the command line, e.g. --sample-size 50
+    let mut c = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(Duration::from_secs(1))
+        .output_directory(&output_directory)
+        .configure_from_args();
+
+    let benchmarks = bench_solutions(&mut c);
+
+    c.final_summary();
+    print_summary(&benchmarks, &output_directory, since);
+}
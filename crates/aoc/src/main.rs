@@ -0,0 +1,274 @@
+// imports
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+use aoc::{format_duration, registry, verify};
+use aoc_core::{load_input, Answers, InputSource, Part, Solution};
+use clap::{Parser, Subcommand};
+
+// code
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "aoc",
+    about = "Run the Advent of Code solutions of this repository"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(about = "Run the solutions of a year, or of a single day")]
+    Run {
+        year: u16,
+
+        day: Option<u8>,
+
+        #[arg(
+            long,
+            value_parser = clap::value_parser!(u8).range(1..=2),
+            help = "Only run the given part (1 or 2)"
+        )]
+        part: Option<u8>,
+
+        #[arg(
+            long,
+            requires = "day",
+            conflicts_with = "inputs",
+            help = "Read the puzzle input from this file, or from stdin if it is -"
+        )]
+        input: Option<String>,
+
+        #[arg(
+            long,
+            env = aoc_core::INPUTS_DIR_ENV,
+            help = "Read the puzzle inputs from <inputs>/<year>/day-<day>.txt when present"
+        )]
+        inputs: Option<PathBuf>,
+    },
+
+    #[command(about = "Compare the answers of the solutions against the recorded answers")]
+    Verify {
+        #[arg(help = "Only verify the solutions of the given year")]
+        year: Option<u16>,
+
+        #[arg(requires = "year", help = "Only verify the solution of the given day")]
+        day: Option<u8>,
+    },
+
+    #[command(about = "List the available solutions")]
+    List {
+        #[arg(help = "Only list the solutions of the given year")]
+        year: Option<u16>,
+    },
+}
+
+fn run_solution(solution: &dyn Solution, parts: &[Part], source: &InputSource) -> bool {
+    println!("{} day {}", solution.year(), solution.day());
+
+    let input_string = match load_input(solution, source) {
+        Ok(input_string) => input_string,
+        Err(error) => {
+            println!("  error: {}", error);
+            return false;
+        }
+    };
+
+    if let Err(error) = solution.parse(&input_string) {
+        println!("  error: {}", error);
+        for line in error.snippet().lines() {
+            println!("    {}", line);
+        }
+        return false;
+    }
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = solution.solve(*part, &input_string);
+        let elapsed = start.elapsed();
+
+        match answer {
+            Some(answer) => println!("  {}: {} ({})", part, answer, format_duration(elapsed)),
+            None => println!("  {}: not solved", part),
+        }
+    }
+
+    true
+}
+
+fn run(year: u16, day: Option<u8>, part: Option<u8>, source: InputSource) -> Result<(), String> {
+    let parts: Vec<Part> = match part {
+        Some(part) => vec![Part::from_number(part).unwrap()],
+        None => Part::ALL.to_vec(),
+    };
+
+    let solutions: Vec<&dyn Solution> = match day {
+        Some(day) => match registry::find(year, day) {
+            Some(solution) => vec![solution],
+            None => return Err(format!("no solution for {} day {}", year, day)),
+        },
+        None => registry::for_year(year).collect(),
+    };
+
+    if solutions.is_empty() {
+        return Err(format!("no solutions for {}", year));
+    }
+
+    let num_of_failures = solutions
+        .into_iter()
+        .filter(|solution| !run_solution(*solution, &parts, &source))
+        .count();
+
+    if num_of_failures > 0 {
+        return Err(format!(
+            "unable to load or parse {} puzzle input(s)",
+            num_of_failures
+        ));
+    }
+
+    Ok(())
+}
+
+fn verify(year: Option<u16>, day: Option<u8>) -> Result<(), String> {
+    let solutions: Vec<&dyn Solution> = registry::SOLUTIONS
+        .iter()
+        .copied()
+        .filter(|solution| year.is_none_or(|year| solution.year() == year))
+        .filter(|solution| day.is_none_or(|day| solution.day() == day))
+        .collect();
+
+    if solutions.is_empty() {
+        return Err("no solutions to verify".to_string());
+    }
+
+    let mut answers: BTreeMap<u16, Answers> = BTreeMap::new();
+    for year in registry::years() {
+        if solutions.iter().any(|solution| solution.year() == year) {
+            let year_answers = Answers::load(year).map_err(|error| error.to_string())?;
+            answers.insert(year, year_answers);
+        }
+    }
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    println!("{}", verify::HEADER);
+
+    for solution in solutions {
+        for verification in verify::verify_solution(solution, &answers[&solution.year()]) {
+            println!("{}", verification.to_row());
+
+            match verification.status {
+                verify::Status::Pass => passed += 1,
+                verify::Status::Missing { .. } => missing += 1,
+                verify::Status::Fail { .. } | verify::Status::Error(_) => failed += 1,
+            }
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed > 0 {
+        return Err(format!("{} part(s) failed verification", failed));
+    }
+
+    Ok(())
+}
+
+fn list(year: Option<u16>) {
+    let solutions = registry::SOLUTIONS
+        .iter()
+        .filter(|solution| year.is_none_or(|year| solution.year() == year));
+
+    for solution in solutions {
+        println!(
+            "{} day {:02}  https://adventofcode.com/{}/day/{}",
+            solution.year(),
+            solution.day(),
+            solution.year(),
+            solution.day()
+        );
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            inputs,
+        } => {
+            let source = match (input, inputs) {
+                (Some(input), _) => InputSource::from_arg(&input),
+                (None, Some(inputs)) => InputSource::Directory(inputs),
+                (None, None) => InputSource::Default,
+            };
+
+            run(year, day, part, source)
+        }
+        Command::Verify { year, day } => verify(year, day),
+        Command::List { year } => {
+            list(year);
+            Ok(())
+        }
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli() {
+        let cli = Cli::try_parse_from(["aoc", "run", "2018", "15", "--part", "2"]).unwrap();
+        match cli.command {
+            Command::Run {
+                year, day, part, ..
+            } => {
+                assert_eq!(year, 2018);
+                assert_eq!(day, Some(15));
+                assert_eq!(part, Some(2));
+            }
+            _ => panic!("expected the run command"),
+        }
+
+        assert!(Cli::try_parse_from(["aoc", "run", "2018", "15", "--part", "3"]).is_err());
+
+        // an input file only makes sense for a single day
+        assert!(Cli::try_parse_from(["aoc", "run", "2018", "--input", "input.txt"]).is_err());
+    }
+
+    #[test]
+    fn test_run_unknown_day() {
+        assert!(run(2018, Some(26), None, InputSource::Default).is_err());
+        assert!(run(1999, None, None, InputSource::Default).is_err());
+
+        let missing = InputSource::File(PathBuf::from("does-not-exist.txt"));
+        assert!(run(2018, Some(1), None, missing).is_err());
+    }
+
+    #[test]
+    fn test_run_malformed_input() {
+        let path = std::env::temp_dir().join("aoc-malformed-2018-day-23.txt");
+        std::fs::write(&path, "pos=<0,0,0>, r=4\npos=<1,x,0>, r=1\n").unwrap();
+
+        let result = run(2018, Some(23), None, InputSource::File(path.clone()));
+        std::fs::remove_file(path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,127 @@
+// imports
+
+use aoc_core::Solution;
+
+// code
+
+// every solution in the workspace, ordered by year and then by day
+pub static SOLUTIONS: &[&dyn Solution] = &[
+    // 2015
+    &day_01_2015::Day01,
+    &day_02_2015::Day02,
+    &day_03_2015::Day03,
+    &day_04_2015::Day04,
+    &day_05_2015::Day05,
+    // 2018
+    &day_01_2018::Day01,
+    &day_02_2018::Day02,
+    &day_03_2018::Day03,
+    &day_04_2018::Day04,
+    &day_05_2018::Day05,
+    &day_06_2018::Day06,
+    &day_07_2018::Day07,
+    &day_08_2018::Day08,
+    &day_09_2018::Day09,
+    &day_10_2018::Day10,
+    &day_11_2018::Day11,
+    &day_12_2018::Day12,
+    &day_13_2018::Day13,
+    &day_14_2018::Day14,
+    &day_15_2018::Day15,
+    &day_16_2018::Day16,
+    &day_17_2018::Day17,
+    &day_18_2018::Day18,
+    &day_19_2018::Day19,
+    &day_20_2018::Day20,
+    &day_21_2018::Day21,
+    &day_22_2018::Day22,
+    &day_23_2018::Day23,
+    &day_24_2018::Day24,
+    &day_25_2018::Day25,
+    // 2019
+    &day_01_2019::Day01,
+    &day_02_2019::Day02,
+    &day_03_2019::Day03,
+    &day_04_2019::Day04,
+    &day_05_2019::Day05,
+    // 2020
+    &day_01_2020::Day01,
+    &day_02_2020::Day02,
+    &day_03_2020::Day03,
+    // 2022
+    &day_01_2022::Day01,
+    &day_02_2022::Day02,
+    &day_03_2022::Day03,
+    &day_04_2022::Day04,
+    &day_05_2022::Day05,
+    &day_06_2022::Day06,
+    &day_07_2022::Day07,
+    &day_08_2022::Day08,
+    &day_09_2022::Day09,
+    // 2023
+    &day_01_2023::Day01,
+    &day_02_2023::Day02,
+    &day_03_2023::Day03,
+    &day_04_2023::Day04,
+    &day_05_2023::Day05,
+    &day_06_2023::Day06,
+    &day_07_2023::Day07,
+    &day_08_2023::Day08,
+    &day_09_2023::Day09,
+    &day_10_2023::Day10,
+    &day_11_2023::Day11,
+    &day_12_2023::Day12,
+    &day_13_2023::Day13,
+    &day_14_2023::Day14,
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|solution| solution.year() == year && solution.day() == day)
+}
+
+// the years that have at least one solution, in ascending order
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = SOLUTIONS.iter().map(|solution| solution.year()).collect();
+    years.dedup();
+    years
+}
+
+pub fn for_year(year: u16) -> impl Iterator<Item = &'static dyn Solution> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .filter(move |solution| solution.year() == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_is_ordered() {
+        let keys: Vec<(u16, u8)> = SOLUTIONS
+            .iter()
+            .map(|solution| (solution.year(), solution.day()))
+            .collect();
+
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_find() {
+        let solution = find(2018, 15).unwrap();
+        assert_eq!(solution.year(), 2018);
+        assert_eq!(solution.day(), 15);
+
+        assert!(find(2018, 26).is_none());
+        assert_eq!(for_year(2018).count(), 25);
+        assert_eq!(years(), vec![2015, 2018, 2019, 2020, 2022, 2023]);
+    }
+}
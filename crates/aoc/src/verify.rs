@@ -0,0 +1,242 @@
+// imports
+
+use std::time::{Duration, Instant};
+
+use aoc_core::{load_input, matches_answer, Answers, InputSource, Part, Solution};
+
+// code
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    // the part is solved, but there is no recorded answer to compare it to
+    Missing {
+        actual: String,
+    },
+    Error(String),
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing { .. } => "missing",
+            Status::Error(_) => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+impl Verification {
+    pub fn to_row(&self) -> String {
+        let details = match &self.status {
+            Status::Pass => String::new(),
+            Status::Fail { expected, actual } => format!(
+                "expected {}, got {}",
+                single_line(expected),
+                actual.as_deref().map_or("nothing".to_string(), single_line)
+            ),
+            Status::Missing { actual } => format!("got {}", single_line(actual)),
+            Status::Error(error) => error.clone(),
+        };
+
+        format!(
+            "{:>4} {:>3} {:>4}  {:<7} {:>9}  {}",
+            self.year,
+            self.day,
+            self.part.number(),
+            self.status.label(),
+            crate::format_duration(self.elapsed),
+            details
+        )
+        .trim_end()
+        .to_string()
+    }
+}
+
+pub const HEADER: &str = "year day part  status       time  details";
+
+// multi-line answers (e.g. the stars drawn by 2018 day 10) are squashed into one row of the table
+fn single_line(answer: &str) -> String {
+    answer.trim().replace('\n', "\\n")
+}
+
+// Parts that are neither solved nor recorded are skipped.
+pub fn verify_solution(solution: &dyn Solution, answers: &Answers) -> Vec<Verification> {
+    let (year, day) = (solution.year(), solution.day());
+
+    let input = load_input(solution, &InputSource::Default)
+        .map_err(|error| error.to_string())
+        .and_then(|input_string| match solution.parse(&input_string) {
+            Ok(_) => Ok(input_string),
+            Err(error) => Err(error.to_string()),
+        });
+
+    let input_string = match input {
+        Ok(input_string) => input_string,
+        Err(error) => {
+            return Part::ALL
+                .iter()
+                .filter(|part| answers.get(day, **part).is_some())
+                .map(|part| Verification {
+                    year,
+                    day,
+                    part: *part,
+                    status: Status::Error(error.clone()),
+                    elapsed: Duration::ZERO,
+                })
+                .collect();
+        }
+    };
+
+    let mut verifications = vec![];
+
+    for part in Part::ALL {
+        let start = Instant::now();
+        let actual = solution.solve(part, &input_string);
+        let elapsed = start.elapsed();
+
+        let status = match (answers.get(day, part), actual) {
+            (None, None) => continue,
+            (None, Some(actual)) => Status::Missing { actual },
+            (Some(expected), Some(actual)) if matches_answer(expected, &actual) => Status::Pass,
+            (Some(expected), actual) => Status::Fail {
+                expected: expected.to_string(),
+                actual,
+            },
+        };
+
+        verifications.push(Verification {
+            year,
+            day,
+            part,
+            status,
+            elapsed,
+        });
+    }
+
+    verifications
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{ParseError, Scanner};
+    use std::path::Path;
+
+    struct Example;
+
+    impl Solution for Example {
+        fn year(&self) -> u16 {
+            2018
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn part_1(&self, _input_string: &str) -> Option<String> {
+            Some("513".to_string())
+        }
+
+        fn part_2(&self, _input_string: &str) -> Option<String> {
+            Some("42".to_string())
+        }
+    }
+
+    fn statuses(answers: &str) -> Vec<Status> {
+        let answers = Answers::parse(Path::new("answers.toml"), answers).unwrap();
+        verify_solution(&Example, &answers)
+            .into_iter()
+            .map(|verification| verification.status)
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_solution() {
+        assert_eq!(
+            statuses("[day-01]\npart-1 = \"513\"\npart-2 = \"287\""),
+            vec![
+                Status::Pass,
+                Status::Fail {
+                    expected: "287".to_string(),
+                    actual: Some("42".to_string())
+                }
+            ]
+        );
+
+        assert_eq!(
+            statuses("[day-01]\npart-1 = \"513\""),
+            vec![
+                Status::Pass,
+                Status::Missing {
+                    actual: "42".to_string()
+                }
+            ]
+        );
+    }
+
+    struct Malformed;
+
+    impl Solution for Malformed {
+        fn year(&self) -> u16 {
+            2018
+        }
+
+        fn day(&self) -> u8 {
+            1
+        }
+
+        fn parse(&self, input_string: &str) -> Result<bool, ParseError> {
+            Err(Scanner::new(1, input_string.lines().next().unwrap()).error("nothing"))
+        }
+
+        fn part_1(&self, _input_string: &str) -> Option<String> {
+            Some("513".to_string())
+        }
+    }
+
+    #[test]
+    fn test_verify_malformed_input() {
+        let answers =
+            Answers::parse(Path::new("answers.toml"), "[day-01]\npart-1 = \"513\"").unwrap();
+        let verifications = verify_solution(&Malformed, &answers);
+
+        assert_eq!(verifications.len(), 1);
+        match &verifications[0].status {
+            Status::Error(error) => {
+                assert!(error.starts_with("line 1, column 1: expected nothing"))
+            }
+            status => panic!("expected an error, got {:?}", status),
+        }
+    }
+
+    #[test]
+    fn test_recorded_answers() {
+        // every recorded answer belongs to a registered solution
+        for year in crate::registry::years() {
+            let answers = Answers::load(year).unwrap();
+            for day in answers.days() {
+                assert!(
+                    crate::registry::find(year, day).is_some(),
+                    "{} day {} has recorded answers, but no solution",
+                    year,
+                    day
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// Simulations that step a state until it repeats, shared by the puzzles that ask for the state
+// after far more steps than can be simulated.
+// https://adventofcode.com/2018/day/12
+// https://adventofcode.com/2018/day/18
+// https://adventofcode.com/2023/day/14
+
+mod simulation;
+
+pub use simulation::{Algorithm, Cycle, Simulation};
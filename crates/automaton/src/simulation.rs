@@ -0,0 +1,466 @@
+// imports
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+// code
+
+// The states of a simulation repeat from the state after start steps onwards, every length steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The number of steps, before the cycle or within its first pass, that end in the same state
+    // as the given number of steps; and the number of passes through the cycle that were skipped.
+    pub fn reduce(&self, steps: u64) -> (usize, u64) {
+        let start = self.start as u64;
+
+        if steps < start {
+            return (steps as usize, 0);
+        }
+
+        let length = self.length as u64;
+        let into_cycle = steps - start;
+
+        ((start + into_cycle % length) as usize, into_cycle / length)
+    }
+}
+
+// how a cycle is found; both only keep a couple of states around
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Algorithm {
+    // a state that is moved ahead to the latest power of two steps, which finds the length of
+    // the cycle first, in fewer steps
+    Brent,
+    // a tortoise and a hare that takes two steps for every step of the tortoise
+    Floyd,
+}
+
+// The key of a state, and its hash; the hashes are compared first, and the keys only when they
+// are equal.
+struct Fingerprint<K> {
+    hash: u64,
+    key: K,
+}
+
+impl<K: Hash + Eq> Fingerprint<K> {
+    fn new(key: K) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        Fingerprint {
+            hash: hasher.finish(),
+            key,
+        }
+    }
+
+    fn is_same(&self, other: &Fingerprint<K>) -> bool {
+        self.hash == other.hash && self.key == other.key
+    }
+}
+
+// a state from the start of the cycle onwards, and the number of steps to it
+struct InCycle<S> {
+    state: S,
+    steps: usize,
+}
+
+// A state that steps in place, and the key of a state, where states with the same key are taken
+// to be the same; e.g. the state itself, or a pattern that drifts along with its offset left out.
+pub struct Simulation<S, F, K> {
+    initial: S,
+    step: F,
+    key: K,
+    algorithm: Algorithm,
+    // the most steps that are taken while looking for a cycle
+    step_limit: usize,
+}
+
+impl<S, F, K, Key> Simulation<S, F, K>
+where
+    S: Clone,
+    F: Fn(&mut S),
+    K: Fn(&S) -> Key,
+    Key: Hash + Eq,
+{
+    pub fn new(initial: S, step: F, key: K) -> Self {
+        Simulation {
+            initial,
+            step,
+            key,
+            algorithm: Algorithm::Brent,
+            step_limit: usize::MAX,
+        }
+    }
+
+    pub fn with_algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    pub fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    fn fingerprint(&self, state: &S) -> Fingerprint<Key> {
+        Fingerprint::new((self.key)(state))
+    }
+
+    fn advance(&self, state: &mut S, steps: usize) {
+        for _ in 0..steps {
+            (self.step)(state);
+        }
+    }
+
+    // the state after the given number of steps, simulated one step at a time
+    pub fn run(&self, steps: usize) -> S {
+        let mut state = self.initial.clone();
+        self.advance(&mut state, steps);
+        state
+    }
+
+    // None if the states do not repeat within the step limit
+    pub fn find_cycle(&self) -> Option<Cycle> {
+        let (cycle, _in_cycle) = self.find_cycle_and_a_state_in_it()?;
+        Some(cycle)
+    }
+
+    // the cycle, and a state in it that was found on the way; which saves simulating the steps
+    // before the cycle again
+    fn find_cycle_and_a_state_in_it(&self) -> Option<(Cycle, InCycle<S>)> {
+        match self.algorithm {
+            Algorithm::Brent => self.find_cycle_brent(),
+            Algorithm::Floyd => self.find_cycle_floyd(),
+        }
+    }
+
+    // https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm
+    //
+    // The hashes of the states that the hare went through are kept, so that the keys are only
+    // compared where the hashes match, once the states before the cycle are stepped through
+    // again to find where it starts.
+    fn find_cycle_brent(&self) -> Option<(Cycle, InCycle<S>)> {
+        let mut power = 1;
+        let mut length = 1;
+
+        let mut tortoise = self.fingerprint(&self.initial);
+        let mut hashes = vec![tortoise.hash];
+
+        let mut hare = self.initial.clone();
+        (self.step)(&mut hare);
+        let mut hare_fingerprint = self.fingerprint(&hare);
+        hashes.push(hare_fingerprint.hash);
+
+        while !tortoise.is_same(&hare_fingerprint) {
+            if hashes.len() > self.step_limit {
+                return None;
+            }
+
+            if power == length {
+                tortoise = hare_fingerprint;
+                power *= 2;
+                length = 0;
+            }
+
+            (self.step)(&mut hare);
+            hare_fingerprint = self.fingerprint(&hare);
+            hashes.push(hare_fingerprint.hash);
+            length += 1;
+        }
+
+        // The first state that repeats is where two states that are length steps apart meet.
+        // Equal hashes need not be equal keys, so the keys of both states are compared as well.
+        let mut start = 0;
+        let mut tortoise = self.initial.clone();
+        let mut ahead = self.run(length);
+
+        while hashes[start] != hashes[start + length] || (self.key)(&tortoise) != (self.key)(&ahead)
+        {
+            (self.step)(&mut tortoise);
+            (self.step)(&mut ahead);
+            start += 1;
+        }
+
+        let in_cycle = InCycle {
+            state: hare,
+            steps: hashes.len() - 1,
+        };
+
+        Some((Cycle { start, length }, in_cycle))
+    }
+
+    // https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare
+    fn find_cycle_floyd(&self) -> Option<(Cycle, InCycle<S>)> {
+        let mut tortoise = self.run(1);
+        let mut hare = self.run(2);
+        let mut num_of_steps = 2;
+
+        while !self
+            .fingerprint(&tortoise)
+            .is_same(&self.fingerprint(&hare))
+        {
+            if num_of_steps >= self.step_limit {
+                return None;
+            }
+
+            (self.step)(&mut tortoise);
+            self.advance(&mut hare, 2);
+            num_of_steps += 2;
+        }
+
+        // the hare is a multiple of the length of the cycle ahead of the tortoise, so they meet
+        // again at the start of the cycle
+        let mut tortoise = self.initial.clone();
+        let mut start = 0;
+
+        while !self
+            .fingerprint(&tortoise)
+            .is_same(&self.fingerprint(&hare))
+        {
+            (self.step)(&mut tortoise);
+            (self.step)(&mut hare);
+            start += 1;
+        }
+
+        let start_fingerprint = self.fingerprint(&tortoise);
+        let mut length = 1;
+        (self.step)(&mut hare);
+
+        while !start_fingerprint.is_same(&self.fingerprint(&hare)) {
+            (self.step)(&mut hare);
+            length += 1;
+        }
+
+        let in_cycle = InCycle {
+            state: tortoise,
+            steps: start,
+        };
+
+        Some((Cycle { start, length }, in_cycle))
+    }
+
+    // A state with the same key as the state after the given number of steps, and the number of
+    // steps that it actually took to get to it; where every state from the start of the cycle
+    // onwards is reached from the state in the cycle, within a pass through the cycle.
+    fn skip_to(&self, cycle: &Cycle, in_cycle: InCycle<S>, steps: u64) -> (S, u64) {
+        if steps < cycle.start as u64 {
+            return (self.run(steps as usize), steps);
+        }
+
+        let steps_ahead = (steps as i128 - in_cycle.steps as i128).rem_euclid(cycle.length as i128);
+
+        let mut state = in_cycle.state;
+        self.advance(&mut state, steps_ahead as usize);
+        (state, in_cycle.steps as u64 + steps_ahead as u64)
+    }
+
+    // The state after the given number of steps, which are skipped once the states repeat.
+    // Since states with the same key are taken to be the same, this is only one of the states
+    // with the key of the actual state.
+    pub fn state_at(&self, steps: u64) -> Option<S> {
+        let (cycle, in_cycle) = self.find_cycle_and_a_state_in_it()?;
+        let (state, _steps) = self.skip_to(&cycle, in_cycle, steps);
+
+        Some(state)
+    }
+
+    // A value of the state after the given number of steps, which changes by the same amount
+    // on every pass through the cycle; e.g. the sum of the positions of a pattern that drifts by
+    // the same offset on every pass.
+    pub fn value_at<V>(&self, steps: u64, value: V) -> Option<i128>
+    where
+        V: Fn(&S) -> i128,
+    {
+        let (cycle, in_cycle) = self.find_cycle_and_a_state_in_it()?;
+        let (mut state, steps_taken) = self.skip_to(&cycle, in_cycle, steps);
+        let value_of_state = value(&state);
+
+        if steps_taken == steps {
+            return Some(value_of_state);
+        }
+
+        // the state is a whole number of passes through the cycle away, in either direction
+        let num_of_cycles = (steps as i128 - steps_taken as i128) / cycle.length as i128;
+
+        self.advance(&mut state, cycle.length);
+        let change_per_cycle = value(&state) - value_of_state;
+
+        Some(value_of_state + change_per_cycle * num_of_cycles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    // the cycle of the sequence, found by remembering every state
+    fn find_cycle_naively(initial: u64, step: impl Fn(&mut u64)) -> Cycle {
+        let mut seen: HashMap<u64, usize> = HashMap::new();
+        let mut state = initial;
+
+        for index in 0.. {
+            if let Some(start) = seen.insert(state, index) {
+                return Cycle {
+                    start,
+                    length: index - start,
+                };
+            }
+
+            step(&mut state);
+        }
+
+        unreachable!();
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let step = |state: &mut u64| *state = (*state * *state + 1) % 1019;
+
+        for initial in 0..200 {
+            let expected = find_cycle_naively(initial, step);
+            let simulation = Simulation::new(initial, step, |state: &u64| *state);
+
+            assert_eq!(simulation.find_cycle(), Some(expected));
+            assert_eq!(
+                simulation.with_algorithm(Algorithm::Floyd).find_cycle(),
+                Some(expected)
+            );
+        }
+
+        // a state that repeats right away
+        let simulation = Simulation::new(7, |_state: &mut u64| {}, |state: &u64| *state);
+        assert_eq!(
+            simulation.find_cycle(),
+            Some(Cycle {
+                start: 0,
+                length: 1
+            })
+        );
+
+        // the states never repeat
+        let simulation = Simulation::new(0, |state: &mut u64| *state += 1, |state: &u64| *state)
+            .with_step_limit(1000);
+        assert_eq!(simulation.find_cycle(), None);
+        assert_eq!(
+            simulation.with_algorithm(Algorithm::Floyd).find_cycle(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_cycle_with_colliding_hashes() {
+        // a key whose hashes are all the same, so only the keys tell the states apart
+        #[derive(PartialEq, Eq)]
+        struct Colliding(u64);
+
+        impl Hash for Colliding {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                0.hash(state);
+            }
+        }
+
+        let step = |state: &mut u64| *state = (*state * *state + 1) % 1019;
+
+        for initial in 0..50 {
+            let expected = find_cycle_naively(initial, step);
+            let simulation = Simulation::new(initial, step, |state: &u64| Colliding(*state));
+
+            assert_eq!(simulation.find_cycle(), Some(expected));
+            assert_eq!(
+                simulation.with_algorithm(Algorithm::Floyd).find_cycle(),
+                Some(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_state_at() {
+        // 3, 5, 7, 2, 4, 6, 8, 5, 7, 2, ...
+        let step = |state: &mut u64| *state = if *state == 8 { 5 } else { *state % 7 + 2 };
+        let simulation = Simulation::new(3, step, |state: &u64| *state);
+
+        assert_eq!(
+            simulation.find_cycle(),
+            Some(Cycle {
+                start: 1,
+                length: 6
+            })
+        );
+
+        for steps in 0..50 {
+            assert_eq!(
+                simulation.state_at(steps),
+                Some(simulation.run(steps as usize))
+            );
+        }
+
+        // 1 + 6 * 166_666_666 + 3 steps end in the same state as 4 steps
+        let cycle = simulation.find_cycle().unwrap();
+        assert_eq!(cycle.reduce(1_000_000_000), (4, 166_666_666));
+        assert_eq!(simulation.state_at(1_000_000_000), Some(4));
+        assert_eq!(
+            simulation.state_at(u64::MAX),
+            simulation.state_at(1 + (u64::MAX - 1) % 6)
+        );
+    }
+
+    #[test]
+    fn test_value_at() {
+        // a glider that moves right by one on every step, and takes one of two shapes once it got
+        // going; the shape is the key
+        #[derive(Clone)]
+        struct Glider {
+            offset: i64,
+            shape: Vec<i64>,
+        }
+
+        let step = |glider: &mut Glider| {
+            glider.offset += 1;
+            glider.shape = if glider.shape == [0, 1, 3] {
+                vec![0, 2, 3]
+            } else {
+                vec![0, 1, 3]
+            };
+        };
+        let initial = Glider {
+            offset: 0,
+            shape: vec![0, 5],
+        };
+        let shape = |glider: &Glider| glider.shape.clone();
+
+        let sum = |glider: &Glider| -> i128 {
+            glider
+                .shape
+                .iter()
+                .map(|position| (glider.offset + position) as i128)
+                .sum()
+        };
+
+        for algorithm in [Algorithm::Brent, Algorithm::Floyd] {
+            let simulation =
+                Simulation::new(initial.clone(), step, shape).with_algorithm(algorithm);
+
+            assert_eq!(
+                simulation.find_cycle(),
+                Some(Cycle {
+                    start: 1,
+                    length: 2
+                })
+            );
+
+            for steps in 0..20 {
+                let glider = simulation.run(steps as usize);
+                assert_eq!(simulation.value_at(steps, sum), Some(sum(&glider)));
+            }
+
+            // an even number of steps ends in the shape of 0, 2 and 3
+            let steps = 50_000_000_000;
+            assert_eq!(simulation.value_at(steps, sum), Some(3 * steps as i128 + 5));
+        }
+    }
+}
@@ -0,0 +1,1257 @@
+// imports
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::instruction::{Instruction, Opcode, Operand};
+use crate::program::Program;
+use crate::registers::Value;
+
+// code
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+    And,
+    Or,
+    GreaterThan,
+    Equal,
+    // only produced by negating the comparisons above
+    LessThanOrEqual,
+    NotEqual,
+}
+
+impl Operator {
+    pub(crate) fn from_opcode(opcode: Opcode) -> Option<Self> {
+        let operator = match opcode {
+            Opcode::Addr | Opcode::Addi => Operator::Add,
+            Opcode::Mulr | Opcode::Muli => Operator::Multiply,
+            Opcode::Banr | Opcode::Bani => Operator::And,
+            Opcode::Borr | Opcode::Bori => Operator::Or,
+            Opcode::Gtir | Opcode::Gtri | Opcode::Gtrr => Operator::GreaterThan,
+            Opcode::Eqir | Opcode::Eqri | Opcode::Eqrr => Operator::Equal,
+            Opcode::Setr | Opcode::Seti => return None,
+        };
+
+        Some(operator)
+    }
+
+    fn symbol(&self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Multiply => "*",
+            Operator::And => "&",
+            Operator::Or => "|",
+            Operator::GreaterThan => ">",
+            Operator::Equal => "==",
+            Operator::LessThanOrEqual => "<=",
+            Operator::NotEqual => "!=",
+        }
+    }
+
+    pub(crate) fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Operator::GreaterThan
+                | Operator::Equal
+                | Operator::LessThanOrEqual
+                | Operator::NotEqual
+        )
+    }
+
+    pub(crate) fn negate(&self) -> Option<Self> {
+        let negated = match self {
+            Operator::GreaterThan => Operator::LessThanOrEqual,
+            Operator::LessThanOrEqual => Operator::GreaterThan,
+            Operator::Equal => Operator::NotEqual,
+            Operator::NotEqual => Operator::Equal,
+            _ => return None,
+        };
+
+        Some(negated)
+    }
+
+    // None if the result overflows
+    fn checked_evaluate(&self, a: Value, b: Value) -> Option<Value> {
+        match self {
+            Operator::Add => a.checked_add(b),
+            Operator::Multiply => a.checked_mul(b),
+            _ => Some(self.evaluate(a, b)),
+        }
+    }
+
+    fn evaluate(&self, a: Value, b: Value) -> Value {
+        match self {
+            Operator::Add => a + b,
+            Operator::Multiply => a * b,
+            Operator::And => a & b,
+            Operator::Or => a | b,
+            Operator::GreaterThan => (a > b) as Value,
+            Operator::Equal => (a == b) as Value,
+            Operator::LessThanOrEqual => (a <= b) as Value,
+            Operator::NotEqual => (a != b) as Value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+    Register(Value),
+    Constant(Value),
+    Binary(Operator, Box<Expression>, Box<Expression>),
+}
+
+impl Expression {
+    // constants are folded
+    pub(crate) fn binary(operator: Operator, left: Expression, right: Expression) -> Self {
+        if let (Expression::Constant(a), Expression::Constant(b)) = (&left, &right) {
+            return Expression::Constant(operator.evaluate(*a, *b));
+        }
+
+        Expression::Binary(operator, Box::new(left), Box::new(right))
+    }
+
+    // true if the expression is always 0 or 1
+    pub(crate) fn is_boolean(&self) -> bool {
+        match self {
+            Expression::Binary(operator, _, _) => operator.is_comparison(),
+            Expression::Constant(value) => *value == 0 || *value == 1,
+            Expression::Register(_) => false,
+        }
+    }
+
+    // the expression is true when it is not 0
+    fn not_zero(self) -> Self {
+        if self.is_boolean() {
+            return self;
+        }
+
+        Expression::binary(Operator::NotEqual, self, Expression::Constant(0))
+    }
+
+    pub fn negate(self) -> Self {
+        match self {
+            Expression::Binary(operator, left, right) if operator.is_comparison() => {
+                Expression::Binary(operator.negate().unwrap(), left, right)
+            }
+            expression => Expression::binary(Operator::Equal, expression, Expression::Constant(0)),
+        }
+    }
+
+    // the value of the expression for the given registers; None if it overflows
+    pub(crate) fn evaluate(&self, registers: &[Value]) -> Option<Value> {
+        match self {
+            Expression::Register(register) => Some(registers[*register as usize]),
+            Expression::Constant(value) => Some(*value),
+            Expression::Binary(operator, left, right) => {
+                operator.checked_evaluate(left.evaluate(registers)?, right.evaluate(registers)?)
+            }
+        }
+    }
+
+    // the address that a computed jump to the expression continues at
+    pub fn computed_target(&self) -> Self {
+        Expression::binary(Operator::Add, self.clone(), Expression::Constant(1))
+    }
+
+    pub fn reads(&self, register: Value) -> bool {
+        match self {
+            Expression::Register(other) => *other == register,
+            Expression::Constant(_) => false,
+            Expression::Binary(_, left, right) => left.reads(register) || right.reads(register),
+        }
+    }
+
+    pub(crate) fn registers(&self, registers: &mut BTreeSet<Value>) {
+        match self {
+            Expression::Register(register) => {
+                registers.insert(*register);
+            }
+            Expression::Constant(_) => {}
+            Expression::Binary(_, left, right) => {
+                left.registers(registers);
+                right.registers(registers);
+            }
+        }
+    }
+
+    fn substitute(&mut self, register: Value, replacement: &Expression) {
+        match self {
+            Expression::Register(other) if *other == register => *self = replacement.clone(),
+            Expression::Register(_) | Expression::Constant(_) => {}
+            Expression::Binary(_, left, right) => {
+                left.substitute(register, replacement);
+                right.substitute(register, replacement);
+            }
+        }
+    }
+
+    // e.g. a comparison that is tested for not being 0 is the comparison itself
+    pub(crate) fn simplify(self) -> Self {
+        match self {
+            Expression::Binary(operator, left, right) => {
+                let left = left.simplify();
+                let right = right.simplify();
+
+                match (operator, &right) {
+                    (Operator::NotEqual, Expression::Constant(0)) if left.is_boolean() => left,
+                    (Operator::Equal, Expression::Constant(0)) if left.is_boolean() => {
+                        left.negate()
+                    }
+                    _ => Expression::binary(operator, left, right),
+                }
+            }
+            expression => expression,
+        }
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expression::Register(register) => write!(f, "r{}", register),
+            Expression::Constant(value) => write!(f, "{}", value),
+            Expression::Binary(operator, left, right) => {
+                for (index, operand) in [left, right].into_iter().enumerate() {
+                    if index == 1 {
+                        write!(f, " {} ", operator.symbol())?;
+                    }
+
+                    match operand.as_ref() {
+                        Expression::Binary(..) => write!(f, "({})", operand)?,
+                        _ => write!(f, "{}", operand)?,
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+// rC = expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment {
+    pub register: Value,
+    pub expression: Expression,
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // e.g. r0 += r3
+        if let Expression::Binary(operator, left, right) = &self.expression {
+            let is_compound = matches!(
+                operator,
+                Operator::Add | Operator::Multiply | Operator::And | Operator::Or
+            );
+
+            let register = Expression::Register(self.register);
+
+            if is_compound && **left == register {
+                return write!(f, "r{} {}= {}", self.register, operator.symbol(), right);
+            }
+
+            // the operators are commutative
+            if is_compound && **right == register {
+                return write!(f, "r{} {}= {}", self.register, operator.symbol(), left);
+            }
+        }
+
+        write!(f, "r{} = {}", self.register, self.expression)
+    }
+}
+
+// How a basic block hands over control. Addresses outside of the program halt it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Terminator {
+    Goto(usize),
+    Branch {
+        condition: Expression,
+        then: usize,
+        otherwise: usize,
+    },
+    // a jump to an address that is computed from registers other than a single flag
+    Computed(Expression),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    // the addresses of the instructions of the block
+    pub start: usize,
+    pub end: usize,
+    pub assignments: Vec<Assignment>,
+    pub terminator: Terminator,
+}
+
+// The basic blocks of a program, keyed by their first address.
+// Every write to the register that the instruction pointer is bound to is a jump: a constant
+// jumps to a single address, and adding a register to the instruction pointer branches on that
+// register. Such a register is assumed to be 0 or 1, as it is when a comparison sets it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlFlowGraph {
+    pub blocks: BTreeMap<usize, Block>,
+    // the address every jump outside of the program is mapped to
+    pub halt: usize,
+}
+
+fn read_operand(operand: Operand, input: Value, address: usize, program: &Program) -> Expression {
+    match operand {
+        // the bound register holds the address of the instruction that is running
+        Operand::Register if Some(input) == program.instruction_pointer_register() => {
+            Expression::Constant(address as Value)
+        }
+        Operand::Register => Expression::Register(input),
+        Operand::Immediate => Expression::Constant(input),
+        Operand::Ignored => Expression::Constant(0),
+    }
+}
+
+fn lift(instruction: &Instruction, address: usize, program: &Program) -> Expression {
+    let (operand_a, operand_b) = instruction.opcode.operands();
+    let a = read_operand(operand_a, instruction.a, address, program);
+    let b = read_operand(operand_b, instruction.b, address, program);
+
+    match Operator::from_opcode(instruction.opcode) {
+        Some(operator) => Expression::binary(operator, a, b),
+        None => a,
+    }
+}
+
+impl ControlFlowGraph {
+    pub fn build(program: &Program) -> Self {
+        let instructions = program.instructions();
+        let halt = instructions.len();
+
+        // the instruction pointer is incremented after the bound register is written to
+        let target = |value: Value| -> usize {
+            let target = value + 1;
+            if target < 0 || target as usize >= halt {
+                halt
+            } else {
+                target as usize
+            }
+        };
+
+        let jumps: BTreeMap<usize, Terminator> = instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| {
+                Some(instruction.c) == program.instruction_pointer_register()
+            })
+            .map(|(address, instruction)| {
+                let terminator = match lift(instruction, address, program) {
+                    Expression::Constant(value) => Terminator::Goto(target(value)),
+                    Expression::Binary(Operator::Add, left, right) => match (*left, *right) {
+                        (Expression::Constant(value), Expression::Register(register))
+                        | (Expression::Register(register), Expression::Constant(value)) => {
+                            Terminator::Branch {
+                                condition: Expression::Register(register).not_zero(),
+                                then: target(value + 1),
+                                otherwise: target(value),
+                            }
+                        }
+                        (left, right) => {
+                            Terminator::Computed(Expression::binary(Operator::Add, left, right))
+                        }
+                    },
+                    expression => Terminator::Computed(expression),
+                };
+
+                (address, terminator)
+            })
+            .collect();
+
+        let mut leaders: BTreeSet<usize> = BTreeSet::new();
+        leaders.insert(0);
+        for (address, terminator) in &jumps {
+            leaders.insert(address + 1);
+
+            match terminator {
+                Terminator::Goto(target) => {
+                    leaders.insert(*target);
+                }
+                Terminator::Branch {
+                    then, otherwise, ..
+                } => {
+                    leaders.insert(*then);
+                    leaders.insert(*otherwise);
+                }
+                Terminator::Computed(_) => {}
+            }
+        }
+        leaders.retain(|address| *address < halt);
+
+        let mut blocks = BTreeMap::new();
+        for start in leaders.iter().copied() {
+            let mut assignments = vec![];
+            let mut address = start;
+
+            let terminator = loop {
+                if let Some(terminator) = jumps.get(&address) {
+                    break terminator.clone();
+                }
+
+                let instruction = &instructions[address];
+                assignments.push(Assignment {
+                    register: instruction.c,
+                    expression: lift(instruction, address, program),
+                });
+
+                address += 1;
+                if address >= halt || leaders.contains(&address) {
+                    // falls through into the next block
+                    address -= 1;
+                    break Terminator::Goto(address + 1);
+                }
+            };
+
+            blocks.insert(
+                start,
+                Block {
+                    start,
+                    end: address + 1,
+                    assignments,
+                    terminator,
+                },
+            );
+        }
+
+        let mut graph = ControlFlowGraph { blocks, halt };
+        graph.propagate_expressions();
+        graph
+    }
+
+    pub fn successors(&self, start: usize) -> Vec<usize> {
+        match &self.blocks[&start].terminator {
+            Terminator::Goto(target) => vec![*target],
+            Terminator::Branch {
+                then, otherwise, ..
+            } => vec![*then, *otherwise],
+            Terminator::Computed(_) => vec![],
+        }
+    }
+
+    // the blocks that every path from the start of the program to a block passes through
+    fn dominators(&self) -> BTreeMap<usize, BTreeSet<usize>> {
+        let all: BTreeSet<usize> = self.blocks.keys().copied().collect();
+        let mut predecessors: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for start in &all {
+            for successor in self.successors(*start) {
+                predecessors.entry(successor).or_default().push(*start);
+            }
+        }
+
+        let mut dominators: BTreeMap<usize, BTreeSet<usize>> =
+            all.iter().map(|start| (*start, all.clone())).collect();
+        dominators.insert(0, [0].into_iter().collect());
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for start in all.iter().filter(|start| **start != 0) {
+                let mut intersection: Option<BTreeSet<usize>> = None;
+                for predecessor in predecessors.get(start).into_iter().flatten() {
+                    let dominators_of_predecessor = &dominators[predecessor];
+                    intersection = Some(match intersection {
+                        None => dominators_of_predecessor.clone(),
+                        Some(set) => set
+                            .intersection(dominators_of_predecessor)
+                            .copied()
+                            .collect(),
+                    });
+                }
+
+                let mut new_dominators = intersection.unwrap_or_default();
+                new_dominators.insert(*start);
+
+                if new_dominators != dominators[start] {
+                    dominators.insert(*start, new_dominators);
+                    changed = true;
+                }
+            }
+        }
+
+        dominators
+    }
+
+    // The natural loops of the program, keyed by the block at their header: the blocks that
+    // jump back to a block that dominates them, along with every block in between.
+    pub fn loops(&self) -> BTreeMap<usize, BTreeSet<usize>> {
+        let dominators = self.dominators();
+        let mut loops: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+
+        for (start, dominators_of_start) in &dominators {
+            for header in self.successors(*start) {
+                if !dominators_of_start.contains(&header) {
+                    continue;
+                }
+
+                let body = loops
+                    .entry(header)
+                    .or_insert_with(|| [header].into_iter().collect());
+
+                // every block that reaches the back edge without passing through the header
+                let mut pending = vec![*start];
+                while let Some(block) = pending.pop() {
+                    if body.insert(block) {
+                        pending.extend(
+                            self.blocks
+                                .keys()
+                                .filter(|other| self.successors(**other).contains(&block)),
+                        );
+                    }
+                }
+            }
+        }
+
+        loops
+    }
+
+    // true if the value of the register at the start of the block may be read later on
+    fn is_live(&self, register: Value, start: usize, visited: &mut BTreeSet<usize>) -> bool {
+        if start == self.halt || !visited.insert(start) {
+            return false;
+        }
+
+        let block = &self.blocks[&start];
+        for assignment in &block.assignments {
+            if assignment.expression.reads(register) {
+                return true;
+            }
+
+            if assignment.register == register {
+                return false;
+            }
+        }
+
+        match &block.terminator {
+            Terminator::Branch { condition, .. } if condition.reads(register) => return true,
+            Terminator::Computed(_) => return true,
+            _ => {}
+        }
+
+        self.successors(start)
+            .into_iter()
+            .any(|successor| self.is_live(register, successor, visited))
+    }
+
+    fn is_live_after(&self, register: Value, start: usize) -> bool {
+        let mut visited = BTreeSet::new();
+        self.successors(start)
+            .into_iter()
+            .any(|successor| self.is_live(register, successor, &mut visited))
+    }
+
+    // Move an expression that is assigned to a register into the single place the register is
+    // read, e.g. r2 = r3 * r1; r2 = r2 == r5; if r2 != 0 becomes if r3 * r1 == r5
+    fn propagate_expressions(&mut self) {
+        let starts: Vec<usize> = self.blocks.keys().copied().collect();
+
+        for start in starts {
+            let mut index = 0;
+
+            while index < self.blocks[&start].assignments.len() {
+                let block = &self.blocks[&start];
+                let register = block.assignments[index].register;
+
+                if self.can_propagate(block, index, |register| self.is_live_after(register, start))
+                {
+                    let block = self.blocks.get_mut(&start).unwrap();
+                    let assignment = block.assignments.remove(index);
+                    substitute_first_use(block, index, register, &assignment.expression);
+                    index = 0;
+                } else {
+                    index += 1;
+                }
+            }
+
+            let block = self.blocks.get_mut(&start).unwrap();
+            for assignment in block.assignments.iter_mut() {
+                assignment.expression = assignment.expression.clone().simplify();
+            }
+            if let Terminator::Branch { condition, .. } = &mut block.terminator {
+                *condition = condition.clone().simplify();
+            }
+        }
+    }
+
+    fn can_propagate<F>(&self, block: &Block, index: usize, is_live_after: F) -> bool
+    where
+        F: Fn(Value) -> bool,
+    {
+        let Assignment {
+            register,
+            expression,
+        } = &block.assignments[index];
+
+        let mut inputs = BTreeSet::new();
+        expression.registers(&mut inputs);
+
+        let mut num_of_reads = 0;
+
+        for assignment in &block.assignments[index + 1..] {
+            if assignment.expression.reads(*register) {
+                num_of_reads += 1;
+            }
+
+            if assignment.register == *register {
+                return num_of_reads == 1;
+            }
+
+            // the inputs of the expression must hold the same values where it is moved to
+            if inputs.contains(&assignment.register) {
+                return false;
+            }
+        }
+
+        if let Terminator::Branch { condition, .. } = &block.terminator {
+            if condition.reads(*register) {
+                num_of_reads += 1;
+            }
+        }
+
+        num_of_reads == 1 && !is_live_after(*register)
+    }
+}
+
+fn substitute_first_use(block: &mut Block, index: usize, register: Value, expression: &Expression) {
+    for assignment in block.assignments[index..].iter_mut() {
+        if assignment.expression.reads(register) {
+            assignment.expression.substitute(register, expression);
+            return;
+        }
+    }
+
+    if let Terminator::Branch { condition, .. } = &mut block.terminator {
+        condition.substitute(register, expression);
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Statement {
+    Assign(Assignment),
+    If {
+        condition: Expression,
+        then: Vec<Statement>,
+        otherwise: Vec<Statement>,
+    },
+    Loop(Vec<Statement>),
+    While {
+        condition: Expression,
+        body: Vec<Statement>,
+    },
+    DoWhile {
+        body: Vec<Statement>,
+        condition: Expression,
+    },
+    Break,
+    Continue,
+    Halt,
+    // control flow that could not be structured
+    Label(usize),
+    Goto(usize),
+    ComputedGoto(Expression),
+}
+
+impl Statement {
+    // true if control never reaches the statement after this one
+    fn is_jump(&self) -> bool {
+        matches!(
+            self,
+            Statement::Break
+                | Statement::Continue
+                | Statement::Halt
+                | Statement::Goto(_)
+                | Statement::ComputedGoto(_)
+        )
+    }
+}
+
+// Where control goes when it leaves the region that is being structured.
+#[derive(Debug, Clone, Copy)]
+struct Region {
+    // jumps to the header of the innermost loop continue it, and jumps to its exit break it
+    header: Option<usize>,
+    exit: Option<usize>,
+    // the address that follows the region, e.g. where the branches of an if meet again
+    stop: Option<usize>,
+}
+
+struct Structurer<'a> {
+    graph: &'a ControlFlowGraph,
+    // the header of every loop, along with the blocks of the loop and the address it exits to
+    loops: BTreeMap<usize, (BTreeSet<usize>, Option<usize>)>,
+    emitted: BTreeSet<usize>,
+}
+
+impl<'a> Structurer<'a> {
+    fn new(graph: &'a ControlFlowGraph) -> Self {
+        let mut structurer = Structurer {
+            graph,
+            loops: BTreeMap::new(),
+            emitted: BTreeSet::new(),
+        };
+        structurer.find_loops();
+        structurer
+    }
+
+    fn find_loops(&mut self) {
+        for (header, body) in self.graph.loops() {
+            let exit = body
+                .iter()
+                .flat_map(|block| self.graph.successors(*block))
+                .filter(|successor| !body.contains(successor) && *successor != self.graph.halt)
+                .min();
+
+            self.loops.insert(header, (body, exit));
+        }
+    }
+
+    // The address where both branches of the block meet again, i.e. its immediate
+    // post-dominator; None if they only leave the region.
+    fn merge_point(&self, start: usize, region: &Region) -> Option<usize> {
+        let is_sink = |address: usize| {
+            address == self.graph.halt
+                || Some(address) == region.header
+                || Some(address) == region.exit
+                || Some(address) == region.stop
+        };
+
+        let mut nodes = BTreeSet::new();
+        let mut pending = self.graph.successors(start);
+        while let Some(node) = pending.pop() {
+            if !is_sink(node) && nodes.insert(node) {
+                pending.extend(self.graph.successors(node));
+            }
+        }
+
+        // post-dominators, where every sink is the same virtual exit
+        let mut post_dominators: BTreeMap<usize, BTreeSet<usize>> =
+            nodes.iter().map(|node| (*node, nodes.clone())).collect();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for node in &nodes {
+                let mut intersection: Option<BTreeSet<usize>> = None;
+                for successor in self.graph.successors(*node) {
+                    let set = if is_sink(successor) {
+                        BTreeSet::new()
+                    } else {
+                        post_dominators[&successor].clone()
+                    };
+
+                    intersection = Some(match intersection {
+                        None => set,
+                        Some(other) => other.intersection(&set).copied().collect(),
+                    });
+                }
+
+                let mut new_post_dominators = intersection.unwrap_or_default();
+                new_post_dominators.insert(*node);
+
+                if new_post_dominators != post_dominators[node] {
+                    post_dominators.insert(*node, new_post_dominators);
+                    changed = true;
+                }
+            }
+        }
+
+        let successors = self.graph.successors(start);
+        let mut common: Option<BTreeSet<usize>> = None;
+        for successor in successors {
+            let set = if is_sink(successor) {
+                BTreeSet::new()
+            } else {
+                post_dominators[&successor].clone()
+            };
+
+            common = Some(match common {
+                None => set,
+                Some(other) => other.intersection(&set).copied().collect(),
+            });
+        }
+
+        // post-dominators form a chain; the closest one is post-dominated by all the others
+        common
+            .unwrap_or_default()
+            .into_iter()
+            .max_by_key(|node| post_dominators[node].len())
+    }
+
+    fn structure(
+        &mut self,
+        mut node: usize,
+        region: Region,
+        entering: Option<usize>,
+    ) -> Vec<Statement> {
+        let mut statements = vec![];
+        let mut entering = entering;
+
+        loop {
+            if Some(node) == region.stop {
+                break;
+            }
+
+            if node == self.graph.halt {
+                statements.push(Statement::Halt);
+                break;
+            }
+
+            if entering != Some(node) {
+                if Some(node) == region.header {
+                    statements.push(Statement::Continue);
+                    break;
+                }
+
+                if Some(node) == region.exit {
+                    statements.push(Statement::Break);
+                    break;
+                }
+
+                if self.emitted.contains(&node) {
+                    statements.push(Statement::Goto(node));
+                    break;
+                }
+
+                if let Some((_, exit)) = self.loops.get(&node) {
+                    let exit = *exit;
+                    let inner = Region {
+                        header: Some(node),
+                        exit,
+                        stop: None,
+                    };
+
+                    let body = self.structure(node, inner, Some(node));
+                    statements.push(Statement::Loop(body));
+
+                    match exit {
+                        Some(exit) => {
+                            node = exit;
+                            continue;
+                        }
+                        None => break,
+                    }
+                }
+            }
+
+            entering = None;
+            self.emitted.insert(node);
+
+            let block = &self.graph.blocks[&node];
+            statements.push(Statement::Label(node));
+            statements.extend(block.assignments.iter().cloned().map(Statement::Assign));
+
+            match block.terminator.clone() {
+                Terminator::Goto(target) => node = target,
+                Terminator::Computed(expression) => {
+                    statements.push(Statement::ComputedGoto(expression));
+                    break;
+                }
+                Terminator::Branch {
+                    condition,
+                    then,
+                    otherwise,
+                } => {
+                    let merge = self.merge_point(node, &region);
+                    let inner = Region {
+                        stop: merge.or(region.stop),
+                        ..region
+                    };
+
+                    let then = self.structure(then, inner, None);
+                    let otherwise = self.structure(otherwise, inner, None);
+                    statements.push(Statement::If {
+                        condition,
+                        then,
+                        otherwise,
+                    });
+
+                    match merge {
+                        Some(merge) => node = merge,
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        statements
+    }
+}
+
+// true if a continue in the statements jumps to the header of the loop they are in
+fn has_continue(statements: &[Statement]) -> bool {
+    statements.iter().any(|statement| match statement {
+        Statement::Continue => true,
+        Statement::If {
+            then, otherwise, ..
+        } => has_continue(then) || has_continue(otherwise),
+        _ => false,
+    })
+}
+
+fn simplify(statements: Vec<Statement>) -> Vec<Statement> {
+    let mut simplified = vec![];
+
+    for statement in statements {
+        match statement {
+            Statement::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                let mut then = simplify(then);
+                let mut otherwise = simplify(otherwise);
+                let mut condition = condition;
+
+                if then.is_empty() && !otherwise.is_empty() {
+                    std::mem::swap(&mut then, &mut otherwise);
+                    condition = condition.negate();
+                }
+
+                // an if that always jumps away does not need an else; when both branches jump
+                // away, the shorter one stays in the if
+                let then_jumps = then.last().is_some_and(Statement::is_jump);
+                let otherwise_jumps = otherwise.last().is_some_and(Statement::is_jump);
+
+                if otherwise_jumps && (!then_jumps || otherwise.len() < then.len()) {
+                    std::mem::swap(&mut then, &mut otherwise);
+                    condition = condition.negate();
+                }
+
+                if then.last().is_some_and(Statement::is_jump) {
+                    simplified.push(Statement::If {
+                        condition,
+                        then,
+                        otherwise: vec![],
+                    });
+                    simplified.extend(otherwise);
+                } else {
+                    simplified.push(Statement::If {
+                        condition,
+                        then,
+                        otherwise,
+                    });
+                }
+            }
+            Statement::Loop(body) => simplified.push(simplify_loop(simplify(body))),
+            statement => simplified.push(statement),
+        }
+    }
+
+    simplified
+}
+
+fn simplify_loop(mut body: Vec<Statement>) -> Statement {
+    if body.last() == Some(&Statement::Continue) {
+        body.pop();
+    }
+
+    let is_break = |statements: &[Statement]| statements == [Statement::Break];
+
+    // the labels are kept at the top, where the header of the loop is
+    let num_of_labels = body
+        .iter()
+        .take_while(|statement| matches!(statement, Statement::Label(_)))
+        .count();
+
+    if let Some(Statement::If {
+        then, otherwise, ..
+    }) = body.get(num_of_labels)
+    {
+        if is_break(then) && otherwise.is_empty() {
+            if let Statement::If { condition, .. } = body.remove(num_of_labels) {
+                return Statement::While {
+                    condition: condition.negate(),
+                    body,
+                };
+            }
+        }
+    }
+
+    if let Some(Statement::If {
+        then, otherwise, ..
+    }) = body.last()
+    {
+        // a continue in the body of a do-while would skip its condition
+        if is_break(then) && otherwise.is_empty() && !has_continue(&body) {
+            if let Some(Statement::If { condition, .. }) = body.pop() {
+                return Statement::DoWhile {
+                    body,
+                    condition: condition.negate(),
+                };
+            }
+        }
+    }
+
+    Statement::Loop(body)
+}
+
+fn goto_targets(statements: &[Statement], targets: &mut BTreeSet<usize>) {
+    for statement in statements {
+        match statement {
+            Statement::Goto(target) => {
+                targets.insert(*target);
+            }
+            Statement::If {
+                then, otherwise, ..
+            } => {
+                goto_targets(then, targets);
+                goto_targets(otherwise, targets);
+            }
+            Statement::Loop(body)
+            | Statement::While { body, .. }
+            | Statement::DoWhile { body, .. } => goto_targets(body, targets),
+            _ => {}
+        }
+    }
+}
+
+fn remove_labels(statements: Vec<Statement>, targets: &BTreeSet<usize>) -> Vec<Statement> {
+    statements
+        .into_iter()
+        .filter(|statement| match statement {
+            Statement::Label(address) => targets.contains(address),
+            _ => true,
+        })
+        .map(|statement| match statement {
+            Statement::If {
+                condition,
+                then,
+                otherwise,
+            } => Statement::If {
+                condition,
+                then: remove_labels(then, targets),
+                otherwise: remove_labels(otherwise, targets),
+            },
+            Statement::Loop(body) => Statement::Loop(remove_labels(body, targets)),
+            Statement::While { condition, body } => Statement::While {
+                condition,
+                body: remove_labels(body, targets),
+            },
+            Statement::DoWhile { body, condition } => Statement::DoWhile {
+                body: remove_labels(body, targets),
+                condition,
+            },
+            statement => statement,
+        })
+        .collect()
+}
+
+// A program lifted into structured pseudo-code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decompiled {
+    pub statements: Vec<Statement>,
+}
+
+pub fn decompile(program: &Program) -> Decompiled {
+    let graph = ControlFlowGraph::build(program);
+    let mut structurer = Structurer::new(&graph);
+
+    let mut statements = vec![];
+    if !graph.blocks.is_empty() {
+        let region = Region {
+            header: None,
+            exit: None,
+            stop: None,
+        };
+
+        statements = structurer.structure(0, region, None);
+
+        // blocks that can only be reached through a computed jump
+        let starts: Vec<usize> = graph.blocks.keys().copied().collect();
+        for start in starts {
+            if !structurer.emitted.contains(&start) {
+                statements.extend(structurer.structure(start, region, None));
+            }
+        }
+    }
+
+    let mut targets = BTreeSet::new();
+    goto_targets(&statements, &mut targets);
+
+    Decompiled {
+        statements: simplify(remove_labels(statements, &targets)),
+    }
+}
+
+fn write_statements(f: &mut fmt::Formatter, statements: &[Statement], depth: usize) -> fmt::Result {
+    let indent = "    ".repeat(depth);
+
+    for statement in statements {
+        match statement {
+            Statement::Assign(assignment) => writeln!(f, "{}{}", indent, assignment)?,
+            Statement::If {
+                condition,
+                then,
+                otherwise,
+            } => {
+                writeln!(f, "{}if {} {{", indent, condition)?;
+                write_statements(f, then, depth + 1)?;
+                if !otherwise.is_empty() {
+                    writeln!(f, "{}}} else {{", indent)?;
+                    write_statements(f, otherwise, depth + 1)?;
+                }
+                writeln!(f, "{}}}", indent)?;
+            }
+            Statement::Loop(body) => {
+                writeln!(f, "{}loop {{", indent)?;
+                write_statements(f, body, depth + 1)?;
+                writeln!(f, "{}}}", indent)?;
+            }
+            Statement::While { condition, body } => {
+                writeln!(f, "{}while {} {{", indent, condition)?;
+                write_statements(f, body, depth + 1)?;
+                writeln!(f, "{}}}", indent)?;
+            }
+            Statement::DoWhile { body, condition } => {
+                writeln!(f, "{}do {{", indent)?;
+                write_statements(f, body, depth + 1)?;
+                writeln!(f, "{}}} while {}", indent, condition)?;
+            }
+            Statement::Break => writeln!(f, "{}break", indent)?,
+            Statement::Continue => writeln!(f, "{}continue", indent)?,
+            Statement::Halt => writeln!(f, "{}halt", indent)?,
+            Statement::Label(address) => writeln!(f, "L{}:", address)?,
+            Statement::Goto(address) => writeln!(f, "{}goto L{}", indent, address)?,
+            Statement::ComputedGoto(expression) => {
+                writeln!(f, "{}goto {}", indent, expression.computed_target())?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl fmt::Display for Decompiled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_statements(f, &self.statements, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decompile_str(input_string: &str) -> String {
+        decompile(&Program::parse(input_string, 6).unwrap()).to_string()
+    }
+
+    #[test]
+    fn test_control_flow_graph() {
+        // example from day 19
+        let program = Program::parse(
+            r###"
+#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5
+"###,
+            6,
+        )
+        .unwrap();
+
+        let graph = ControlFlowGraph::build(&program);
+        assert_eq!(graph.halt, 7);
+        assert_eq!(
+            graph.blocks.keys().copied().collect::<Vec<_>>(),
+            vec![0, 3, 4, 5]
+        );
+
+        // addi 0 1 0 skips over addr 1 2 3
+        assert_eq!(graph.blocks[&0].end, 3);
+        assert_eq!(graph.blocks[&0].terminator, Terminator::Goto(4));
+        // setr 1 0 0 jumps to r1 + 1, which is not known without running the program
+        assert_eq!(
+            graph.blocks[&4].terminator,
+            Terminator::Computed(Expression::Register(1))
+        );
+
+        // blocks that are not reached by a known jump follow the rest of the program
+        assert_eq!(
+            decompile(&program).to_string(),
+            "r1 = 5\nr2 = 6\nL4:\ngoto r1 + 1\nr3 = r1 + r2\ngoto L4\nr4 = 8\nr5 = 9\nhalt\n"
+        );
+    }
+
+    #[test]
+    fn test_loops() {
+        // the start of day 21
+        let decompiled = decompile_str(
+            r###"
+#ip 1
+seti 123 0 5
+bani 5 456 5
+eqri 5 72 5
+addr 5 1 1
+seti 0 0 1
+seti 0 2 5
+"###,
+        );
+        assert_eq!(
+            decompiled,
+            "r5 = 123\ndo {\n    r5 = (r5 & 456) == 72\n} while r5 == 0\nr5 = 0\nhalt\n"
+        );
+
+        // the comparison only decides the branch, so it becomes the condition of the loop
+        let decompiled = decompile_str(
+            r###"
+#ip 4
+seti 0 0 1
+gtir 10 1 3
+addr 3 4 4
+seti 5 0 4
+addi 1 1 1
+seti 0 0 4
+seti 7 0 0
+"###,
+        );
+        assert_eq!(
+            decompiled,
+            "r1 = 0\nwhile 10 > r1 {\n    r1 += 1\n}\nr0 = 7\nhalt\n"
+        );
+
+        // the only way out of the loop is to halt
+        let decompiled = decompile_str(
+            r###"
+#ip 5
+seti 0 0 1
+addi 1 1 1
+eqri 1 5 3
+addr 3 5 5
+seti 0 0 5
+"###,
+        );
+        assert_eq!(
+            decompiled,
+            "r1 = 0\nloop {\n    r1 += 1\n    if r1 == 5 {\n        halt\n    }\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_conditionals() {
+        let decompiled = decompile_str(
+            r###"
+#ip 5
+eqrr 1 2 3
+addr 3 5 5
+addi 5 2 5
+seti 1 0 0
+addi 5 1 5
+seti 2 0 0
+muli 0 10 0
+"###,
+        );
+        assert_eq!(
+            decompiled,
+            "if r1 == r2 {\n    r0 = 1\n} else {\n    r0 = 2\n}\nr0 *= 10\nhalt\n"
+        );
+    }
+}
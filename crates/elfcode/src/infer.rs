@@ -0,0 +1,277 @@
+// imports
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use aoc_core::{Constraints, Unsolvable};
+
+use crate::instruction::{Instruction, Opcode};
+use crate::registers::{Registers, Value};
+
+// code
+
+// an instruction as it appears in the puzzle input of day 16: the opcode number, input A,
+// input B, and output register C
+pub type NumberedInstruction = [Value; 4];
+
+pub type OpcodeMap = BTreeMap<Value, Opcode>;
+
+pub fn with_opcode(instruction: &NumberedInstruction, opcode: Opcode) -> Instruction {
+    let [_opcode_number, a, b, c] = *instruction;
+    Instruction::new(opcode, a, b, c)
+}
+
+// The registers before and after the device ran an instruction whose opcode is only known by
+// its number, e.g.
+//
+// Before: [3, 2, 1, 1]
+// 9 2 1 2
+// After:  [3, 2, 2, 1]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    pub before: Registers,
+    pub instruction: NumberedInstruction,
+    pub after: Registers,
+}
+
+impl Sample {
+    pub fn opcode_number(&self) -> Value {
+        self.instruction[0]
+    }
+
+    pub fn behaves_like(&self, opcode: Opcode) -> bool {
+        let mut registers = self.before.clone();
+
+        match with_opcode(&self.instruction, opcode).execute(&mut registers) {
+            Err(_) => false,
+            Ok(()) => registers == self.after,
+        }
+    }
+
+    // every opcode that the sample behaves like
+    pub fn candidates(&self) -> Vec<Opcode> {
+        Opcode::ALL
+            .iter()
+            .copied()
+            .filter(|opcode| self.behaves_like(*opcode))
+            .collect()
+    }
+}
+
+impl fmt::Display for Sample {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [opcode_number, a, b, c] = self.instruction;
+
+        write!(
+            f,
+            "Before: {:?} / {} {} {} {} / After: {:?}",
+            self.before.values(),
+            opcode_number,
+            a,
+            b,
+            c,
+            self.after.values()
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InferenceError {
+    // the sample does not behave like any opcode
+    NoMatchingOpcode {
+        sample: Sample,
+    },
+    // the sample rules out every opcode that the earlier samples of its number left
+    ContradictorySample {
+        sample: Sample,
+    },
+    // every opcode that the samples of the number allow is taken by another number
+    Unsatisfiable {
+        opcode_number: Value,
+        sample: Sample,
+    },
+    // the number may be either opcode, and every sample still holds
+    Ambiguous {
+        opcode_number: Value,
+        opcodes: [Opcode; 2],
+        sample: Sample,
+    },
+}
+
+impl fmt::Display for InferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InferenceError::NoMatchingOpcode { sample } => {
+                write!(f, "no opcode behaves like the sample {}", sample)
+            }
+            InferenceError::ContradictorySample { sample } => write!(
+                f,
+                "the sample {} contradicts the earlier samples of opcode number {}",
+                sample,
+                sample.opcode_number()
+            ),
+            InferenceError::Unsatisfiable {
+                opcode_number,
+                sample,
+            } => write!(
+                f,
+                "every opcode left for opcode number {} belongs to another number, \
+                 after the sample {}",
+                opcode_number, sample
+            ),
+            InferenceError::Ambiguous {
+                opcode_number,
+                opcodes: [first, second],
+                sample,
+            } => write!(
+                f,
+                "opcode number {} may be either {} or {}, after the sample {}",
+                opcode_number,
+                first.name(),
+                second.name(),
+                sample
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InferenceError {}
+
+// Work out which opcode every number that the samples use stands for, where no two numbers
+// stand for the same opcode.
+pub fn infer_opcodes(samples: &[Sample]) -> Result<OpcodeMap, InferenceError> {
+    let mut constraints = Constraints::new();
+
+    // the sample that last narrowed down the opcodes of each number, to point at when the
+    // number can not be solved
+    let mut narrowed_by: BTreeMap<Value, &Sample> = BTreeMap::new();
+
+    for sample in samples {
+        let candidates = sample.candidates();
+        if candidates.is_empty() {
+            return Err(InferenceError::NoMatchingOpcode {
+                sample: sample.clone(),
+            });
+        }
+
+        let opcode_number = sample.opcode_number();
+        let num_of_candidates = constraints
+            .candidates(opcode_number)
+            .map(|candidates| candidates.len());
+
+        if constraints.restrict(opcode_number, candidates).is_err() {
+            return Err(InferenceError::ContradictorySample {
+                sample: sample.clone(),
+            });
+        }
+
+        if num_of_candidates != constraints.candidates(opcode_number).map(|c| c.len()) {
+            narrowed_by.insert(opcode_number, sample);
+        }
+    }
+
+    constraints.solve().map_err(|unsolvable| match unsolvable {
+        Unsolvable::Contradiction { variable } => InferenceError::Unsatisfiable {
+            opcode_number: variable,
+            sample: narrowed_by[&variable].clone(),
+        },
+        Unsolvable::Ambiguous { variable, values } => InferenceError::Ambiguous {
+            opcode_number: variable,
+            opcodes: values,
+            sample: narrowed_by[&variable].clone(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(before: [Value; 4], instruction: NumberedInstruction, after: [Value; 4]) -> Sample {
+        Sample {
+            before: Registers::from(before.to_vec()),
+            instruction,
+            after: Registers::from(after.to_vec()),
+        }
+    }
+
+    #[test]
+    fn test_candidates() {
+        // example of day 16
+        let example = sample([3, 2, 1, 1], [9, 2, 1, 2], [3, 2, 2, 1]);
+        assert_eq!(
+            example.candidates(),
+            vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti]
+        );
+        assert_eq!(
+            example.to_string(),
+            "Before: [3, 2, 1, 1] / 9 2 1 2 / After: [3, 2, 2, 1]"
+        );
+    }
+
+    #[test]
+    fn test_infer_opcodes() {
+        // 9 is addi, mulr or seti; 7 is seti; 9 is mulr; 4 is addr or borr
+        let samples = vec![
+            sample([3, 2, 1, 1], [9, 2, 1, 2], [3, 2, 2, 1]),
+            sample([5, 6, 7, 8], [7, 3, 0, 0], [3, 6, 7, 8]),
+            sample([2, 3, 0, 0], [9, 0, 1, 3], [2, 3, 0, 6]),
+            sample([1, 2, 0, 0], [4, 0, 1, 3], [1, 2, 0, 3]),
+        ];
+
+        assert_eq!(
+            infer_opcodes(&samples),
+            Err(InferenceError::Ambiguous {
+                opcode_number: 4,
+                opcodes: [Opcode::Addr, Opcode::Borr],
+                sample: samples[3].clone(),
+            })
+        );
+
+        // 1 | 3 is not 4
+        let mut samples = samples;
+        samples.push(sample([1, 3, 0, 0], [4, 0, 1, 3], [1, 3, 0, 4]));
+
+        assert_eq!(
+            infer_opcodes(&samples),
+            Ok(OpcodeMap::from([
+                (4, Opcode::Addr),
+                (7, Opcode::Seti),
+                (9, Opcode::Mulr)
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_inference_errors() {
+        let nothing = sample([0, 0, 0, 0], [1, 0, 0, 0], [7, 7, 7, 7]);
+        assert_eq!(
+            infer_opcodes(std::slice::from_ref(&nothing)),
+            Err(InferenceError::NoMatchingOpcode { sample: nothing })
+        );
+
+        let seti = sample([5, 6, 7, 8], [7, 3, 0, 0], [3, 6, 7, 8]);
+        let not_seti = sample([5, 6, 7, 8], [7, 3, 0, 0], [0, 6, 7, 8]);
+        let error = infer_opcodes(&[seti, not_seti.clone()]).unwrap_err();
+        assert_eq!(
+            error,
+            InferenceError::ContradictorySample {
+                sample: not_seti.clone()
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "the sample Before: [5, 6, 7, 8] / 7 3 0 0 / After: [0, 6, 7, 8] contradicts the \
+             earlier samples of opcode number 7"
+        );
+
+        // both 1 and 2 can only be mulr
+        let first = sample([2, 3, 0, 0], [1, 0, 1, 3], [2, 3, 0, 6]);
+        let second = sample([2, 3, 0, 0], [2, 0, 1, 3], [2, 3, 0, 6]);
+        let third = sample([2, 3, 0, 0], [2, 1, 0, 3], [2, 3, 0, 6]);
+        assert!(matches!(
+            infer_opcodes(&[first, second, third]),
+            Err(InferenceError::Unsatisfiable { .. })
+        ));
+    }
+}
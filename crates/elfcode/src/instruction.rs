@@ -0,0 +1,225 @@
+// imports
+
+use std::fmt;
+
+use crate::program::Error;
+use crate::registers::{Registers, Value};
+
+// code
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+// How an instruction reads its input A or B.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    // the input is the number of a register, whose value is used
+    Register,
+    // the input is the value itself
+    Immediate,
+    Ignored,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 16] = [
+        Opcode::Addr,
+        Opcode::Addi,
+        Opcode::Mulr,
+        Opcode::Muli,
+        Opcode::Banr,
+        Opcode::Bani,
+        Opcode::Borr,
+        Opcode::Bori,
+        Opcode::Setr,
+        Opcode::Seti,
+        Opcode::Gtir,
+        Opcode::Gtri,
+        Opcode::Gtrr,
+        Opcode::Eqir,
+        Opcode::Eqri,
+        Opcode::Eqrr,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Opcode::ALL.into_iter().find(|opcode| opcode.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Opcode::Addr => "addr",
+            Opcode::Addi => "addi",
+            Opcode::Mulr => "mulr",
+            Opcode::Muli => "muli",
+            Opcode::Banr => "banr",
+            Opcode::Bani => "bani",
+            Opcode::Borr => "borr",
+            Opcode::Bori => "bori",
+            Opcode::Setr => "setr",
+            Opcode::Seti => "seti",
+            Opcode::Gtir => "gtir",
+            Opcode::Gtri => "gtri",
+            Opcode::Gtrr => "gtrr",
+            Opcode::Eqir => "eqir",
+            Opcode::Eqri => "eqri",
+            Opcode::Eqrr => "eqrr",
+        }
+    }
+
+    // how inputs A and B are read; the output C is always a register
+    pub fn operands(&self) -> (Operand, Operand) {
+        use Operand::*;
+
+        match self {
+            Opcode::Addr | Opcode::Mulr | Opcode::Banr | Opcode::Borr => (Register, Register),
+            Opcode::Addi | Opcode::Muli | Opcode::Bani | Opcode::Bori => (Register, Immediate),
+            Opcode::Setr => (Register, Ignored),
+            Opcode::Seti => (Immediate, Ignored),
+            Opcode::Gtir | Opcode::Eqir => (Immediate, Register),
+            Opcode::Gtri | Opcode::Eqri => (Register, Immediate),
+            Opcode::Gtrr | Opcode::Eqrr => (Register, Register),
+        }
+    }
+
+    // the value that is stored into register C, given the values that inputs A and B were read as
+    pub fn evaluate(&self, a: Value, b: Value) -> Value {
+        match self {
+            // addr (add register) and addi (add immediate)
+            Opcode::Addr | Opcode::Addi => a + b,
+            // mulr (multiply register) and muli (multiply immediate)
+            Opcode::Mulr | Opcode::Muli => a * b,
+            // banr (bitwise AND register) and bani (bitwise AND immediate)
+            Opcode::Banr | Opcode::Bani => a & b,
+            // borr (bitwise OR register) and bori (bitwise OR immediate)
+            Opcode::Borr | Opcode::Bori => a | b,
+            // setr (set register) and seti (set immediate) ignore input B
+            Opcode::Setr | Opcode::Seti => a,
+            // greater-than testing sets register C to 1 if A is greater than B, otherwise to 0
+            Opcode::Gtir | Opcode::Gtri | Opcode::Gtrr => (a > b) as Value,
+            // equality testing sets register C to 1 if A is equal to B, otherwise to 0
+            Opcode::Eqir | Opcode::Eqri | Opcode::Eqrr => (a == b) as Value,
+        }
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// e.g. addi 4 16 4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub a: Value,
+    pub b: Value,
+    // the output register
+    pub c: Value,
+}
+
+impl Instruction {
+    pub fn new(opcode: Opcode, a: Value, b: Value, c: Value) -> Self {
+        Instruction { opcode, a, b, c }
+    }
+
+    fn read(registers: &Registers, operand: Operand, input: Value) -> Result<Value, Error> {
+        match operand {
+            Operand::Register => registers.get(input),
+            Operand::Immediate => Ok(input),
+            Operand::Ignored => Ok(0),
+        }
+    }
+
+    // Fails without changing any register if the instruction refers to a register that does
+    // not exist.
+    pub fn execute(&self, registers: &mut Registers) -> Result<(), Error> {
+        let (operand_a, operand_b) = self.opcode.operands();
+
+        let a = Instruction::read(registers, operand_a, self.a)?;
+        let b = Instruction::read(registers, operand_b, self.b)?;
+
+        registers.set(self.c, self.opcode.evaluate(a, b))
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.opcode, self.a, self.b, self.c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execute(opcode: Opcode, a: Value, b: Value, before: &[Value]) -> Result<Value, Error> {
+        let mut registers = Registers::from(before.to_vec());
+        Instruction::new(opcode, a, b, 0).execute(&mut registers)?;
+        registers.get(0)
+    }
+
+    #[test]
+    fn test_opcodes() {
+        for opcode in Opcode::ALL {
+            assert_eq!(Opcode::from_name(opcode.name()), Some(opcode));
+        }
+        assert_eq!(Opcode::from_name("nop"), None);
+
+        let registers = [0, 12, 10, 3];
+        assert_eq!(execute(Opcode::Addr, 1, 2, &registers), Ok(22));
+        assert_eq!(execute(Opcode::Addi, 1, 2, &registers), Ok(14));
+        assert_eq!(execute(Opcode::Mulr, 1, 2, &registers), Ok(120));
+        assert_eq!(execute(Opcode::Muli, 1, 2, &registers), Ok(24));
+        assert_eq!(execute(Opcode::Banr, 1, 2, &registers), Ok(8));
+        assert_eq!(execute(Opcode::Bani, 1, 2, &registers), Ok(0));
+        assert_eq!(execute(Opcode::Borr, 1, 2, &registers), Ok(14));
+        assert_eq!(execute(Opcode::Bori, 1, 2, &registers), Ok(14));
+        assert_eq!(execute(Opcode::Setr, 1, 99, &registers), Ok(12));
+        assert_eq!(execute(Opcode::Seti, 1, 99, &registers), Ok(1));
+        assert_eq!(execute(Opcode::Gtir, 11, 2, &registers), Ok(1));
+        assert_eq!(execute(Opcode::Gtri, 1, 12, &registers), Ok(0));
+        assert_eq!(execute(Opcode::Gtrr, 1, 2, &registers), Ok(1));
+        assert_eq!(execute(Opcode::Eqir, 10, 2, &registers), Ok(1));
+        assert_eq!(execute(Opcode::Eqri, 1, 12, &registers), Ok(1));
+        assert_eq!(execute(Opcode::Eqrr, 1, 2, &registers), Ok(0));
+    }
+
+    #[test]
+    fn test_invalid_registers() {
+        // example from day 16: 9 2 1 2 behaves like mulr, addi and seti
+        let before = [3, 2, 1, 1];
+        let matching: Vec<Opcode> = Opcode::ALL
+            .into_iter()
+            .filter(|opcode| {
+                let mut registers = Registers::from(before.to_vec());
+                Instruction::new(*opcode, 2, 1, 2)
+                    .execute(&mut registers)
+                    .is_ok()
+                    && registers.values() == [3, 2, 2, 1]
+            })
+            .collect();
+        assert_eq!(matching, vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti]);
+
+        assert_eq!(
+            execute(Opcode::Setr, 4, 0, &before),
+            Err(Error::InvalidRegister { register: 4 })
+        );
+    }
+}
@@ -0,0 +1,23 @@
+// ElfCode device shared by the 2018 puzzles.
+// https://adventofcode.com/2018/day/16
+// https://adventofcode.com/2018/day/19
+// https://adventofcode.com/2018/day/21
+
+mod decompile;
+mod infer;
+mod instruction;
+mod optimise;
+mod program;
+mod registers;
+
+pub use decompile::{
+    decompile, Assignment, Block, ControlFlowGraph, Decompiled, Expression, Operator, Statement,
+    Terminator,
+};
+pub use infer::{
+    infer_opcodes, with_opcode, InferenceError, NumberedInstruction, OpcodeMap, Sample,
+};
+pub use instruction::{Instruction, Opcode, Operand};
+pub use optimise::{verify, Idiom, Mismatch, OptimisedProgram, Run};
+pub use program::{Error, Program, Status};
+pub use registers::{Registers, Value};
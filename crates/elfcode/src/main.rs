@@ -0,0 +1,197 @@
+// imports
+
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use clap::{Parser, Subcommand};
+use elfcode::{decompile, verify, ControlFlowGraph, OptimisedProgram, Program, Terminator, Value};
+
+// code
+
+// the device that the programs of days 19 and 21 run on
+const NUM_OF_REGISTERS: usize = 6;
+
+#[derive(Parser, Debug)]
+#[command(name = "elfcode", about = "Decompile and run ElfCode programs")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(about = "Lift the program into structured pseudo-code")]
+    Decompile {
+        #[arg(help = "The file with the program, or - to read it from stdin")]
+        program: String,
+
+        #[arg(long, help = "Print the basic blocks of the program instead")]
+        blocks: bool,
+    },
+
+    #[command(about = "Run the program, executing its hot loops natively")]
+    Run {
+        #[arg(help = "The file with the program, or - to read it from stdin")]
+        program: String,
+
+        #[arg(
+            long,
+            value_delimiter = ',',
+            allow_hyphen_values = true,
+            help = "The values that the registers start with, e.g. --registers 1,0,0"
+        )]
+        registers: Vec<Value>,
+
+        #[arg(
+            long,
+            help = "Also run the program with the interpreter, and check that both end the same"
+        )]
+        verify: bool,
+    },
+}
+
+fn read_program(path: &str) -> Result<Program, String> {
+    let input_string = if path == "-" {
+        let mut input_string = String::new();
+        io::stdin()
+            .read_to_string(&mut input_string)
+            .map_err(|error| format!("unable to read stdin: {}", error))?;
+        input_string
+    } else {
+        fs::read_to_string(path).map_err(|error| format!("unable to read {}: {}", path, error))?
+    };
+
+    Program::parse(&input_string, NUM_OF_REGISTERS)
+        .map_err(|error| format!("{}\n{}", error, error.snippet()))
+}
+
+// e.g.
+// 3..6:
+//     r2 = r3 * r1
+//     if r2 == r5 goto 7 else goto 6
+fn format_blocks(graph: &ControlFlowGraph) -> Vec<String> {
+    let target = |address: usize| {
+        if address == graph.halt {
+            "halt".to_string()
+        } else {
+            format!("goto {}", address)
+        }
+    };
+
+    let mut lines = vec![];
+
+    for block in graph.blocks.values() {
+        lines.push(format!("{}..{}:", block.start, block.end));
+        lines.extend(
+            block
+                .assignments
+                .iter()
+                .map(|assignment| format!("    {}", assignment)),
+        );
+
+        lines.push(match &block.terminator {
+            Terminator::Goto(address) => format!("    {}", target(*address)),
+            Terminator::Branch {
+                condition,
+                then,
+                otherwise,
+            } => format!(
+                "    if {} {} else {}",
+                condition,
+                target(*then),
+                target(*otherwise)
+            ),
+            Terminator::Computed(expression) => {
+                format!("    goto {}", expression.computed_target())
+            }
+        });
+    }
+
+    lines
+}
+
+fn run(mut program: Program, registers: Vec<Value>, should_verify: bool) -> Result<(), String> {
+    for (register, value) in registers.into_iter().enumerate() {
+        program
+            .registers_mut()
+            .set(register as Value, value)
+            .map_err(|error| error.to_string())?;
+    }
+
+    if should_verify {
+        let run = verify(&program).map_err(|mismatch| mismatch.to_string())?;
+        println!(
+            "registers {:?} after {} instructions, as with the interpreter",
+            run.registers.values(),
+            run.num_of_instructions_executed
+        );
+        return Ok(());
+    }
+
+    let mut program = OptimisedProgram::new(program);
+    for (header, idiom) in program.hot_loops() {
+        println!("hot loop at {}: {}", header, idiom);
+    }
+
+    program.run().map_err(|error| error.to_string())?;
+    println!(
+        "registers {:?} after {} instructions",
+        program.program().registers().values(),
+        program.program().num_of_instructions_executed()
+    );
+
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Decompile { program, blocks } => read_program(&program).map(|program| {
+            if blocks {
+                for line in format_blocks(&ControlFlowGraph::build(&program)) {
+                    println!("{}", line);
+                }
+            } else {
+                print!("{}", decompile(&program));
+            }
+        }),
+        Command::Run {
+            program,
+            registers,
+            verify,
+        } => read_program(&program).and_then(|program| run(program, registers, verify)),
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_blocks() {
+        let program = Program::parse(
+            "#ip 2\nseti 3 0 1\neqri 1 3 0\naddr 0 2 2\nmuli 1 2 1",
+            NUM_OF_REGISTERS,
+        )
+        .unwrap();
+
+        assert_eq!(
+            format_blocks(&ControlFlowGraph::build(&program)),
+            vec![
+                "0..3:",
+                "    r1 = 3",
+                "    if r1 == 3 halt else goto 3",
+                "3..4:",
+                "    r1 *= 2",
+                "    halt"
+            ]
+        );
+    }
+}
@@ -0,0 +1,1272 @@
+// imports
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::decompile::{ControlFlowGraph, Expression, Operator};
+use crate::instruction::Operand;
+use crate::program::{Error, Program, Status};
+use crate::registers::{Registers, Value};
+
+// code
+
+// loops with more paths or longer paths than these are left to the interpreter
+const MAX_NUM_OF_PATHS: usize = 8;
+const MAX_PATH_LENGTH: u64 = 64;
+
+// The kind of computation that a hot loop performs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Idiom {
+    // a register counts up until a condition holds, e.g. r3 = r4 / 256
+    Counting,
+    // registers are incremented by the same amount on every iteration, e.g. r0 += r2 * r3
+    RepeatedAddition,
+    // a register is incremented on the iterations where a condition holds,
+    // e.g. r0 += r3 when r3 * r1 == r5 for some r1, i.e. when r3 divides r5
+    DivisorSum,
+}
+
+impl fmt::Display for Idiom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            Idiom::Counting => "counting loop",
+            Idiom::RepeatedAddition => "multiplication by repeated addition",
+            Idiom::DivisorSum => "divisor sum",
+        };
+
+        write!(f, "{}", description)
+    }
+}
+
+// A path through one iteration of a loop, from its header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Path {
+    // every branch along the path, with whether its condition held
+    conditions: Vec<(Expression, bool)>,
+    // the registers at the header of the inner loop that the path runs through, if any; the
+    // registers and conditions after it are in terms of the registers that the inner loop exits
+    // with
+    before_inner: Option<Vec<Expression>>,
+    // the value of every register at the end of the path, in terms of the registers at the header
+    registers: Vec<Expression>,
+    // the header of the loop, or the address the loop exits to
+    next: Value,
+    num_of_instructions: u64,
+}
+
+impl Path {
+    fn holds(&self, registers: &[Value]) -> Option<bool> {
+        for (condition, held) in &self.conditions {
+            if (condition.evaluate(registers)? != 0) != *held {
+                return Some(false);
+            }
+        }
+
+        Some(true)
+    }
+
+    fn condition(&self, condition: &Expression) -> Option<bool> {
+        self.conditions
+            .iter()
+            .find(|(other, _)| other == condition)
+            .map(|(_, held)| *held)
+    }
+}
+
+// the registers whose value is not the one they started with
+fn changes(registers: &[Expression]) -> BTreeSet<Value> {
+    (0..registers.len() as Value)
+        .filter(|register| registers[*register as usize] != Expression::Register(*register))
+        .collect()
+}
+
+fn read_operand(registers: &[Expression], operand: Operand, input: Value) -> Option<Expression> {
+    match operand {
+        Operand::Register => registers.get(input as usize).cloned(),
+        Operand::Immediate => Some(Expression::Constant(input)),
+        Operand::Ignored => Some(Expression::Constant(0)),
+    }
+}
+
+fn symbolic_registers(num_of_registers: usize) -> Vec<Expression> {
+    (0..num_of_registers as Value)
+        .map(Expression::Register)
+        .collect()
+}
+
+// Execute every path through the loop symbolically, until it either jumps back to the header or
+// leaves the body of the loop. A path that reaches the header of the inner loop, if any, skips
+// to the address that the inner loop exits to.
+// None if a jump depends on more than a comparison.
+fn explore(
+    program: &Program,
+    header: Value,
+    body: &BTreeSet<Value>,
+    inner: Option<(Value, Value)>,
+) -> Option<Vec<Path>> {
+    let num_of_registers = program.registers().len();
+    let instruction_pointer_register = program.instruction_pointer_register()?;
+
+    let start = Path {
+        conditions: vec![],
+        before_inner: None,
+        registers: symbolic_registers(num_of_registers),
+        next: header,
+        num_of_instructions: 0,
+    };
+
+    let mut pending = vec![(start, BTreeSet::new())];
+    let mut paths = vec![];
+
+    while let Some((mut path, mut visited)) = pending.pop() {
+        loop {
+            let address = path.next;
+            if path.num_of_instructions > 0 && (address == header || !body.contains(&address)) {
+                paths.push(path);
+                break;
+            }
+
+            if let Some((inner_header, inner_exit)) = inner {
+                if address == inner_header && path.before_inner.is_none() {
+                    let registers = symbolic_registers(num_of_registers);
+                    path.before_inner = Some(std::mem::replace(&mut path.registers, registers));
+                    path.next = inner_exit;
+                    continue;
+                }
+            }
+
+            // other loops in the loop are not supported
+            if !visited.insert(address) || path.num_of_instructions >= MAX_PATH_LENGTH {
+                return None;
+            }
+
+            let instruction = program.instructions()[address as usize];
+            let (operand_a, operand_b) = instruction.opcode.operands();
+
+            path.registers[instruction_pointer_register as usize] = Expression::Constant(address);
+
+            let a = read_operand(&path.registers, operand_a, instruction.a)?;
+            let b = read_operand(&path.registers, operand_b, instruction.b)?;
+            let value = match Operator::from_opcode(instruction.opcode) {
+                Some(operator) => Expression::binary(operator, a, b),
+                None => a,
+            };
+
+            *path.registers.get_mut(instruction.c as usize)? = value.clone();
+            path.num_of_instructions += 1;
+
+            if instruction.c != instruction_pointer_register {
+                path.next = address + 1;
+                continue;
+            }
+
+            match value {
+                Expression::Constant(value) => path.next = value + 1,
+                Expression::Binary(Operator::Add, left, right) => {
+                    let (value, condition) = match (*left, *right) {
+                        (Expression::Constant(value), condition)
+                        | (condition, Expression::Constant(value))
+                            if condition.is_boolean() =>
+                        {
+                            (value, condition)
+                        }
+                        _ => return None,
+                    };
+
+                    let mut taken = path.clone();
+                    taken.registers[instruction_pointer_register as usize] =
+                        Expression::Constant(value + 1);
+                    taken.conditions.push((condition.clone(), true));
+                    taken.next = value + 2;
+                    pending.push((taken, visited.clone()));
+
+                    path.registers[instruction_pointer_register as usize] =
+                        Expression::Constant(value);
+                    path.conditions.push((condition, false));
+                    path.next = value + 1;
+                }
+                _ => return None,
+            }
+
+            if paths.len() + pending.len() >= MAX_NUM_OF_PATHS {
+                return None;
+            }
+        }
+    }
+
+    Some(paths)
+}
+
+fn reads_only(expression: &Expression, registers: &BTreeSet<Value>) -> bool {
+    let mut read = BTreeSet::new();
+    expression.registers(&mut read);
+    read.is_subset(registers)
+}
+
+fn reads_any(expression: &Expression, registers: &BTreeSet<Value>) -> bool {
+    let mut read = BTreeSet::new();
+    expression.registers(&mut read);
+    !read.is_disjoint(registers)
+}
+
+// the degree of the expression as a polynomial of the register; None if it is not a polynomial
+fn degree(expression: &Expression, register: Value) -> Option<u32> {
+    match expression {
+        Expression::Register(other) => Some((*other == register) as u32),
+        Expression::Constant(_) => Some(0),
+        Expression::Binary(operator, left, right) => {
+            let left = degree(left, register)?;
+            let right = degree(right, register)?;
+
+            match operator {
+                Operator::Add => Some(left.max(right)),
+                Operator::Multiply => Some(left + right),
+                _ if left == 0 && right == 0 => Some(0),
+                _ => None,
+            }
+        }
+    }
+}
+
+// a comparison whose sides both change linearly with the register
+fn is_linear_comparison(expression: &Expression, register: Value) -> bool {
+    match expression {
+        Expression::Binary(operator, left, right) if operator.is_comparison() => {
+            degree(left, register).is_some_and(|degree| degree <= 1)
+                && degree(right, register).is_some_and(|degree| degree <= 1)
+        }
+        _ => false,
+    }
+}
+
+// The difference between the sides of a linear comparison when the register has its value, and
+// how much it changes when the register is one more.
+fn linear(
+    comparison: &Expression,
+    register: Value,
+    registers: &mut [Value],
+) -> Option<(Value, Value)> {
+    let (left, right) = match comparison {
+        Expression::Binary(_, left, right) => (left, right),
+        _ => return None,
+    };
+
+    let register = register as usize;
+    let first = registers[register];
+
+    let mut differences = [0; 2];
+    for (index, difference) in differences.iter_mut().enumerate() {
+        registers[register] = first.checked_add(index as Value)?;
+        *difference = left
+            .evaluate(registers)?
+            .checked_sub(right.evaluate(registers)?)?;
+    }
+    registers[register] = first;
+
+    Some((differences[0], differences[1].checked_sub(differences[0])?))
+}
+
+// The first iteration where difference + step * iteration compares to 0 with the operator;
+// None if there is none.
+fn first_iteration(operator: Operator, difference: Value, step: Value) -> Option<Value> {
+    match operator {
+        Operator::GreaterThan if difference > 0 => Some(0),
+        Operator::GreaterThan if step > 0 => Some(difference.checked_neg()? / step + 1),
+        Operator::LessThanOrEqual if difference <= 0 => Some(0),
+        Operator::LessThanOrEqual if step < 0 => {
+            let step = step.checked_neg()?;
+            Some(difference.checked_add(step - 1)? / step)
+        }
+        Operator::Equal if difference == 0 => Some(0),
+        Operator::Equal if step != 0 => {
+            let distance = difference.checked_neg()?;
+            if distance % step == 0 && distance / step > 0 {
+                Some(distance / step)
+            } else {
+                None
+            }
+        }
+        Operator::NotEqual if difference != 0 => Some(0),
+        Operator::NotEqual if step != 0 => Some(1),
+        _ => None,
+    }
+}
+
+// the iteration where a loop that counts the register up by one exits, given its registers at
+// the header
+fn exit_iteration(
+    exit_condition: &(Expression, bool),
+    register: Value,
+    registers: &[Value],
+) -> Option<Value> {
+    let (condition, exits_when) = exit_condition;
+    let (difference, step) = linear(condition, register, &mut registers.to_vec())?;
+
+    let operator = match condition {
+        Expression::Binary(operator, _, _) if *exits_when => *operator,
+        Expression::Binary(operator, _, _) => operator.negate()?,
+        _ => return None,
+    };
+
+    first_iteration(operator, difference, step)
+}
+
+// the number of iterations before the given one where difference + step * iteration is 0
+fn num_of_zeroes(difference: Value, step: Value, iteration: Value) -> Option<Value> {
+    if step == 0 {
+        return Some(if difference == 0 { iteration } else { 0 });
+    }
+
+    let distance = difference.checked_neg()?;
+    let is_zero_before = distance % step == 0 && (0..iteration).contains(&(distance / step));
+
+    Some(is_zero_before as Value)
+}
+
+// r + increment
+fn increment(expression: &Expression, register: Value) -> Option<&Expression> {
+    match expression {
+        Expression::Binary(Operator::Add, left, right) => match (left.as_ref(), right.as_ref()) {
+            (Expression::Register(other), increment) | (increment, Expression::Register(other))
+                if *other == register && !increment.reads(register) =>
+            {
+                Some(increment)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// The condition that every exit path agrees on, and every path that continues the loop
+// disagrees with.
+fn find_exit_condition(continue_paths: &[Path], exit_paths: &[Path]) -> Option<(Expression, bool)> {
+    exit_paths
+        .first()?
+        .conditions
+        .iter()
+        .find(|(condition, held)| {
+            exit_paths
+                .iter()
+                .all(|path| path.condition(condition) == Some(*held))
+                && continue_paths
+                    .iter()
+                    .all(|path| path.condition(condition) == Some(!*held))
+        })
+        .cloned()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Accumulator {
+    register: Value,
+    // an expression of the registers that the loop does not change
+    increment: Expression,
+    // None if the register is incremented on every iteration, or otherwise whether the guard of
+    // the loop holds on the iterations that increment it
+    guard: Option<bool>,
+}
+
+// A loop whose iterations can all be run at once, as it counts a register up by one until a
+// comparison that changes linearly with that register decides to exit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CountedLoop {
+    induction: Value,
+    // the loop exits on the first iteration where the condition is the given value
+    exit_condition: (Expression, bool),
+    // the other branch of the loop, if any, which is an equality that is linear in the induction
+    // register
+    guard: Option<Expression>,
+    accumulators: Vec<Accumulator>,
+    // registers that every iteration overwrites
+    temporaries: Vec<Value>,
+    continue_paths: Vec<Path>,
+    exit_paths: Vec<Path>,
+}
+
+impl CountedLoop {
+    fn analyse(header: Value, paths: Vec<Path>) -> Option<Self> {
+        let (continue_paths, exit_paths): (Vec<Path>, Vec<Path>) =
+            paths.into_iter().partition(|path| path.next == header);
+
+        if continue_paths.is_empty() || exit_paths.is_empty() || continue_paths.len() > 2 {
+            return None;
+        }
+
+        let exit_condition = find_exit_condition(&continue_paths, &exit_paths)?;
+
+        let mut guards: Vec<&Expression> = vec![];
+        for (condition, _) in continue_paths
+            .iter()
+            .chain(&exit_paths)
+            .flat_map(|path| &path.conditions)
+        {
+            if *condition != exit_condition.0 && !guards.contains(&condition) {
+                guards.push(condition);
+            }
+        }
+        if guards.len() > 1 {
+            return None;
+        }
+        let guard = guards.pop().cloned();
+
+        if continue_paths.len() == 2 {
+            let guard = guard.as_ref()?;
+            if continue_paths[0].condition(guard)? == continue_paths[1].condition(guard)? {
+                return None;
+            }
+        }
+
+        let num_of_registers = continue_paths[0].registers.len() as Value;
+        let mut counters = vec![];
+        let mut accumulators = vec![];
+        let mut temporaries = vec![];
+
+        for register in 0..num_of_registers {
+            let values: Vec<&Expression> = continue_paths
+                .iter()
+                .map(|path| &path.registers[register as usize])
+                .collect();
+            let unchanged = Expression::Register(register);
+
+            if values.iter().all(|value| **value == unchanged) {
+                continue;
+            }
+
+            if values.iter().all(|value| !value.reads(register)) {
+                temporaries.push(register);
+                continue;
+            }
+
+            let increments: Vec<Option<&Expression>> = values
+                .iter()
+                .map(|value| increment(value, register))
+                .collect();
+
+            let incremented = increments.iter().flatten().next().copied()?;
+            let guard = if increments.iter().all(|other| *other == Some(incremented)) {
+                None
+            } else {
+                // the other path leaves the register as it is
+                let (index, _) = increments
+                    .iter()
+                    .enumerate()
+                    .find(|(_, other)| other.is_some())?;
+                if *values[1 - index] != unchanged {
+                    return None;
+                }
+
+                Some(continue_paths[index].condition(guard.as_ref()?)?)
+            };
+
+            if *incremented == Expression::Constant(1) && guard.is_none() {
+                counters.push(register);
+            }
+
+            accumulators.push(Accumulator {
+                register,
+                increment: incremented.clone(),
+                guard,
+            });
+        }
+
+        // the register that counts the iterations decides when the loop exits
+        let induction = counters
+            .into_iter()
+            .find(|register| exit_condition.0.reads(*register))?;
+        accumulators.retain(|accumulator| accumulator.register != induction);
+
+        let temporaries_set: BTreeSet<Value> = temporaries.iter().copied().collect();
+        let mut changing: BTreeSet<Value> = accumulators
+            .iter()
+            .map(|accumulator| accumulator.register)
+            .chain(temporaries.iter().copied())
+            .collect();
+
+        // the iteration a condition holds on only depends on the induction register
+        for condition in std::iter::once(&exit_condition.0).chain(&guard) {
+            if reads_any(condition, &changing) || !is_linear_comparison(condition, induction) {
+                return None;
+            }
+        }
+        if let Some(Expression::Binary(operator, _, _)) = &guard {
+            if !matches!(operator, Operator::Equal | Operator::NotEqual) {
+                return None;
+            }
+        }
+
+        changing.insert(induction);
+        if accumulators
+            .iter()
+            .any(|accumulator| reads_any(&accumulator.increment, &changing))
+        {
+            return None;
+        }
+
+        // the values that temporaries are left with at the header are never read
+        let reads_temporaries = continue_paths.iter().chain(&exit_paths).any(|path| {
+            path.registers.iter().enumerate().any(|(register, value)| {
+                let is_unchanged = *value == Expression::Register(register as Value);
+                !is_unchanged && reads_any(value, &temporaries_set)
+            })
+        });
+        if reads_temporaries {
+            return None;
+        }
+
+        Some(CountedLoop {
+            induction,
+            exit_condition,
+            guard,
+            accumulators,
+            temporaries,
+            continue_paths,
+            exit_paths,
+        })
+    }
+
+    fn idiom(&self) -> Idiom {
+        if self.accumulators.is_empty() {
+            Idiom::Counting
+        } else if self
+            .accumulators
+            .iter()
+            .any(|accumulator| accumulator.guard.is_some())
+        {
+            Idiom::DivisorSum
+        } else {
+            Idiom::RepeatedAddition
+        }
+    }
+
+    // the number of iterations before the given one where the guard held
+    fn num_of_guards(&self, registers: &[Value], iteration: Value) -> Option<Value> {
+        let guard = match &self.guard {
+            Some(guard) => guard,
+            None => return Some(0),
+        };
+
+        let (difference, step) = linear(guard, self.induction, &mut registers.to_vec())?;
+        let num_of_zeroes = num_of_zeroes(difference, step, iteration)?;
+
+        match guard {
+            Expression::Binary(Operator::Equal, _, _) => Some(num_of_zeroes),
+            _ => Some(iteration - num_of_zeroes),
+        }
+    }
+
+    // the registers at the header of the iteration, apart from the temporaries
+    fn registers_at(&self, registers: &[Value], iteration: Value) -> Option<Vec<Value>> {
+        let num_of_guards = self.num_of_guards(registers, iteration)?;
+
+        let mut registers_at = registers.to_vec();
+        let induction = self.induction as usize;
+        registers_at[induction] = registers[induction].checked_add(iteration)?;
+
+        for accumulator in &self.accumulators {
+            let num_of_increments = match accumulator.guard {
+                None => iteration,
+                Some(true) => num_of_guards,
+                Some(false) => iteration - num_of_guards,
+            };
+
+            let increment = accumulator
+                .increment
+                .evaluate(registers)?
+                .checked_mul(num_of_increments)?;
+            let register = accumulator.register as usize;
+            registers_at[register] = registers[register].checked_add(increment)?;
+        }
+
+        Some(registers_at)
+    }
+
+    // the continue path that is taken that many times before the iteration, for each path
+    fn num_of_instructions_before(&self, registers: &[Value], iteration: Value) -> Option<u64> {
+        let num_of_guards = self.num_of_guards(registers, iteration)?;
+        let mut num_of_instructions: u64 = 0;
+
+        for path in &self.continue_paths {
+            let num_of_times = match &self.guard {
+                Some(guard) if self.continue_paths.len() == 2 => match path.condition(guard)? {
+                    true => num_of_guards,
+                    false => iteration - num_of_guards,
+                },
+                _ => iteration,
+            };
+
+            num_of_instructions = num_of_instructions
+                .checked_add(path.num_of_instructions.checked_mul(num_of_times as u64)?)?;
+        }
+
+        Some(num_of_instructions)
+    }
+
+    // Run every iteration of the loop at once, and return the registers after it, the address
+    // it exits to and the number of instructions that the interpreter would have executed.
+    // None if the loop would never exit, or if a value overflows.
+    fn run(&self, registers: &[Value]) -> Option<(Vec<Value>, Value, u64)> {
+        let num_of_iterations = exit_iteration(&self.exit_condition, self.induction, registers)?;
+        let mut at_exit = self.registers_at(registers, num_of_iterations)?;
+
+        // the temporaries hold what the last iteration before the exit left in them
+        if num_of_iterations > 0 {
+            let before_exit = self.registers_at(registers, num_of_iterations - 1)?;
+
+            let path = self
+                .continue_paths
+                .iter()
+                .find(|path| path.holds(&before_exit) == Some(true))?;
+
+            for temporary in &self.temporaries {
+                at_exit[*temporary as usize] =
+                    path.registers[*temporary as usize].evaluate(&before_exit)?;
+            }
+        }
+
+        let path = self
+            .exit_paths
+            .iter()
+            .find(|path| path.holds(&at_exit) == Some(true))?;
+
+        let final_registers = path
+            .registers
+            .iter()
+            .map(|value| value.evaluate(&at_exit))
+            .collect::<Option<Vec<Value>>>()?;
+
+        let num_of_instructions = self
+            .num_of_instructions_before(registers, num_of_iterations)?
+            .checked_add(path.num_of_instructions)?;
+
+        Some((final_registers, path.next, num_of_instructions))
+    }
+}
+
+// the divisors of a positive number
+fn divisors(number: Value) -> Vec<Value> {
+    let mut divisors = vec![];
+
+    let mut divisor: Value = 1;
+    while let Some(square) = divisor.checked_mul(divisor) {
+        if square > number {
+            break;
+        }
+
+        if number % divisor == 0 {
+            divisors.push(divisor);
+
+            if square != number {
+                divisors.push(number / divisor);
+            }
+        }
+
+        divisor += 1;
+    }
+
+    divisors
+}
+
+// A loop that counts a multiple up by one, and on every iteration runs a counted loop that adds
+// the multiple to an accumulator when multiple * inner induction == target, e.g. day 19:
+//
+// for r3 in 1..=r5 {
+//     for r1 in 1..=r5 {
+//         if r3 * r1 == r5 {
+//             r0 += r3
+//         }
+//     }
+// }
+//
+// Which is the sum of the divisors of the target, within the range of the multiple.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NestedDivisorSum {
+    inner: CountedLoop,
+    // the register that counts the iterations of this loop, which the inner loop multiplies
+    multiple: Value,
+    accumulator: Value,
+    target: Expression,
+    exit_condition: (Expression, bool),
+    // the registers at the header of the inner loop, in terms of the registers at the header
+    before_inner: Vec<Expression>,
+    // whether the exit iteration of the inner loop adds the multiple when the guard holds
+    inner_exit_adds: bool,
+    continue_path: Path,
+    exit_path: Path,
+}
+
+impl NestedDivisorSum {
+    fn analyse(header: Value, paths: Vec<Path>, inner: &CountedLoop) -> Option<Self> {
+        let (mut continue_paths, mut exit_paths): (Vec<Path>, Vec<Path>) =
+            paths.into_iter().partition(|path| path.next == header);
+
+        if continue_paths.len() != 1 || exit_paths.len() != 1 {
+            return None;
+        }
+
+        let continue_path = continue_paths.pop()?;
+        let exit_path = exit_paths.pop()?;
+        let exit_condition = find_exit_condition(
+            std::slice::from_ref(&continue_path),
+            std::slice::from_ref(&exit_path),
+        )?;
+        if continue_path.conditions.len() != 1 || exit_path.conditions.len() != 1 {
+            return None;
+        }
+
+        // every iteration runs the inner loop from the same registers
+        let before_inner = continue_path.before_inner.clone()?;
+        if exit_path.before_inner.as_ref() != Some(&before_inner) {
+            return None;
+        }
+
+        let (accumulator, multiple) = match inner.accumulators.as_slice() {
+            [Accumulator {
+                register,
+                increment: Expression::Register(multiple),
+                guard: Some(true),
+            }] => (*register, *multiple),
+            _ => return None,
+        };
+
+        // the registers that the inner loop changes
+        let mut changed_by_inner: BTreeSet<Value> = inner.temporaries.iter().copied().collect();
+        changed_by_inner.insert(inner.induction);
+        changed_by_inner.insert(accumulator);
+
+        let num_of_registers = before_inner.len();
+        let changed_before_inner = changes(&before_inner);
+        let changed_after_inner = changes(&continue_path.registers);
+
+        let invariants: BTreeSet<Value> = (0..num_of_registers as Value)
+            .filter(|register| {
+                !changed_before_inner.contains(register)
+                    && !changed_by_inner.contains(register)
+                    && !changed_after_inner.contains(register)
+            })
+            .collect();
+
+        // the inner loop only changes the accumulator of this loop, and always from scratch
+        if !invariants.contains(&multiple)
+            && continue_path.registers[multiple as usize]
+                != Expression::binary(
+                    Operator::Add,
+                    Expression::Register(multiple),
+                    Expression::Constant(1),
+                )
+        {
+            return None;
+        }
+        if changed_before_inner.contains(&multiple)
+            || changed_by_inner.contains(&multiple)
+            || changed_before_inner.contains(&accumulator)
+            || changed_after_inner.contains(&accumulator)
+            || !changed_before_inner.contains(&inner.induction)
+        {
+            return None;
+        }
+
+        if changed_before_inner
+            .iter()
+            .any(|register| !reads_only(&before_inner[*register as usize], &invariants))
+        {
+            return None;
+        }
+
+        let mut counted = invariants.clone();
+        counted.insert(multiple);
+
+        if changed_after_inner
+            .iter()
+            .any(|register| !reads_only(&continue_path.registers[*register as usize], &counted))
+        {
+            return None;
+        }
+        if !reads_only(&exit_condition.0, &counted)
+            || !is_linear_comparison(&exit_condition.0, multiple)
+        {
+            return None;
+        }
+
+        // multiple * inner induction == target
+        let target = match &inner.guard {
+            Some(Expression::Binary(Operator::Equal, left, right)) => {
+                let product = |expression: &Expression| match expression {
+                    Expression::Binary(Operator::Multiply, left, right) => matches!(
+                        (left.as_ref(), right.as_ref()),
+                        (Expression::Register(a), Expression::Register(b))
+                            if (*a, *b) == (multiple, inner.induction)
+                                || (*a, *b) == (inner.induction, multiple)
+                    ),
+                    _ => false,
+                };
+
+                if product(left) {
+                    right.as_ref().clone()
+                } else if product(right) {
+                    left.as_ref().clone()
+                } else {
+                    return None;
+                }
+            }
+            _ => return None,
+        };
+        if !reads_only(&target, &invariants) {
+            return None;
+        }
+
+        // the number of iterations of the inner loop is the same on every iteration
+        let mut set_before_inner = invariants.clone();
+        set_before_inner.extend(changed_before_inner.iter().copied());
+        if !reads_only(&inner.exit_condition.0, &set_before_inner) {
+            return None;
+        }
+
+        let guard = inner.guard.as_ref()?;
+        let mut inner_exit_adds = None;
+        for path in &inner.exit_paths {
+            let value = &path.registers[accumulator as usize];
+
+            let adds = if *value == Expression::Register(accumulator) {
+                false
+            } else if increment(value, accumulator) == Some(&Expression::Register(multiple))
+                && path.condition(guard) == Some(true)
+            {
+                true
+            } else {
+                return None;
+            };
+
+            if path.condition(guard) == Some(true) && *inner_exit_adds.get_or_insert(adds) != adds {
+                return None;
+            }
+        }
+
+        Some(NestedDivisorSum {
+            inner: inner.clone(),
+            multiple,
+            accumulator,
+            target,
+            exit_condition,
+            before_inner,
+            inner_exit_adds: inner_exit_adds.unwrap_or(false),
+            continue_path,
+            exit_path,
+        })
+    }
+
+    // the number of instructions of an iteration of the inner loop, without and with its guard
+    // holding
+    fn inner_lengths(paths: &[Path], guard: &Expression) -> Option<(u64, u64)> {
+        let length = |held: bool| {
+            paths
+                .iter()
+                .find(|path| path.condition(guard).unwrap_or(held) == held)
+                .map(|path| path.num_of_instructions)
+        };
+
+        Some((length(false)?, length(true)?))
+    }
+
+    // None if a value overflows, so that the interpreter runs the loop instead
+    fn run(&self, registers: &[Value]) -> Option<(Vec<Value>, Value, u64)> {
+        let first_multiple = registers[self.multiple as usize];
+        let target = self.target.evaluate(registers)?;
+        if first_multiple < 1 || target < 1 {
+            return None;
+        }
+
+        let num_of_iterations = exit_iteration(&self.exit_condition, self.multiple, registers)?;
+
+        let before_inner = self
+            .before_inner
+            .iter()
+            .map(|value| value.evaluate(registers))
+            .collect::<Option<Vec<Value>>>()?;
+        let num_of_inner_iterations = exit_iteration(
+            &self.inner.exit_condition,
+            self.inner.induction,
+            &before_inner,
+        )?;
+        let first_inner = before_inner[self.inner.induction as usize];
+        let exit_inner = first_inner.checked_add(num_of_inner_iterations)?;
+
+        let guard = self.inner.guard.as_ref()?;
+        let (length, length_with_guard) = Self::inner_lengths(&self.inner.continue_paths, guard)?;
+        let (exit_length, exit_length_with_guard) =
+            Self::inner_lengths(&self.inner.exit_paths, guard)?;
+
+        // the multiples before the exit iteration whose inner loop adds them
+        let mut sum: Value = 0;
+        let mut num_of_guards = 0;
+        let mut num_of_exit_guards = 0;
+
+        let last_multiple = first_multiple.checked_add(num_of_iterations)?;
+        for divisor in divisors(target) {
+            if divisor < first_multiple || divisor >= last_multiple {
+                continue;
+            }
+
+            let inner_induction = target / divisor;
+            if first_inner <= inner_induction && inner_induction < exit_inner {
+                sum = sum.checked_add(divisor)?;
+                num_of_guards += 1;
+            } else if inner_induction == exit_inner {
+                if self.inner_exit_adds {
+                    sum = sum.checked_add(divisor)?;
+                }
+                num_of_exit_guards += 1;
+            }
+        }
+
+        // a guard that shortens an iteration is not something this idiom knows how to count
+        let guard_length = length_with_guard.checked_sub(length)?;
+        let exit_guard_length = exit_length_with_guard.checked_sub(exit_length)?;
+
+        let length_of_iteration = self
+            .continue_path
+            .num_of_instructions
+            .checked_add(length.checked_mul(u64::try_from(num_of_inner_iterations).ok()?)?)?
+            .checked_add(exit_length)?;
+        let mut num_of_instructions = length_of_iteration
+            .checked_mul(u64::try_from(num_of_iterations).ok()?)?
+            .checked_add(guard_length.checked_mul(num_of_guards)?)?
+            .checked_add(exit_guard_length.checked_mul(num_of_exit_guards)?)?;
+
+        // the exit iteration is run as it is
+        let mut at_exit = registers.to_vec();
+        at_exit[self.multiple as usize] = last_multiple;
+        let accumulator = self.accumulator as usize;
+        at_exit[accumulator] = at_exit[accumulator].checked_add(sum)?;
+
+        let before_inner = self
+            .before_inner
+            .iter()
+            .map(|value| value.evaluate(&at_exit))
+            .collect::<Option<Vec<Value>>>()?;
+        let (after_inner, _, num_of_inner_instructions) = self.inner.run(&before_inner)?;
+
+        let final_registers = self
+            .exit_path
+            .registers
+            .iter()
+            .map(|value| value.evaluate(&after_inner))
+            .collect::<Option<Vec<Value>>>()?;
+
+        num_of_instructions = num_of_instructions
+            .checked_add(num_of_inner_instructions)?
+            .checked_add(self.exit_path.num_of_instructions)?;
+
+        Some((final_registers, self.exit_path.next, num_of_instructions))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HotLoop {
+    Counted(CountedLoop),
+    NestedDivisorSum(Box<NestedDivisorSum>),
+}
+
+impl HotLoop {
+    fn idiom(&self) -> Idiom {
+        match self {
+            HotLoop::Counted(counted_loop) => counted_loop.idiom(),
+            HotLoop::NestedDivisorSum(_) => Idiom::DivisorSum,
+        }
+    }
+
+    fn run(&self, registers: &[Value]) -> Option<(Vec<Value>, Value, u64)> {
+        match self {
+            HotLoop::Counted(counted_loop) => counted_loop.run(registers),
+            HotLoop::NestedDivisorSum(divisor_sum) => divisor_sum.run(registers),
+        }
+    }
+}
+
+// A program whose hot loops are executed natively, as a single step, whenever the instruction
+// pointer reaches their header. Every other instruction goes through the interpreter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimisedProgram {
+    program: Program,
+    // keyed by the address of the header of the loop
+    hot_loops: BTreeMap<Value, HotLoop>,
+}
+
+impl OptimisedProgram {
+    pub fn new(program: Program) -> Self {
+        let graph = ControlFlowGraph::build(&program);
+
+        let loops: Vec<(Value, BTreeSet<Value>)> = graph
+            .loops()
+            .into_iter()
+            .map(|(header, blocks)| {
+                let body = blocks
+                    .iter()
+                    .flat_map(|start| {
+                        let block = &graph.blocks[start];
+                        block.start as Value..block.end as Value
+                    })
+                    .collect();
+
+                (header as Value, body)
+            })
+            .collect();
+
+        let mut counted_loops = BTreeMap::new();
+        for (header, body) in &loops {
+            if let Some(counted_loop) = explore(&program, *header, body, None)
+                .and_then(|paths| CountedLoop::analyse(*header, paths))
+            {
+                counted_loops.insert(*header, counted_loop);
+            }
+        }
+
+        let mut hot_loops = BTreeMap::new();
+        for (header, body) in &loops {
+            if let Some(counted_loop) = counted_loops.get(header) {
+                hot_loops.insert(*header, HotLoop::Counted(counted_loop.clone()));
+                continue;
+            }
+
+            // a loop around a single counted loop
+            let inner_loops: Vec<(&Value, &CountedLoop)> = counted_loops
+                .iter()
+                .filter(|(inner_header, _)| *inner_header != header && body.contains(inner_header))
+                .collect();
+
+            let (inner_header, inner) = match inner_loops.as_slice() {
+                [inner_loop] => *inner_loop,
+                _ => continue,
+            };
+
+            let inner_exits: BTreeSet<Value> =
+                inner.exit_paths.iter().map(|path| path.next).collect();
+            let inner_exit = match inner_exits.iter().collect::<Vec<_>>().as_slice() {
+                [inner_exit] => **inner_exit,
+                _ => continue,
+            };
+
+            if let Some(divisor_sum) =
+                explore(&program, *header, body, Some((*inner_header, inner_exit)))
+                    .and_then(|paths| NestedDivisorSum::analyse(*header, paths, inner))
+            {
+                hot_loops.insert(*header, HotLoop::NestedDivisorSum(Box::new(divisor_sum)));
+            }
+        }
+
+        OptimisedProgram { program, hot_loops }
+    }
+
+    // the address of the header of every hot loop, along with what it computes
+    pub fn hot_loops(&self) -> Vec<(Value, Idiom)> {
+        self.hot_loops
+            .iter()
+            .map(|(header, hot_loop)| (*header, hot_loop.idiom()))
+            .collect()
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    pub fn program_mut(&mut self) -> &mut Program {
+        &mut self.program
+    }
+
+    pub fn into_program(self) -> Program {
+        self.program
+    }
+
+    // Execute the next instruction, or every iteration of the hot loop that starts at it.
+    pub fn execute_instruction(&mut self) -> Result<Status, Error> {
+        let instruction_pointer = self.program.instruction_pointer();
+
+        if let Some(hot_loop) = self.hot_loops.get(&instruction_pointer) {
+            // otherwise, the interpreter runs the loop
+            if let Some((registers, next, num_of_instructions)) =
+                hot_loop.run(self.program.registers().values())
+            {
+                *self.program.registers_mut() = Registers::from(registers);
+                self.program.jump(next, num_of_instructions);
+                return Ok(Status::NotHalted);
+            }
+        }
+
+        self.program.execute_instruction()
+    }
+
+    pub fn run(&mut self) -> Result<(), Error> {
+        while self.execute_instruction()? == Status::NotHalted {}
+        Ok(())
+    }
+}
+
+// How a program ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub registers: Registers,
+    pub num_of_instructions_executed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub interpreted: Result<Run, Error>,
+    pub optimised: Result<Run, Error>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let describe = |run: &Result<Run, Error>| match run {
+            Ok(run) => format!(
+                "registers {:?} after {} instructions",
+                run.registers.values(),
+                run.num_of_instructions_executed
+            ),
+            Err(error) => error.to_string(),
+        };
+
+        write!(
+            f,
+            "the interpreter ended with {}, but the optimised program ended with {}",
+            describe(&self.interpreted),
+            describe(&self.optimised)
+        )
+    }
+}
+
+// Run the program until it halts, both with the interpreter and with its hot loops executed
+// natively, and check that both end with the same registers after the same number of
+// instructions. The interpreter runs every instruction, so this is meant for small inputs.
+pub fn verify(program: &Program) -> Result<Run, Mismatch> {
+    let finish = |program: &Program| Run {
+        registers: program.registers().clone(),
+        num_of_instructions_executed: program.num_of_instructions_executed(),
+    };
+
+    let mut interpreted = program.clone();
+    let interpreted = interpreted.run().map(|_| finish(&interpreted));
+
+    let mut optimised = OptimisedProgram::new(program.clone());
+    let optimised = optimised.run().map(|_| finish(optimised.program()));
+
+    match (interpreted, optimised) {
+        (Ok(interpreted), Ok(optimised)) if interpreted == optimised => Ok(optimised),
+        (interpreted, optimised) => Err(Mismatch {
+            interpreted,
+            optimised,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_with_registers(input_string: &str, registers: &[(Value, Value)]) -> Program {
+        let mut program = Program::parse(input_string, 6).unwrap();
+        for (register, value) in registers {
+            program.registers_mut().set(*register, *value).unwrap();
+        }
+        program
+    }
+
+    #[test]
+    fn test_repeated_addition() {
+        // r3 = 0
+        // do {
+        //     r0 += r1
+        //     r3 += 1
+        // } while r3 <= r2
+        let input_string = r###"
+#ip 4
+seti 0 0 3
+addr 0 1 0
+addi 3 1 3
+gtrr 3 2 5
+addr 5 4 4
+seti 0 0 4
+"###;
+
+        let program = parse_with_registers(input_string, &[(1, 7), (2, 1000)]);
+        assert_eq!(
+            OptimisedProgram::new(program.clone()).hot_loops(),
+            vec![(1, Idiom::RepeatedAddition)]
+        );
+
+        let run = verify(&program).unwrap();
+        assert_eq!(run.registers.get(0), Ok(7 * 1001));
+
+        // the loop body runs once, even though the condition holds from the start
+        let program = parse_with_registers(input_string, &[(1, 7), (2, -5)]);
+        assert_eq!(verify(&program).unwrap().registers.get(0), Ok(7));
+    }
+
+    #[test]
+    fn test_counting() {
+        // loop A of day 21: r3 = r4 / 256
+        let input_string = r###"
+#ip 5
+seti 0 0 3
+addi 3 1 2
+muli 2 256 2
+gtrr 2 4 2
+addr 2 5 5
+addi 5 1 5
+seti 9 0 5
+addi 3 1 3
+seti 0 0 5
+"###;
+
+        let program = parse_with_registers(input_string, &[(4, 0)]);
+        assert_eq!(
+            OptimisedProgram::new(program).hot_loops(),
+            vec![(1, Idiom::Counting)]
+        );
+
+        for value in [0, 255, 256, 100_000] {
+            let program = parse_with_registers(input_string, &[(4, value)]);
+            let run = verify(&program).unwrap();
+            assert_eq!(run.registers.get(3), Ok(value / 256));
+        }
+    }
+
+    #[test]
+    fn test_divisor_sum() {
+        // the loops of day 19, which add up the divisors of r5
+        let input_string = r###"
+#ip 4
+seti 1 5 3
+seti 1 9 1
+mulr 3 1 2
+eqrr 2 5 2
+addr 2 4 4
+addi 4 1 4
+addr 3 0 0
+addi 1 1 1
+gtrr 1 5 2
+addr 4 2 4
+seti 1 9 4
+addi 3 1 3
+gtrr 3 5 2
+addr 2 4 4
+seti 0 8 4
+"###;
+
+        let program = parse_with_registers(input_string, &[(5, 1)]);
+        assert_eq!(
+            OptimisedProgram::new(program).hot_loops(),
+            vec![(1, Idiom::DivisorSum), (2, Idiom::DivisorSum)]
+        );
+
+        for (value, sum_of_divisors) in [(1, 1), (36, 91), (60, 168), (97, 98)] {
+            let program = parse_with_registers(input_string, &[(5, value)]);
+            let run = verify(&program).unwrap();
+            assert_eq!(run.registers.get(0), Ok(sum_of_divisors));
+        }
+
+        // without running the program one instruction at a time
+        let mut program =
+            OptimisedProgram::new(parse_with_registers(input_string, &[(5, 10_551_329)]));
+        program.run().unwrap();
+        assert_eq!(program.program().registers().get(0), Ok(10_628_484));
+    }
+}
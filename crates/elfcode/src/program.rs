@@ -0,0 +1,294 @@
+// imports
+
+use std::fmt;
+
+use aoc_core::{scan_lines, ParseError, Scanner};
+
+use crate::instruction::{Instruction, Opcode, Operand};
+use crate::registers::{Registers, Value};
+
+// code
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    InvalidRegister { register: Value },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidRegister { register } => write!(f, "invalid register {}", register),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Halted,
+    NotHalted,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    // the register that the instruction pointer is bound to, if any (i.e. #ip 4)
+    instruction_pointer_register: Option<Value>,
+    instructions: Vec<Instruction>,
+
+    // current value of the instruction pointer
+    instruction_pointer: Value,
+    registers: Registers,
+    num_of_instructions_executed: u64,
+}
+
+impl Program {
+    pub fn new(
+        num_of_registers: usize,
+        instruction_pointer_register: Option<Value>,
+        instructions: Vec<Instruction>,
+    ) -> Self {
+        Program {
+            instruction_pointer_register,
+            instructions,
+            // The instruction pointer starts at 0.
+            instruction_pointer: 0,
+            registers: Registers::new(num_of_registers),
+            num_of_instructions_executed: 0,
+        }
+    }
+
+    // A program for a device with the given number of registers, e.g.
+    // #ip 0
+    // seti 5 0 1
+    // seti 6 0 2
+    pub fn parse(input_string: &str, num_of_registers: usize) -> Result<Self, ParseError> {
+        let mut instruction_pointer_register = None;
+        let mut instructions = vec![];
+
+        for mut scanner in scan_lines(input_string) {
+            // the binding of the instruction pointer comes before any instruction
+            let is_first_line = instructions.is_empty() && instruction_pointer_register.is_none();
+
+            if is_first_line && scanner.eat("#ip") {
+                scanner.skip_whitespace();
+                instruction_pointer_register =
+                    Some(parse_register(&mut scanner, num_of_registers)?);
+                scanner.end()?;
+                continue;
+            }
+
+            instructions.push(parse_instruction(&mut scanner, num_of_registers)?);
+        }
+
+        Ok(Program::new(
+            num_of_registers,
+            instruction_pointer_register,
+            instructions,
+        ))
+    }
+
+    // the same program, with the instruction pointer and every register back at 0
+    pub fn fork(&self) -> Self {
+        Program::new(
+            self.registers.len(),
+            self.instruction_pointer_register,
+            self.instructions.clone(),
+        )
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn instruction_pointer_register(&self) -> Option<Value> {
+        self.instruction_pointer_register
+    }
+
+    pub fn instruction_pointer(&self) -> Value {
+        self.instruction_pointer
+    }
+
+    pub fn set_instruction_pointer(&mut self, instruction_pointer: Value) {
+        self.instruction_pointer = instruction_pointer;
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut Registers {
+        &mut self.registers
+    }
+
+    pub fn num_of_instructions_executed(&self) -> u64 {
+        self.num_of_instructions_executed
+    }
+
+    // the instruction at the instruction pointer; None if the program would halt
+    pub fn current_instruction(&self) -> Option<&Instruction> {
+        if self.instruction_pointer < 0 {
+            return None;
+        }
+
+        self.instructions.get(self.instruction_pointer as usize)
+    }
+
+    pub fn execute_instruction(&mut self) -> Result<Status, Error> {
+        // If the instruction pointer ever causes the device to attempt to load an instruction
+        // outside the instructions defined in the program, the program instead immediately halts.
+        let instruction = match self.current_instruction() {
+            Some(instruction) => *instruction,
+            None => return Ok(Status::Halted),
+        };
+
+        // write the value of the instruction pointer to the bound register
+        if let Some(register) = self.instruction_pointer_register {
+            self.registers.set(register, self.instruction_pointer)?;
+        }
+
+        instruction.execute(&mut self.registers)?;
+
+        // write the bound register back to the instruction pointer
+        if let Some(register) = self.instruction_pointer_register {
+            self.instruction_pointer = self.registers.get(register)?;
+        }
+
+        // after the instruction has executed, add one to the instruction pointer
+        self.instruction_pointer += 1;
+        self.num_of_instructions_executed += 1;
+
+        Ok(Status::NotHalted)
+    }
+
+    // continue at the instruction pointer, after instructions were run without the interpreter
+    pub(crate) fn jump(&mut self, instruction_pointer: Value, num_of_instructions: u64) {
+        self.instruction_pointer = instruction_pointer;
+        self.num_of_instructions_executed += num_of_instructions;
+    }
+
+    pub fn run(&mut self) -> Result<(), Error> {
+        while self.execute_instruction()? == Status::NotHalted {}
+        Ok(())
+    }
+}
+
+fn parse_register(scanner: &mut Scanner, num_of_registers: usize) -> Result<Value, ParseError> {
+    let start = *scanner;
+    let register: Value = scanner.integer()?;
+
+    if register < 0 || register as usize >= num_of_registers {
+        return Err(start.error(match num_of_registers {
+            0 => "no register, since the device has none".to_string(),
+            _ => format!("a register between 0 and {}", num_of_registers - 1),
+        }));
+    }
+
+    Ok(register)
+}
+
+fn parse_input_value(
+    scanner: &mut Scanner,
+    operand: Operand,
+    num_of_registers: usize,
+) -> Result<Value, ParseError> {
+    match operand {
+        Operand::Register => parse_register(scanner, num_of_registers),
+        Operand::Immediate | Operand::Ignored => scanner.integer(),
+    }
+}
+
+// e.g. addi 4 16 4
+fn parse_instruction(
+    scanner: &mut Scanner,
+    num_of_registers: usize,
+) -> Result<Instruction, ParseError> {
+    let start = *scanner;
+    let name = scanner.word("an opcode")?;
+    let opcode = Opcode::from_name(name).ok_or_else(|| start.error("an opcode"))?;
+    let (operand_a, operand_b) = opcode.operands();
+
+    scanner.skip_whitespace();
+    let a = parse_input_value(scanner, operand_a, num_of_registers)?;
+    scanner.skip_whitespace();
+    let b = parse_input_value(scanner, operand_b, num_of_registers)?;
+    scanner.skip_whitespace();
+    let c = parse_register(scanner, num_of_registers)?;
+    scanner.end()?;
+
+    Ok(Instruction::new(opcode, a, b, c))
+}
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(register) = self.instruction_pointer_register {
+            writeln!(f, "#ip {}", register)?;
+        }
+
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r###"
+#ip 0
+seti 5 0 1
+seti 6 0 2
+addi 0 1 0
+addr 1 2 3
+setr 1 0 0
+seti 8 0 4
+seti 9 0 5
+"###;
+
+    #[test]
+    fn test_run() {
+        let mut program = Program::parse(EXAMPLE, 6).unwrap();
+        assert_eq!(program.instruction_pointer_register(), Some(0));
+        assert_eq!(program.instructions().len(), 7);
+
+        program.run().unwrap();
+        assert_eq!(program.registers().values(), &[6, 5, 6, 0, 0, 9]);
+        assert_eq!(program.num_of_instructions_executed(), 5);
+
+        let fork = program.fork();
+        assert_eq!(fork.registers().values(), &[0; 6]);
+        assert_eq!(fork.instruction_pointer(), 0);
+        assert_eq!(fork.to_string(), EXAMPLE.trim_start());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Program::parse("#ip 0\nseti 5 0 1\nnope 1 2 3", 6).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected an opcode, found \"nope\""
+        );
+
+        let error = Program::parse("addr 1 6 3", 6).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 8: expected a register between 0 and 5, found \"6\""
+        );
+
+        // input B of seti is ignored, so any value is fine
+        assert!(Program::parse("seti 5 100 1", 6).is_ok());
+
+        // the registers are those of the device that the program is for
+        let error = Program::parse("#ip 4\nseti 5 0 1", 4).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 5: expected a register between 0 and 3, found \"4\""
+        );
+        let program = Program::parse("#ip 3\nseti 5 0 1", 4).unwrap();
+        assert_eq!(program.registers().len(), 4);
+        assert_eq!(program.fork().registers().len(), 4);
+    }
+}
@@ -0,0 +1,77 @@
+// imports
+
+use crate::program::Error;
+
+// code
+
+pub type Value = i64;
+
+// The register file of the device. Every register starts with the value 0.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Registers(Vec<Value>);
+
+impl Registers {
+    pub fn new(num_of_registers: usize) -> Self {
+        Registers(vec![0; num_of_registers])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn values(&self) -> &[Value] {
+        &self.0
+    }
+
+    fn index(&self, register: Value) -> Result<usize, Error> {
+        if register < 0 || register as usize >= self.0.len() {
+            return Err(Error::InvalidRegister { register });
+        }
+
+        Ok(register as usize)
+    }
+
+    pub fn get(&self, register: Value) -> Result<Value, Error> {
+        Ok(self.0[self.index(register)?])
+    }
+
+    pub fn set(&mut self, register: Value, value: Value) -> Result<(), Error> {
+        let index = self.index(register)?;
+        self.0[index] = value;
+        Ok(())
+    }
+}
+
+impl From<Vec<Value>> for Registers {
+    fn from(values: Vec<Value>) -> Self {
+        Registers(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registers() {
+        let mut registers = Registers::new(4);
+        assert_eq!(registers.values(), &[0, 0, 0, 0]);
+
+        registers.set(3, 7).unwrap();
+        assert_eq!(registers.get(3), Ok(7));
+        assert_eq!(registers, Registers::from(vec![0, 0, 0, 7]));
+
+        assert_eq!(
+            registers.get(4),
+            Err(Error::InvalidRegister { register: 4 })
+        );
+        assert_eq!(
+            registers.set(-1, 0),
+            Err(Error::InvalidRegister { register: -1 })
+        );
+    }
+}
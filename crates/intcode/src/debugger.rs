@@ -0,0 +1,428 @@
+// imports
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::disassemble::format_instruction;
+use crate::instruction::{Instruction, Opcode};
+use crate::machine::{Error, Machine, State, Value};
+
+// code
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    // stop before the instruction at this address runs
+    Address(usize),
+    // stop before any instruction with this opcode runs
+    Opcode(Opcode),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::Address(address) => write!(f, "address {}", address),
+            Breakpoint::Opcode(opcode) => write!(f, "opcode {}", opcode),
+        }
+    }
+}
+
+// Why the debugger handed control back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    // the instruction pointer is at an instruction that matches the breakpoint; it has not run yet
+    Breakpoint(Breakpoint),
+    // a watched memory cell was changed by the instruction that just ran
+    Watchpoint {
+        address: usize,
+        old: Value,
+        new: Value,
+    },
+    // the machine halted, is waiting for input, or produced output
+    State(State),
+}
+
+// An instruction that was executed, along with what it wrote to memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    // starts at 1 for the first instruction that was executed
+    pub step: usize,
+    pub address: usize,
+    pub instruction: Instruction,
+    pub parameters: Vec<Value>,
+    // the relative base before the instruction ran
+    pub relative_base: Value,
+    // the address that was written to, and the value that was written
+    pub write: Option<(usize, Value)>,
+}
+
+// e.g.
+//      3     4: mul [4], #3 -> [4]       rb=0 [4]=99
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:>6} {:>5}: {:<24} rb={}",
+            self.step,
+            self.address,
+            format_instruction(&self.instruction, &self.parameters),
+            self.relative_base
+        )?;
+
+        if let Some((address, value)) = self.write {
+            write!(f, " [{}]={}", address, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Runs a machine one instruction at a time, stopping at breakpoints and watchpoints, and
+// optionally recording a trace of every instruction that was executed.
+#[derive(Debug, Clone)]
+pub struct Debugger {
+    machine: Machine,
+    // in the order they were added
+    breakpoints: Vec<Breakpoint>,
+    watchpoints: BTreeSet<usize>,
+    tracing: bool,
+    trace: Vec<TraceEntry>,
+    // the number of instructions that were executed
+    steps: usize,
+    // the instruction pointer when control was last handed back, and why
+    last_stop: Option<(usize, Stop)>,
+}
+
+impl Debugger {
+    pub fn new(machine: Machine) -> Self {
+        Debugger {
+            machine,
+            breakpoints: vec![],
+            watchpoints: BTreeSet::new(),
+            tracing: false,
+            trace: vec![],
+            steps: 0,
+            last_stop: None,
+        }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    // e.g. to give the machine more input, or to patch its memory
+    pub fn machine_mut(&mut self) -> &mut Machine {
+        &mut self.machine
+    }
+
+    pub fn into_machine(self) -> Machine {
+        self.machine
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    // None if the last instruction that was stepped over did not stop the debugger
+    pub fn last_stop(&self) -> Option<Stop> {
+        self.last_stop.map(|(_, stop)| stop)
+    }
+
+    // false if the breakpoint was already set
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        if self.breakpoints.contains(&breakpoint) {
+            return false;
+        }
+
+        self.breakpoints.push(breakpoint);
+        true
+    }
+
+    // false if the breakpoint was not set
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) -> bool {
+        let num_of_breakpoints = self.breakpoints.len();
+        self.breakpoints.retain(|existing| *existing != breakpoint);
+        self.breakpoints.len() != num_of_breakpoints
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    // false if the address was already watched
+    pub fn watch(&mut self, address: usize) -> bool {
+        self.watchpoints.insert(address)
+    }
+
+    // false if the address was not watched
+    pub fn unwatch(&mut self, address: usize) -> bool {
+        self.watchpoints.remove(&address)
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.watchpoints.iter().copied()
+    }
+
+    pub fn set_tracing(&mut self, tracing: bool) {
+        self.tracing = tracing;
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracing
+    }
+
+    // the instructions that were executed while tracing was enabled, oldest first
+    pub fn trace(&self) -> &[TraceEntry] {
+        &self.trace
+    }
+
+    pub fn take_trace(&mut self) -> Vec<TraceEntry> {
+        std::mem::take(&mut self.trace)
+    }
+
+    // the first breakpoint that matches the instruction at the instruction pointer
+    pub fn breakpoint(&self) -> Option<Breakpoint> {
+        let address = self.machine.instruction_pointer();
+        let opcode = self
+            .machine
+            .current_instruction()
+            .ok()
+            .map(|instruction| instruction.opcode);
+
+        self.breakpoints
+            .iter()
+            .copied()
+            .find(|breakpoint| match breakpoint {
+                Breakpoint::Address(breakpoint_address) => *breakpoint_address == address,
+                Breakpoint::Opcode(breakpoint_opcode) => Some(*breakpoint_opcode) == opcode,
+            })
+    }
+
+    // Execute a single instruction, regardless of any breakpoint at the instruction pointer.
+    // An input instruction without any input does not count as a step; the machine stays put.
+    pub fn step(&mut self) -> Result<Option<Stop>, Error> {
+        let stop = self.step_instruction()?;
+        self.record_stop(stop);
+        Ok(stop)
+    }
+
+    fn record_stop(&mut self, stop: Option<Stop>) {
+        self.last_stop = stop.map(|stop| (self.machine.instruction_pointer(), stop));
+    }
+
+    fn step_instruction(&mut self) -> Result<Option<Stop>, Error> {
+        let address = self.machine.instruction_pointer();
+        let relative_base = self.machine.relative_base();
+        let instruction = self.machine.current_instruction()?;
+        let parameters = self.machine.current_parameters()?;
+        let write_address = self.machine.write_address()?;
+        let old = write_address.map(|address| self.machine.read(address));
+
+        let state = self.machine.step()?;
+
+        if state == Some(State::NeedsInput) {
+            return Ok(Some(Stop::State(State::NeedsInput)));
+        }
+
+        self.steps += 1;
+
+        let write = write_address.map(|address| (address, self.machine.read(address)));
+
+        if self.tracing {
+            self.trace.push(TraceEntry {
+                step: self.steps,
+                address,
+                instruction,
+                parameters,
+                relative_base,
+                write,
+            });
+        }
+
+        if let Some(state) = state {
+            return Ok(Some(Stop::State(state)));
+        }
+
+        if let (Some((address, new)), Some(old)) = (write, old) {
+            if old != new && self.watchpoints.contains(&address) {
+                return Ok(Some(Stop::Watchpoint { address, old, new }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    // Run until a breakpoint or a watchpoint is hit, or until the machine halts, needs input, or
+    // produces output. A breakpoint at the instruction pointer is only stepped over if it is the
+    // breakpoint that was last hit, so that resuming after a breakpoint makes progress.
+    pub fn resume(&mut self) -> Result<Stop, Error> {
+        let address = self.machine.instruction_pointer();
+
+        if let Some((stop_address, Stop::Breakpoint(_))) = self.last_stop {
+            if stop_address == address {
+                if let Some(stop) = self.step()? {
+                    return Ok(stop);
+                }
+            }
+        }
+
+        loop {
+            if let Some(breakpoint) = self.breakpoint() {
+                let stop = Stop::Breakpoint(breakpoint);
+                self.record_stop(Some(stop));
+                return Ok(stop);
+            }
+
+            if let Some(stop) = self.step()? {
+                return Ok(stop);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // outputs 999 if the input is below 8, 1000 if it is equal to 8, or 1001 if it is greater
+    const COMPARE_TO_8: &str = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+                                1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+                                999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+
+    fn debugger(program: &str) -> Debugger {
+        Debugger::new(Machine::parse(program).unwrap())
+    }
+
+    #[test]
+    fn test_step() {
+        let mut debugger = debugger("1002,4,3,4,33");
+        debugger.set_tracing(true);
+
+        assert_eq!(debugger.step(), Ok(None));
+        assert_eq!(debugger.step(), Ok(Some(Stop::State(State::Halted))));
+        assert_eq!(debugger.steps(), 2);
+
+        let trace: Vec<String> = debugger
+            .trace()
+            .iter()
+            .map(|entry| entry.to_string())
+            .collect();
+        assert_eq!(
+            trace,
+            vec![
+                "     1     0: mul [4], #3 -> [4]       rb=0 [4]=99",
+                "     2     4: halt                     rb=0"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_needs_input() {
+        let mut debugger = debugger("3,0,4,0,99");
+
+        assert_eq!(debugger.resume(), Ok(Stop::State(State::NeedsInput)));
+        assert_eq!(debugger.steps(), 0);
+        assert_eq!(debugger.machine().instruction_pointer(), 0);
+
+        debugger.machine_mut().push_input(42);
+        assert_eq!(debugger.resume(), Ok(Stop::State(State::Output(42))));
+        assert_eq!(debugger.resume(), Ok(Stop::State(State::Halted)));
+        assert_eq!(debugger.resume(), Err(Error::Halted));
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut debugger = debugger(COMPARE_TO_8);
+        debugger.machine_mut().push_input(9);
+
+        assert!(debugger.add_breakpoint(Breakpoint::Address(9)));
+        assert!(!debugger.add_breakpoint(Breakpoint::Address(9)));
+        assert!(debugger.add_breakpoint(Breakpoint::Opcode(Opcode::Output)));
+
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Breakpoint(Breakpoint::Address(9)))
+        );
+        assert_eq!(debugger.machine().instruction_pointer(), 9);
+
+        // resuming steps over the breakpoint that was hit
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Breakpoint(Breakpoint::Opcode(Opcode::Output)))
+        );
+        assert_eq!(debugger.resume(), Ok(Stop::State(State::Output(1001))));
+
+        assert!(debugger.remove_breakpoint(Breakpoint::Address(9)));
+        assert!(!debugger.remove_breakpoint(Breakpoint::Address(9)));
+        assert_eq!(
+            debugger.breakpoints(),
+            &[Breakpoint::Opcode(Opcode::Output)]
+        );
+    }
+
+    #[test]
+    fn test_breakpoint_at_start() {
+        let mut debugger = debugger("3,0,4,0,99");
+        debugger.machine_mut().push_input(42);
+        assert!(debugger.add_breakpoint(Breakpoint::Address(0)));
+        assert!(debugger.add_breakpoint(Breakpoint::Address(4)));
+
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Breakpoint(Breakpoint::Address(0)))
+        );
+        assert_eq!(debugger.steps(), 0);
+        assert_eq!(
+            debugger.last_stop(),
+            Some(Stop::Breakpoint(Breakpoint::Address(0)))
+        );
+
+        assert_eq!(debugger.resume(), Ok(Stop::State(State::Output(42))));
+
+        // the output stopped the debugger, so the breakpoint at the halt is still hit
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Breakpoint(Breakpoint::Address(4)))
+        );
+        assert_eq!(debugger.resume(), Ok(Stop::State(State::Halted)));
+    }
+
+    #[test]
+    fn test_watchpoints() {
+        let mut debugger = debugger(COMPARE_TO_8);
+        debugger.machine_mut().push_input(9);
+        assert!(debugger.watch(20));
+        assert!(debugger.watch(21));
+
+        // the input is stored at address 21
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watchpoint {
+                address: 21,
+                old: 0,
+                new: 9
+            })
+        );
+
+        // 9 == 8 writes 0 to address 20, which does not change it; 8 < 9 does
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Watchpoint {
+                address: 20,
+                old: 0,
+                new: 1
+            })
+        );
+        assert_eq!(debugger.machine().instruction_pointer(), 13);
+
+        // a breakpoint at the instruction pointer is not skipped after a watchpoint
+        assert!(debugger.add_breakpoint(Breakpoint::Address(13)));
+        assert_eq!(
+            debugger.resume(),
+            Ok(Stop::Breakpoint(Breakpoint::Address(13)))
+        );
+        assert_eq!(debugger.machine().instruction_pointer(), 13);
+
+        assert!(debugger.unwatch(20));
+        assert_eq!(debugger.watchpoints().collect::<Vec<_>>(), vec![21]);
+    }
+}
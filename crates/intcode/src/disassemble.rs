@@ -0,0 +1,220 @@
+// imports
+
+use std::fmt;
+
+use crate::instruction::{Instruction, Mode};
+use crate::machine::Value;
+
+// code
+
+// e.g. [12] for position mode, #5 for immediate mode, and [rb+3] for relative mode
+pub fn format_parameter(mode: Mode, parameter: Value) -> String {
+    match mode {
+        Mode::Position => format!("[{}]", parameter),
+        Mode::Immediate => format!("#{}", parameter),
+        Mode::Relative => format!("[rb{:+}]", parameter),
+    }
+}
+
+// An instruction along with its parameters, e.g. add [12], #5 -> [3]
+// The parameter that is written to (if any) comes last, after the arrow.
+pub fn format_instruction(instruction: &Instruction, parameters: &[Value]) -> String {
+    let mut formatted: Vec<String> = parameters
+        .iter()
+        .zip(instruction.modes.iter())
+        .map(|(parameter, mode)| format_parameter(*mode, *parameter))
+        .collect();
+
+    let written = instruction
+        .opcode
+        .written_parameter()
+        .and_then(|_| formatted.pop());
+
+    let mut line = instruction.opcode.mnemonic().to_string();
+
+    if !formatted.is_empty() {
+        line.push(' ');
+        line.push_str(&formatted.join(", "));
+    }
+
+    if let Some(written) = written {
+        line.push_str(" -> ");
+        line.push_str(&written);
+    }
+
+    line
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Disassembled {
+    Instruction {
+        instruction: Instruction,
+        parameters: Vec<Value>,
+    },
+    // a value that does not decode to an instruction, or an instruction that is cut short by the
+    // end of the program
+    Data(Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    pub address: usize,
+    pub disassembled: Disassembled,
+}
+
+impl Line {
+    // the values of the program that make up this line
+    pub fn raw(&self) -> Vec<Value> {
+        match &self.disassembled {
+            Disassembled::Instruction {
+                instruction,
+                parameters,
+            } => {
+                let mut raw = vec![encode(instruction)];
+                raw.extend(parameters);
+                raw
+            }
+            Disassembled::Data(value) => vec![*value],
+        }
+    }
+}
+
+fn encode(instruction: &Instruction) -> Value {
+    let modes = instruction
+        .modes
+        .iter()
+        .rev()
+        .fold(0, |modes, mode| modes * 10 + mode_digit(*mode));
+
+    modes * 100 + instruction.opcode.value()
+}
+
+fn mode_digit(mode: Mode) -> Value {
+    match mode {
+        Mode::Position => 0,
+        Mode::Immediate => 1,
+        Mode::Relative => 2,
+    }
+}
+
+// e.g.
+//    4: 1002,4,3,4         mul [4], #3 -> [4]
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let raw: Vec<String> = self.raw().iter().map(|value| value.to_string()).collect();
+
+        let text = match &self.disassembled {
+            Disassembled::Instruction {
+                instruction,
+                parameters,
+            } => format_instruction(instruction, parameters),
+            Disassembled::Data(value) => format!("data {}", value),
+        };
+
+        write!(f, "{:>5}: {:<24} {}", self.address, raw.join(","), text)
+    }
+}
+
+// Decode the program from the given address, one instruction after another.
+// Since code and data share the same memory, values that are not instructions are shown as data;
+// self-modifying programs may still be decoded differently than they are run.
+pub fn disassemble_from(program: &[Value], mut address: usize) -> impl Iterator<Item = Line> + '_ {
+    std::iter::from_fn(move || {
+        if address >= program.len() {
+            return None;
+        }
+
+        let instruction = Instruction::decode(program[address])
+            .filter(|instruction| address + instruction.length() <= program.len());
+
+        let disassembled = match instruction {
+            Some(instruction) => Disassembled::Instruction {
+                instruction,
+                parameters: program[address + 1..address + instruction.length()].to_vec(),
+            },
+            None => Disassembled::Data(program[address]),
+        };
+
+        let line = Line {
+            address,
+            disassembled,
+        };
+
+        address += line.raw().len();
+        Some(line)
+    })
+}
+
+pub fn disassemble(program: &[Value]) -> Vec<Line> {
+    disassemble_from(program, 0).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machine::parse_program;
+
+    fn listing(program: &str) -> Vec<String> {
+        disassemble(&parse_program(program).unwrap())
+            .iter()
+            .map(|line| line.to_string().split_off(32))
+            .collect()
+    }
+
+    #[test]
+    fn test_format_instruction() {
+        let instruction = Instruction::decode(1001).unwrap();
+        assert_eq!(
+            format_instruction(&instruction, &[12, 5, 3]),
+            "add [12], #5 -> [3]"
+        );
+
+        let instruction = Instruction::decode(204).unwrap();
+        assert_eq!(format_instruction(&instruction, &[-1]), "out [rb-1]");
+
+        let instruction = Instruction::decode(99).unwrap();
+        assert_eq!(format_instruction(&instruction, &[]), "halt");
+    }
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            listing("1,9,10,3,2,3,11,0,99,30,40,50"),
+            vec![
+                "add [9], [10] -> [3]",
+                "mul [3], [11] -> [0]",
+                "halt",
+                "data 30",
+                "data 40",
+                "data 50"
+            ]
+        );
+
+        assert_eq!(
+            listing("3,9,8,9,10,9,4,9,99,-1,8"),
+            vec![
+                "in -> [9]",
+                "eq [9], [10] -> [9]",
+                "out [9]",
+                "halt",
+                "data -1",
+                "data 8"
+            ]
+        );
+
+        // an instruction that is cut short by the end of the program
+        assert_eq!(listing("1105,1"), vec!["data 1105", "data 1"]);
+
+        let program = parse_program("1,9,10,3,2,3,11,0,99,30,40,50").unwrap();
+        let line = disassemble_from(&program, 4).next().unwrap();
+        assert_eq!(line.address, 4);
+        assert_eq!(line.raw(), vec![2, 3, 11, 0]);
+
+        let line = &disassemble(&[1002, 4, 3, 4, 33])[0];
+        assert_eq!(line.raw(), vec![1002, 4, 3, 4]);
+        assert_eq!(
+            line.to_string(),
+            "    0: 1002,4,3,4               mul [4], #3 -> [4]"
+        );
+    }
+}
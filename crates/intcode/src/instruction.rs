@@ -0,0 +1,208 @@
+// imports
+
+use std::fmt;
+
+// code
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    // the parameter is the address of the value
+    Position,
+    // the parameter is the value itself
+    Immediate,
+    // the parameter is an offset from the relative base to the address of the value
+    Relative,
+}
+
+impl Mode {
+    fn from_digit(digit: i64) -> Option<Self> {
+        match digit {
+            0 => Some(Mode::Position),
+            1 => Some(Mode::Immediate),
+            2 => Some(Mode::Relative),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Opcode {
+    Add,
+    Multiply,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustRelativeBase,
+    Halt,
+}
+
+impl Opcode {
+    pub const ALL: [Opcode; 10] = [
+        Opcode::Add,
+        Opcode::Multiply,
+        Opcode::Input,
+        Opcode::Output,
+        Opcode::JumpIfTrue,
+        Opcode::JumpIfFalse,
+        Opcode::LessThan,
+        Opcode::Equals,
+        Opcode::AdjustRelativeBase,
+        Opcode::Halt,
+    ];
+
+    pub fn from_value(value: i64) -> Option<Self> {
+        let opcode = match value {
+            1 => Opcode::Add,
+            2 => Opcode::Multiply,
+            3 => Opcode::Input,
+            4 => Opcode::Output,
+            5 => Opcode::JumpIfTrue,
+            6 => Opcode::JumpIfFalse,
+            7 => Opcode::LessThan,
+            8 => Opcode::Equals,
+            9 => Opcode::AdjustRelativeBase,
+            99 => Opcode::Halt,
+            _ => return None,
+        };
+
+        Some(opcode)
+    }
+
+    pub fn value(&self) -> i64 {
+        match self {
+            Opcode::Add => 1,
+            Opcode::Multiply => 2,
+            Opcode::Input => 3,
+            Opcode::Output => 4,
+            Opcode::JumpIfTrue => 5,
+            Opcode::JumpIfFalse => 6,
+            Opcode::LessThan => 7,
+            Opcode::Equals => 8,
+            Opcode::AdjustRelativeBase => 9,
+            Opcode::Halt => 99,
+        }
+    }
+
+    pub fn num_of_parameters(&self) -> usize {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => 3,
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => 2,
+            Opcode::Input | Opcode::Output | Opcode::AdjustRelativeBase => 1,
+            Opcode::Halt => 0,
+        }
+    }
+
+    // the parameter (starting at 0) that is the address the instruction writes to, if any
+    pub fn written_parameter(&self) -> Option<usize> {
+        match self {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => Some(2),
+            Opcode::Input => Some(0),
+            _ => None,
+        }
+    }
+
+    pub fn from_mnemonic(mnemonic: &str) -> Option<Self> {
+        Opcode::ALL
+            .into_iter()
+            .find(|opcode| opcode.mnemonic() == mnemonic)
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Add => "add",
+            Opcode::Multiply => "mul",
+            Opcode::Input => "in",
+            Opcode::Output => "out",
+            Opcode::JumpIfTrue => "jnz",
+            Opcode::JumpIfFalse => "jz",
+            Opcode::LessThan => "lt",
+            Opcode::Equals => "eq",
+            Opcode::AdjustRelativeBase => "arb",
+            Opcode::Halt => "halt",
+        }
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+// An opcode along with the modes of its parameters.
+// The value 1002 is decoded as: multiply, with the parameter modes (position, immediate, position).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    // None if the value is not a valid instruction
+    pub fn decode(value: i64) -> Option<Self> {
+        if value < 0 {
+            return None;
+        }
+
+        let opcode = Opcode::from_value(value % 100)?;
+
+        let mut modes = [Mode::Position; 3];
+        let mut digits = value / 100;
+        for mode in modes.iter_mut() {
+            *mode = Mode::from_digit(digits % 10)?;
+            digits /= 10;
+        }
+
+        // there are no more than 3 parameters
+        if digits != 0 {
+            return None;
+        }
+
+        Some(Instruction { opcode, modes })
+    }
+
+    pub fn length(&self) -> usize {
+        1 + self.opcode.num_of_parameters()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(
+            Instruction::decode(1002),
+            Some(Instruction {
+                opcode: Opcode::Multiply,
+                modes: [Mode::Position, Mode::Immediate, Mode::Position]
+            })
+        );
+        assert_eq!(
+            Instruction::decode(21101).map(|instruction| instruction.modes),
+            Some([Mode::Immediate, Mode::Immediate, Mode::Relative])
+        );
+        assert_eq!(Instruction::decode(99).unwrap().length(), 1);
+
+        assert_eq!(Instruction::decode(42), None);
+        assert_eq!(Instruction::decode(301), None);
+        assert_eq!(Instruction::decode(100001), None);
+        assert_eq!(Instruction::decode(-1), None);
+    }
+
+    #[test]
+    fn test_opcodes() {
+        for value in [1, 2, 3, 4, 5, 6, 7, 8, 9, 99] {
+            assert_eq!(Opcode::from_value(value).unwrap().value(), value);
+        }
+
+        for opcode in Opcode::ALL {
+            assert_eq!(Opcode::from_mnemonic(opcode.mnemonic()), Some(opcode));
+        }
+        assert_eq!(Opcode::from_mnemonic("nop"), None);
+    }
+}
@@ -0,0 +1,16 @@
+// Intcode virtual machine shared by the 2019 puzzles.
+// https://adventofcode.com/2019/day/2
+// https://adventofcode.com/2019/day/5
+// https://adventofcode.com/2019/day/9
+
+mod debugger;
+mod disassemble;
+mod instruction;
+mod machine;
+
+pub use debugger::{Breakpoint, Debugger, Stop, TraceEntry};
+pub use disassemble::{
+    disassemble, disassemble_from, format_instruction, format_parameter, Disassembled, Line,
+};
+pub use instruction::{Instruction, Mode, Opcode};
+pub use machine::{parse_program, Error, Machine, State, Value};
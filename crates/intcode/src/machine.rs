@@ -0,0 +1,414 @@
+// imports
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use aoc_core::{scan_lines, ParseError};
+
+use crate::instruction::{Instruction, Mode, Opcode};
+
+// code
+
+pub type Value = i64;
+
+// e.g. 1,9,10,3,2,3,11,0,99,30,40,50
+pub fn parse_program(input_string: &str) -> Result<Vec<Value>, ParseError> {
+    let mut program = vec![];
+
+    for mut scanner in scan_lines(input_string) {
+        loop {
+            program.push(scanner.integer()?);
+            scanner.skip_whitespace();
+
+            if scanner.is_at_end() {
+                break;
+            }
+
+            scanner.literal(",")?;
+            scanner.skip_whitespace();
+        }
+    }
+
+    Ok(program)
+}
+
+// Where the machine stopped after running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Halted,
+    // the machine is paused at an input instruction until more input is given
+    NeedsInput,
+    Output(Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    InvalidInstruction { address: usize, value: Value },
+    NegativeAddress { address: usize, target: Value },
+    ImmediateWrite { address: usize },
+    // the machine cannot be resumed once it has halted
+    Halted,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InvalidInstruction { address, value } => {
+                write!(f, "invalid instruction {} at address {}", value, address)
+            }
+            Error::NegativeAddress { address, target } => write!(
+                f,
+                "instruction at address {} accesses negative address {}",
+                address, target
+            ),
+            Error::ImmediateWrite { address } => write!(
+                f,
+                "instruction at address {} writes to an immediate mode parameter",
+                address
+            ),
+            Error::Halted => write!(f, "the machine has halted"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    // grows on demand; addresses beyond the end of the memory read as 0
+    memory: Vec<Value>,
+    instruction_pointer: usize,
+    relative_base: Value,
+    input: VecDeque<Value>,
+    output: VecDeque<Value>,
+    halted: bool,
+}
+
+impl Machine {
+    pub fn new(program: Vec<Value>) -> Self {
+        Machine {
+            memory: program,
+            instruction_pointer: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+            halted: false,
+        }
+    }
+
+    pub fn parse(input_string: &str) -> Result<Self, ParseError> {
+        Ok(Machine::new(parse_program(input_string)?))
+    }
+
+    pub fn memory(&self) -> &[Value] {
+        &self.memory
+    }
+
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
+    }
+
+    pub fn relative_base(&self) -> Value {
+        self.relative_base
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn read(&self, address: usize) -> Value {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    pub fn write(&mut self, address: usize, value: Value) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+
+        self.memory[address] = value;
+    }
+
+    pub fn push_input(&mut self, value: Value) {
+        self.input.push_back(value);
+    }
+
+    pub fn extend_input<I: IntoIterator<Item = Value>>(&mut self, values: I) {
+        self.input.extend(values);
+    }
+
+    pub fn pop_output(&mut self) -> Option<Value> {
+        self.output.pop_front()
+    }
+
+    // all of the output that has been collected by run, in the order it was produced
+    pub fn take_output(&mut self) -> Vec<Value> {
+        self.output.drain(..).collect()
+    }
+
+    // the instruction at the instruction pointer
+    pub fn current_instruction(&self) -> Result<Instruction, Error> {
+        let value = self.read(self.instruction_pointer);
+
+        Instruction::decode(value).ok_or(Error::InvalidInstruction {
+            address: self.instruction_pointer,
+            value,
+        })
+    }
+
+    // the parameters of the instruction at the instruction pointer, as they are in memory
+    pub fn current_parameters(&self) -> Result<Vec<Value>, Error> {
+        let instruction = self.current_instruction()?;
+
+        Ok((1..instruction.length())
+            .map(|offset| self.read(self.instruction_pointer + offset))
+            .collect())
+    }
+
+    // the address that the instruction at the instruction pointer writes to, if it writes to memory
+    pub fn write_address(&self) -> Result<Option<usize>, Error> {
+        let instruction = self.current_instruction()?;
+
+        match instruction.opcode.written_parameter() {
+            Some(nth) => Ok(Some(self.parameter_address(&instruction, nth)?)),
+            None => Ok(None),
+        }
+    }
+
+    fn to_address(&self, target: Value) -> Result<usize, Error> {
+        if target < 0 {
+            return Err(Error::NegativeAddress {
+                address: self.instruction_pointer,
+                target,
+            });
+        }
+
+        Ok(target as usize)
+    }
+
+    // the address that the nth parameter (starting at 0) of the current instruction refers to
+    fn parameter_address(&self, instruction: &Instruction, nth: usize) -> Result<usize, Error> {
+        let parameter = self.read(self.instruction_pointer + 1 + nth);
+
+        match instruction.modes[nth] {
+            Mode::Position => self.to_address(parameter),
+            Mode::Relative => self.to_address(self.relative_base + parameter),
+            Mode::Immediate => Err(Error::ImmediateWrite {
+                address: self.instruction_pointer,
+            }),
+        }
+    }
+
+    fn read_parameter(&self, instruction: &Instruction, nth: usize) -> Result<Value, Error> {
+        match instruction.modes[nth] {
+            Mode::Immediate => Ok(self.read(self.instruction_pointer + 1 + nth)),
+            Mode::Position | Mode::Relative => {
+                Ok(self.read(self.parameter_address(instruction, nth)?))
+            }
+        }
+    }
+
+    fn write_parameter(
+        &mut self,
+        instruction: &Instruction,
+        nth: usize,
+        value: Value,
+    ) -> Result<(), Error> {
+        let address = self.parameter_address(instruction, nth)?;
+        self.write(address, value);
+        Ok(())
+    }
+
+    // Execute a single instruction.
+    // Returns the state of the machine if the instruction halted it, paused it for input, or
+    // produced output.
+    pub fn step(&mut self) -> Result<Option<State>, Error> {
+        if self.halted {
+            return Err(Error::Halted);
+        }
+
+        let instruction = self.current_instruction()?;
+        let next_instruction_pointer = self.instruction_pointer + instruction.length();
+
+        match instruction.opcode {
+            Opcode::Add | Opcode::Multiply | Opcode::LessThan | Opcode::Equals => {
+                let left = self.read_parameter(&instruction, 0)?;
+                let right = self.read_parameter(&instruction, 1)?;
+
+                let result = match instruction.opcode {
+                    Opcode::Add => left + right,
+                    Opcode::Multiply => left * right,
+                    Opcode::LessThan => (left < right) as Value,
+                    Opcode::Equals => (left == right) as Value,
+                    _ => unreachable!(),
+                };
+
+                self.write_parameter(&instruction, 2, result)?;
+            }
+            Opcode::Input => match self.input.pop_front() {
+                Some(value) => self.write_parameter(&instruction, 0, value)?,
+                None => return Ok(Some(State::NeedsInput)),
+            },
+            Opcode::Output => {
+                let value = self.read_parameter(&instruction, 0)?;
+                self.instruction_pointer = next_instruction_pointer;
+                return Ok(Some(State::Output(value)));
+            }
+            Opcode::JumpIfTrue | Opcode::JumpIfFalse => {
+                let condition = self.read_parameter(&instruction, 0)? != 0;
+
+                if condition == (instruction.opcode == Opcode::JumpIfTrue) {
+                    let target = self.read_parameter(&instruction, 1)?;
+                    self.instruction_pointer = self.to_address(target)?;
+                    return Ok(None);
+                }
+            }
+            Opcode::AdjustRelativeBase => {
+                self.relative_base += self.read_parameter(&instruction, 0)?;
+            }
+            Opcode::Halt => {
+                self.halted = true;
+                return Ok(Some(State::Halted));
+            }
+        }
+
+        self.instruction_pointer = next_instruction_pointer;
+        Ok(None)
+    }
+
+    // Run until the machine halts, needs more input, or produces a value.
+    pub fn run_until_io(&mut self) -> Result<State, Error> {
+        loop {
+            if let Some(state) = self.step()? {
+                return Ok(state);
+            }
+        }
+    }
+
+    // Run until the machine halts or needs more input. Output is collected; see take_output.
+    pub fn run(&mut self) -> Result<State, Error> {
+        loop {
+            match self.run_until_io()? {
+                State::Output(value) => self.output.push_back(value),
+                state => return Ok(state),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_program(program: &str, input: &[Value]) -> Vec<Value> {
+        let mut machine = Machine::parse(program).unwrap();
+        machine.extend_input(input.iter().copied());
+        assert_eq!(machine.run(), Ok(State::Halted));
+        machine.take_output()
+    }
+
+    #[test]
+    fn test_parse_program() {
+        assert_eq!(parse_program("1,0,0,0,99\n"), Ok(vec![1, 0, 0, 0, 99]));
+        assert_eq!(parse_program("3, -1, 99"), Ok(vec![3, -1, 99]));
+
+        let error = parse_program("1,0,x").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+
+    #[test]
+    fn test_memory() {
+        let mut machine = Machine::parse("1,9,10,3,2,3,11,0,99,30,40,50").unwrap();
+        assert_eq!(machine.run(), Ok(State::Halted));
+        assert_eq!(
+            machine.memory(),
+            &[3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]
+        );
+
+        // memory beyond the program grows on demand
+        assert_eq!(machine.read(1000), 0);
+        machine.write(1000, 7);
+        assert_eq!(machine.read(1000), 7);
+        assert_eq!(machine.memory().len(), 1001);
+
+        assert_eq!(machine.step(), Err(Error::Halted));
+    }
+
+    #[test]
+    fn test_comparisons() {
+        // is the input equal to 8, using position mode
+        let program = "3,9,8,9,10,9,4,9,99,-1,8";
+        assert_eq!(run_program(program, &[8]), vec![1]);
+        assert_eq!(run_program(program, &[7]), vec![0]);
+
+        // is the input less than 8, using immediate mode
+        let program = "3,3,1107,-1,8,3,4,3,99";
+        assert_eq!(run_program(program, &[7]), vec![1]);
+        assert_eq!(run_program(program, &[9]), vec![0]);
+    }
+
+    #[test]
+    fn test_jumps() {
+        // is the input non-zero
+        for program in [
+            "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9",
+            "3,3,1105,-1,9,1101,0,0,12,4,12,99,1",
+        ] {
+            assert_eq!(run_program(program, &[0]), vec![0]);
+            assert_eq!(run_program(program, &[5]), vec![1]);
+        }
+    }
+
+    #[test]
+    fn test_relative_mode() {
+        // a quine
+        let program = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected: Vec<Value> = parse_program(program).unwrap();
+        assert_eq!(run_program(program, &[]), expected);
+
+        assert_eq!(
+            run_program("1102,34915192,34915192,7,4,7,99,0", &[]),
+            vec![1219070632396864]
+        );
+        assert_eq!(
+            run_program("104,1125899906842624,99", &[]),
+            vec![1125899906842624]
+        );
+    }
+
+    #[test]
+    fn test_run_until_io() {
+        // echo the input twice
+        let mut machine = Machine::parse("3,0,4,0,4,0,99").unwrap();
+        assert_eq!(machine.run_until_io(), Ok(State::NeedsInput));
+
+        machine.push_input(42);
+        assert_eq!(machine.run_until_io(), Ok(State::Output(42)));
+        assert_eq!(machine.run_until_io(), Ok(State::Output(42)));
+        assert_eq!(machine.run_until_io(), Ok(State::Halted));
+        assert!(machine.is_halted());
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut machine = Machine::parse("1,0,0,0,42").unwrap();
+        assert_eq!(
+            machine.run(),
+            Err(Error::InvalidInstruction {
+                address: 4,
+                value: 42
+            })
+        );
+
+        let mut machine = Machine::parse("10001,0,0,0,99").unwrap();
+        assert_eq!(machine.run(), Err(Error::ImmediateWrite { address: 0 }));
+
+        let mut machine = Machine::parse("4,-1,99").unwrap();
+        assert_eq!(
+            machine.run(),
+            Err(Error::NegativeAddress {
+                address: 0,
+                target: -1
+            })
+        );
+    }
+}
@@ -0,0 +1,445 @@
+// imports
+
+use std::fs;
+use std::io::{self, BufRead, Read, Write};
+use std::process;
+
+use clap::{Parser, Subcommand};
+use intcode::{
+    disassemble, disassemble_from, parse_program, Breakpoint, Debugger, Machine, Opcode, State,
+    Stop, Value,
+};
+
+// code
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "intcode",
+    about = "Disassemble, trace and debug Intcode programs"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    #[command(about = "Print the program as annotated mnemonics")]
+    Disassemble {
+        #[arg(help = "The file with the program, or - to read it from stdin")]
+        program: String,
+    },
+
+    #[command(about = "Run the program and log every instruction that is executed")]
+    Trace {
+        #[arg(help = "The file with the program, or - to read it from stdin")]
+        program: String,
+
+        #[arg(
+            long,
+            value_delimiter = ',',
+            allow_hyphen_values = true,
+            help = "Values to give to the program as input, e.g. --input 1,-2"
+        )]
+        input: Vec<Value>,
+
+        #[arg(long, help = "Stop after executing this many instructions")]
+        limit: Option<usize>,
+    },
+
+    #[command(about = "Step through the program interactively; type help for the commands")]
+    Debug {
+        #[arg(help = "The file with the program")]
+        program: String,
+
+        #[arg(
+            long,
+            value_delimiter = ',',
+            allow_hyphen_values = true,
+            help = "Values to give to the program as input, e.g. --input 1,-2"
+        )]
+        input: Vec<Value>,
+    },
+}
+
+const HELP: &str = "\
+commands:
+  step [n], s [n]             execute the next n instructions (1 by default)
+  continue, c                 run until a breakpoint, a watchpoint, output, input or halt
+  break [address|mnemonic]    stop before the instruction at an address, or before an opcode
+                              (e.g. break 12, break out); lists the breakpoints without arguments
+  delete <address|mnemonic>   remove a breakpoint
+  watch [address]             stop after the memory cell at the address changes;
+                              lists the watchpoints without arguments
+  unwatch <address>           remove a watchpoint
+  input <value>...            give values to the program as input
+  print <address> [count]     show the values in memory from the address
+  disassemble [address] [n]   disassemble n instructions from the address (the instruction
+                              pointer by default)
+  trace on|off|show [n]       start or stop logging executed instructions, or show the last n
+  info                        show the instruction pointer, the relative base and the steps
+  quit, q                     exit the debugger";
+
+fn read_program(path: &str) -> Result<Vec<Value>, String> {
+    let input_string = if path == "-" {
+        let mut input_string = String::new();
+        io::stdin()
+            .read_to_string(&mut input_string)
+            .map_err(|error| format!("unable to read stdin: {}", error))?;
+        input_string
+    } else {
+        fs::read_to_string(path).map_err(|error| format!("unable to read {}: {}", path, error))?
+    };
+
+    parse_program(&input_string).map_err(|error| format!("{}\n{}", error, error.snippet()))
+}
+
+fn parse_address(argument: &str) -> Result<usize, String> {
+    argument
+        .parse()
+        .map_err(|_| format!("expected an address, found {:?}", argument))
+}
+
+fn parse_count(argument: Option<&str>, default: usize) -> Result<usize, String> {
+    match argument {
+        Some(argument) => argument
+            .parse()
+            .map_err(|_| format!("expected a count, found {:?}", argument)),
+        None => Ok(default),
+    }
+}
+
+// e.g. 12 for an address, or out for an opcode
+fn parse_breakpoint(argument: &str) -> Result<Breakpoint, String> {
+    if let Some(opcode) = Opcode::from_mnemonic(argument) {
+        return Ok(Breakpoint::Opcode(opcode));
+    }
+
+    parse_address(argument)
+        .map(Breakpoint::Address)
+        .map_err(|_| format!("expected an address or a mnemonic, found {:?}", argument))
+}
+
+fn describe_stop(stop: &Stop) -> String {
+    match stop {
+        Stop::Breakpoint(breakpoint) => format!("breakpoint at {}", breakpoint),
+        Stop::Watchpoint { address, old, new } => {
+            format!("watchpoint [{}]: {} -> {}", address, old, new)
+        }
+        Stop::State(State::Output(value)) => format!("output {}", value),
+        Stop::State(State::NeedsInput) => "waiting for input".to_string(),
+        Stop::State(State::Halted) => "halted".to_string(),
+    }
+}
+
+// the instruction that runs next, e.g. => 4: 1002,4,3,4    mul [4], #3 -> [4]
+fn next_instruction(debugger: &Debugger) -> Option<String> {
+    let machine = debugger.machine();
+
+    if machine.is_halted() {
+        return None;
+    }
+
+    disassemble_from(machine.memory(), machine.instruction_pointer())
+        .next()
+        .map(|line| format!("=> {}", line.to_string().trim_start()))
+}
+
+// Execute a single debugger command, and return the lines to show for it.
+fn run_command(debugger: &mut Debugger, command_line: &str) -> Result<Vec<String>, String> {
+    let mut words = command_line.split_whitespace();
+    let command = match words.next() {
+        Some(command) => command,
+        None => return Ok(vec![]),
+    };
+    let arguments: Vec<&str> = words.collect();
+
+    let mut lines = vec![];
+
+    match command {
+        "help" | "h" => lines.push(HELP.to_string()),
+        "step" | "s" => {
+            let count = parse_count(arguments.first().copied(), 1)?;
+
+            for _ in 0..count {
+                if let Some(stop) = debugger.step().map_err(|error| error.to_string())? {
+                    lines.push(describe_stop(&stop));
+
+                    if !matches!(stop, Stop::State(State::Output(_))) {
+                        break;
+                    }
+                }
+            }
+
+            lines.extend(next_instruction(debugger));
+        }
+        "continue" | "c" => {
+            let stop = debugger.resume().map_err(|error| error.to_string())?;
+            lines.push(describe_stop(&stop));
+            lines.extend(next_instruction(debugger));
+        }
+        "break" | "b" if arguments.is_empty() => {
+            lines.extend(debugger.breakpoints().iter().map(|b| b.to_string()));
+        }
+        "break" | "b" => {
+            for argument in arguments {
+                let breakpoint = parse_breakpoint(argument)?;
+
+                if debugger.add_breakpoint(breakpoint) {
+                    lines.push(format!("breakpoint at {}", breakpoint));
+                }
+            }
+        }
+        "delete" => {
+            for argument in arguments {
+                let breakpoint = parse_breakpoint(argument)?;
+
+                if !debugger.remove_breakpoint(breakpoint) {
+                    return Err(format!("no breakpoint at {}", breakpoint));
+                }
+            }
+        }
+        "watch" | "w" if arguments.is_empty() => {
+            let machine = debugger.machine();
+            lines.extend(
+                debugger
+                    .watchpoints()
+                    .map(|address| format!("[{}] = {}", address, machine.read(address))),
+            );
+        }
+        "watch" | "w" => {
+            for argument in arguments {
+                debugger.watch(parse_address(argument)?);
+            }
+        }
+        "unwatch" => {
+            for argument in arguments {
+                let address = parse_address(argument)?;
+
+                if !debugger.unwatch(address) {
+                    return Err(format!("no watchpoint at {}", address));
+                }
+            }
+        }
+        "input" | "i" => {
+            let values = arguments
+                .iter()
+                .map(|argument| {
+                    argument
+                        .parse::<Value>()
+                        .map_err(|_| format!("expected a value, found {:?}", argument))
+                })
+                .collect::<Result<Vec<Value>, String>>()?;
+
+            debugger.machine_mut().extend_input(values);
+        }
+        "print" | "p" => {
+            let address = match arguments.first() {
+                Some(argument) => parse_address(argument)?,
+                None => return Err("expected an address".to_string()),
+            };
+            let count = parse_count(arguments.get(1).copied(), 1)?;
+
+            let machine = debugger.machine();
+            lines.extend(
+                (address..address + count)
+                    .map(|address| format!("[{}] = {}", address, machine.read(address))),
+            );
+        }
+        "disassemble" | "d" => {
+            let address = match arguments.first() {
+                Some(argument) => parse_address(argument)?,
+                None => debugger.machine().instruction_pointer(),
+            };
+            let count = parse_count(arguments.get(1).copied(), 10)?;
+
+            lines.extend(
+                disassemble_from(debugger.machine().memory(), address)
+                    .take(count)
+                    .map(|line| line.to_string()),
+            );
+        }
+        "trace" | "t" => match arguments.first().copied() {
+            Some("on") => debugger.set_tracing(true),
+            Some("off") => debugger.set_tracing(false),
+            Some("show") | None => {
+                let count = parse_count(arguments.get(1).copied(), 20)?;
+                let trace = debugger.trace();
+
+                lines.extend(
+                    trace[trace.len().saturating_sub(count)..]
+                        .iter()
+                        .map(|entry| entry.to_string()),
+                );
+            }
+            Some(argument) => {
+                return Err(format!("expected on, off or show, found {:?}", argument))
+            }
+        },
+        "info" => {
+            let machine = debugger.machine();
+            lines.push(format!(
+                "ip={} rb={} steps={} tracing={}{}",
+                machine.instruction_pointer(),
+                machine.relative_base(),
+                debugger.steps(),
+                if debugger.is_tracing() { "on" } else { "off" },
+                if machine.is_halted() { " (halted)" } else { "" }
+            ));
+        }
+        _ => return Err(format!("unknown command {:?}; type help", command)),
+    }
+
+    Ok(lines)
+}
+
+fn debug(program: Vec<Value>, input: Vec<Value>) {
+    let mut machine = Machine::new(program);
+    machine.extend_input(input);
+
+    let mut debugger = Debugger::new(machine);
+    println!("{}", next_instruction(&debugger).unwrap_or_default());
+
+    let stdin = io::stdin();
+    loop {
+        print!("(intcode) ");
+        io::stdout().flush().unwrap();
+
+        let mut command_line = String::new();
+        if stdin.lock().read_line(&mut command_line).unwrap() == 0 {
+            break;
+        }
+
+        if matches!(command_line.trim(), "quit" | "q") {
+            break;
+        }
+
+        match run_command(&mut debugger, &command_line) {
+            Ok(lines) => {
+                for line in lines {
+                    println!("{}", line);
+                }
+            }
+            Err(error) => println!("error: {}", error),
+        }
+    }
+}
+
+fn trace(program: Vec<Value>, input: Vec<Value>, limit: Option<usize>) -> Result<(), String> {
+    let mut machine = Machine::new(program);
+    machine.extend_input(input);
+
+    let mut debugger = Debugger::new(machine);
+    debugger.set_tracing(true);
+
+    loop {
+        if limit.is_some_and(|limit| debugger.steps() >= limit) {
+            println!("stopped after {} instructions", debugger.steps());
+            return Ok(());
+        }
+
+        let stop = debugger.step().map_err(|error| error.to_string())?;
+
+        for entry in debugger.take_trace() {
+            println!("{}", entry);
+        }
+
+        match stop {
+            Some(Stop::State(State::Output(value))) => println!("output {}", value),
+            Some(Stop::State(State::NeedsInput)) => {
+                return Err("the program is waiting for more input".to_string())
+            }
+            Some(Stop::State(State::Halted)) => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Disassemble { program } => read_program(&program).map(|program| {
+            for line in disassemble(&program) {
+                println!("{}", line);
+            }
+        }),
+        Command::Trace {
+            program,
+            input,
+            limit,
+        } => read_program(&program).and_then(|program| trace(program, input, limit)),
+        Command::Debug { program, input } => {
+            if program == "-" {
+                Err("the debugger reads its commands from stdin; pass a file".to_string())
+            } else {
+                read_program(&program).map(|program| debug(program, input))
+            }
+        }
+    };
+
+    if let Err(error) = result {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_commands(debugger: &mut Debugger, command_lines: &[&str]) -> Vec<String> {
+        command_lines
+            .iter()
+            .flat_map(|command_line| run_command(debugger, command_line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_run_command() {
+        let mut debugger = Debugger::new(Machine::parse("3,0,4,0,1002,0,3,0,4,0,99").unwrap());
+
+        assert_eq!(
+            run_commands(&mut debugger, &["break out", "continue"]),
+            vec![
+                "breakpoint at opcode out",
+                "waiting for input",
+                "=> 0: 3,0                      in -> [0]"
+            ]
+        );
+
+        assert_eq!(
+            run_commands(
+                &mut debugger,
+                &["input 7", "watch 0", "c", "c", "c", "c", "c"]
+            ),
+            vec![
+                "watchpoint [0]: 3 -> 7",
+                "=> 2: 4,0                      out [0]",
+                "breakpoint at opcode out",
+                "=> 2: 4,0                      out [0]",
+                "output 7",
+                "=> 4: 1002,0,3,0               mul [0], #3 -> [0]",
+                "watchpoint [0]: 7 -> 21",
+                "=> 8: 4,0                      out [0]",
+                "breakpoint at opcode out",
+                "=> 8: 4,0                      out [0]"
+            ]
+        );
+
+        assert_eq!(
+            run_commands(&mut debugger, &["print 0 2", "step 2", "info"]),
+            vec![
+                "[0] = 21",
+                "[1] = 0",
+                "output 21",
+                "halted",
+                "ip=10 rb=0 steps=5 tracing=off (halted)"
+            ]
+        );
+
+        assert!(run_command(&mut debugger, "step").is_err());
+        assert!(run_command(&mut debugger, "delete 3").is_err());
+        assert!(run_command(&mut debugger, "jump 3").is_err());
+    }
+}